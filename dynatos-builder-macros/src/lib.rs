@@ -8,6 +8,17 @@ use {
 	syn::{Token, punctuated::Punctuated},
 };
 
+/// Creates a builder for a function component.
+///
+/// Each argument of the function is a prop, which may be configured with `#[prop(...)]`:
+/// - `#[prop(default = <expr> as <ty>)]`: The prop is optional, with a default value.
+/// - `#[prop(name = <ident>)]`: The name of the prop. Required for props with patterns.
+/// - `#[prop(from)]`: Creates a `from_<prop>` function to build the component from just this prop.
+/// - `#[prop(required)]`: While the prop is missing, the builder uses a `Missing_<prop>` marker type
+///   (in the `<builder>_missing` module), so that errors from not setting it mention its name.
+/// - `#[prop(with = <fn> as <ty>)]`: The setter accepts a `<ty>`, and stores `<fn>(value)` as the prop.
+///   This may be used to coerce or validate the value. The type of the prop may not depend on any
+///   generics of the component.
 #[proc_macro_attribute]
 #[expect(clippy::too_many_lines, reason = "TODO")]
 pub fn builder(_attr: TokenStream, input: TokenStream) -> TokenStream {
@@ -43,6 +54,32 @@ pub fn builder(_attr: TokenStream, input: TokenStream) -> TokenStream {
 	// All props
 	let props = Prop::parse_all(&input);
 
+	// Module for the missing prop markers
+	let missing_mod = quote::format_ident!("{}_missing", builder.to_string().to_case(convert_case::Case::Snake));
+	let missing_prop_ty = |prop: &Prop| match prop.required {
+		true => {
+			let marker = quote::format_ident!("Missing_{}", prop.ident);
+			syn::parse_quote! { #missing_mod::#marker }
+		},
+		false => self::missing_prop_ty(),
+	};
+
+	// Missing prop markers
+	let missing_markers = props
+		.iter()
+		.filter(|prop| prop.required)
+		.map(|prop| -> syn::ItemStruct {
+			let marker = quote::format_ident!("Missing_{}", prop.ident);
+			let doc = format!("Marker for the missing `{}` prop", prop.ident);
+			syn::parse_quote! {
+				#[doc = #doc]
+				#[derive(Clone, Copy, Default, Debug)]
+				#[allow(non_camel_case_types, reason = "We want the prop name as-is")]
+				pub struct #marker;
+			}
+		})
+		.collect::<Vec<_>>();
+
 	// Builder type params
 	let builder_type_params = props
 		.iter()
@@ -59,7 +96,7 @@ pub fn builder(_attr: TokenStream, input: TokenStream) -> TokenStream {
 	// Builder default type arguments
 	let builder_default_ty_args = props
 		.iter()
-		.map(|prop| syn::GenericArgument::Type(prop.default_ty.clone().unwrap_or_else(self::missing_prop_ty)))
+		.map(|prop| syn::GenericArgument::Type(prop.default_ty.clone().unwrap_or_else(|| missing_prop_ty(prop))))
 		.collect::<Punctuated<_, Token![,]>>();
 
 	// Builder props declaration
@@ -126,7 +163,10 @@ pub fn builder(_attr: TokenStream, input: TokenStream) -> TokenStream {
 			let prop_ident = &prop.ident;
 
 			let fn_name = quote::format_ident!("from_{}", prop.ident);
-			let prop_ty = &prop.ty;
+			let prop_ty = match &prop.with {
+				Some(with) => &with.input_ty,
+				None => &prop.ty,
+			};
 			syn::parse_quote! {
 				pub #asyncness fn #fn_name< #builder_build_ty_params >(
 					#prop_ident: #prop_ty,
@@ -152,6 +192,29 @@ pub fn builder(_attr: TokenStream, input: TokenStream) -> TokenStream {
 			let fn_name = &prop.ident;
 			let mut fn_args = builder_type_args.clone();
 
+			// If we have a `with` function, the setter takes its input and
+			// stores the output, which must be the prop type.
+			if let Some(with) = &prop.with {
+				let with_fn = &with.fn_expr;
+				let input_ty = &with.input_ty;
+
+				fn_args[prop_idx] = prop.ty.clone();
+				return syn::parse_quote! {
+					pub fn #fn_name(
+						self,
+						#prop_ident: #input_ty,
+					) -> #builder< #fn_args > {
+						let #prop_ident = (#with_fn)(#prop_ident);
+
+						#[allow(clippy::needless_update, reason = "Sometimes, we don't have any prop identifiers")]
+						#builder {
+							#prop_ident,
+							..self
+						}
+					}
+				};
+			}
+
 			let new_ty_arg = self::ident_to_pascal_case(prop_ident);
 			let new_ty_arg = self::ident_to_ty_param(quote::format_ident!("New{}", new_ty_arg));
 
@@ -200,7 +263,7 @@ pub fn builder(_attr: TokenStream, input: TokenStream) -> TokenStream {
 				None => {
 					let default = match &prop.default_ty {
 						Some(ty) => self::ty_default(ty),
-						None => self::ty_default(&self::missing_prop_ty()),
+						None => self::ty_default(&missing_prop_ty(prop)),
 					};
 
 					syn::parse_quote! { #ident: #default }
@@ -272,7 +335,20 @@ pub fn builder(_attr: TokenStream, input: TokenStream) -> TokenStream {
 		}
 	};
 
+	// Missing prop markers module
+	let missing_mod_decl: Option<syn::ItemMod> = (!missing_markers.is_empty()).then(|| {
+		let doc = format!("Missing prop markers for [`{builder}`]");
+		syn::parse_quote! {
+			#[doc = #doc]
+			#builder_vis mod #missing_mod {
+				#( #missing_markers )*
+			}
+		}
+	});
+
 	TokenStream::from(quote! {
+		#missing_mod_decl
+
 		#cmpt_decl
 		#cmpt_inherent_impl
 
@@ -303,6 +379,22 @@ struct Prop {
 
 	/// If a `from_` function should be created for this component
 	create_from_fn: bool,
+
+	/// Function to transform the value in the setter
+	with: Option<PropWith>,
+
+	/// If the prop should have a marker type while missing
+	required: bool,
+}
+
+/// A prop's `with` function
+#[derive(Clone, Debug)]
+struct PropWith {
+	/// Function expression
+	fn_expr: syn::Expr,
+
+	/// Input type
+	input_ty: syn::Type,
 }
 
 impl Prop {
@@ -331,6 +423,8 @@ impl Prop {
 		let mut default_ty = None;
 		let mut default_value = None;
 		let mut create_from_fn = false;
+		let mut with = None;
+		let mut required = false;
 		for attr in &arg.attrs {
 			// Ignore any attributes that aren't `#[prop(...)]`
 			let syn::Meta::List(attr) = &attr.meta else {
@@ -352,6 +446,7 @@ impl Prop {
 						let ident = path.get_ident().expect("Expected identifier");
 						match ident.to_string().as_str() {
 							"from" => create_from_fn = true,
+							"required" => required = true,
 							ident => panic!("Unknown path attribute: {ident:?}"),
 						}
 					},
@@ -373,6 +468,15 @@ impl Prop {
 								},
 								_ => panic!("Expected default value to be of the form `<expr> as <ty>`"),
 							},
+							"with" => match name_value.value {
+								syn::Expr::Cast(cast) => {
+									with = Some(PropWith {
+										fn_expr:  *cast.expr,
+										input_ty: *cast.ty,
+									});
+								},
+								_ => panic!("Expected with function to be of the form `<fn> as <ty>`"),
+							},
 							ident => panic!("Unknown name-value attribute: {ident:?}"),
 						}
 					},
@@ -384,6 +488,11 @@ impl Prop {
 			unreachable!("Specified a default value without a type");
 		}
 
+		assert!(
+			!(required && default_value.is_some()),
+			"Required props cannot have a default value"
+		);
+
 		let prop_ident = prop_ident.expect("Props with patterns must specify their name via `#[prop(name = ...)]`");
 
 		Self {
@@ -393,6 +502,8 @@ impl Prop {
 			default_ty,
 			default_value,
 			create_from_fn,
+			with,
+			required,
 		}
 	}
 }
@@ -19,5 +19,5 @@ pub mod loadable_signal;
 pub use self::{
 	loadable::{IntoLoaded, IteratorLoadableExt, Loadable},
 	loadable_borrow::{LoadableBorrow, LoadableBorrowMut, SignalBorrowLoadable, SignalBorrowMutLoadable},
-	loadable_signal::LoadableSignal,
+	loadable_signal::{AsyncSignalBorrowLoadable, LoadableSignal},
 };
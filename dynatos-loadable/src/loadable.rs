@@ -23,6 +23,9 @@ pub enum Loadable<T, E> {
 	/// Empty
 	Empty,
 
+	/// Loading
+	Loading,
+
 	/// Failed to load
 	Err(E),
 
@@ -37,12 +40,24 @@ impl<T, E> Loadable<T, E> {
 		matches!(self, Self::Empty)
 	}
 
+	/// Returns if the loadable is loading.
+	#[must_use]
+	pub const fn is_loading(&self) -> bool {
+		matches!(self, Self::Loading)
+	}
+
+	/// Returns if the loadable is an error.
+	#[must_use]
+	pub const fn is_err(&self) -> bool {
+		matches!(self, Self::Err(_))
+	}
+
 	/// Returns if the loadable is loaded.
 	///
 	/// This means it's either an error or a value
 	#[must_use]
 	pub const fn is_loaded(&self) -> bool {
-		!self.is_empty()
+		matches!(self, Self::Err(_) | Self::Loaded(_))
 	}
 
 	/// Returns this loadable's value by reference.
@@ -52,6 +67,7 @@ impl<T, E> Loadable<T, E> {
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(err.clone()),
 			Self::Loaded(value) => Loadable::Loaded(value),
 		}
@@ -65,6 +81,7 @@ impl<T, E> Loadable<T, E> {
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(err.clone()),
 			Self::Loaded(value) => Loadable::Loaded(value),
 		}
@@ -77,6 +94,7 @@ impl<T, E> Loadable<T, E> {
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(err.clone()),
 			Self::Loaded(value) => Loadable::Loaded(value),
 		}
@@ -90,6 +108,7 @@ impl<T, E> Loadable<T, E> {
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(err.clone()),
 			Self::Loaded(value) => Loadable::Loaded(value),
 		}
@@ -102,6 +121,7 @@ impl<T, E> Loadable<T, E> {
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(err),
 			Self::Loaded(value) => Loadable::Loaded(f(value)),
 		}
@@ -114,6 +134,7 @@ impl<T, E> Loadable<T, E> {
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(f(err)),
 			Self::Loaded(value) => Loadable::Loaded(value),
 		}
@@ -122,6 +143,7 @@ impl<T, E> Loadable<T, E> {
 	/// Zips two loadable.
 	///
 	/// If is empty, the result will be empty.
+	/// If any is loading (and none are empty), the result will be loading.
 	/// If any is errored, the result will be an error.
 	pub fn zip<U>(self, rhs: Loadable<U, E>) -> Loadable<(T, U), E> {
 		match (self, rhs) {
@@ -131,6 +153,10 @@ impl<T, E> Loadable<T, E> {
 			// Otherwise, if we have both values, return loaded
 			(Self::Loaded(lhs), Loadable::Loaded(rhs)) => Loadable::Loaded((lhs, rhs)),
 
+			// Otherwise, if any is loading while the other isn't empty, we're loading
+			(Self::Loading, Loadable::Loading | Loadable::Loaded(_)) | (Self::Loaded(_), Loadable::Loading) =>
+				Loadable::Loading,
+
 			// Otherwise, we're empty
 			_ => Loadable::Empty,
 		}
@@ -138,24 +164,73 @@ impl<T, E> Loadable<T, E> {
 
 	/// Chains this loadable with another if it's loaded
 	///
-	/// If any operation returns empty or error, it will be propagated
+	/// If any operation returns empty, loading or error, it will be propagated
 	pub fn and_then<U, F>(self, f: F) -> Loadable<U, E>
 	where
 		F: FnOnce(T) -> Loadable<U, E>,
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(err),
 			Self::Loaded(value) => f(value),
 		}
 	}
 
+	/// Calls `f` with this loadable's value, if loaded.
+	///
+	/// Returns this loadable unchanged.
+	///
+	/// ```
+	/// # use dynatos_loadable::Loadable;
+	/// let mut seen = None;
+	/// let loadable: Loadable<i32, ()> = Loadable::Loaded(5);
+	/// let value = loadable.inspect(|&v| seen = Some(v)).map(|v| v * 2);
+	/// assert_eq!(seen, Some(5));
+	/// assert_eq!(value, Loadable::Loaded(10));
+	/// ```
+	#[must_use]
+	pub fn inspect<F>(self, f: F) -> Self
+	where
+		F: FnOnce(&T),
+	{
+		if let Self::Loaded(value) = &self {
+			f(value);
+		}
+
+		self
+	}
+
+	/// Calls `f` with this loadable's error, if errored.
+	///
+	/// Returns this loadable unchanged.
+	///
+	/// ```
+	/// # use dynatos_loadable::Loadable;
+	/// let mut seen = None;
+	/// let loadable: Loadable<i32, &str> = Loadable::Err("Oh no");
+	/// let value = loadable.inspect_err(|&err| seen = Some(err)).map(|v| v * 2);
+	/// assert_eq!(seen, Some("Oh no"));
+	/// assert_eq!(value, Loadable::Err("Oh no"));
+	/// ```
+	#[must_use]
+	pub fn inspect_err<F>(self, f: F) -> Self
+	where
+		F: FnOnce(&E),
+	{
+		if let Self::Err(err) = &self {
+			f(err);
+		}
+
+		self
+	}
+
 	/// Converts this to an option.
 	///
 	/// Maps `Loadable::Loaded` to `Some` and the rest to `None`.
 	pub fn loaded(self) -> Option<T> {
 		match self {
-			Self::Empty => None,
+			Self::Empty | Self::Loading => None,
 			Self::Err(_err) => None,
 			Self::Loaded(value) => Some(value),
 		}
@@ -166,7 +241,7 @@ impl<T, E> Loadable<T, E> {
 	/// If unloaded, returns `default`
 	pub fn loaded_or(self, default: T) -> Result<T, E> {
 		match self {
-			Self::Empty => Ok(default),
+			Self::Empty | Self::Loading => Ok(default),
 			Self::Err(err) => Err(err),
 			Self::Loaded(value) => Ok(value),
 		}
@@ -199,6 +274,7 @@ impl<T, E> Loadable<Option<T>, E> {
 	{
 		match self {
 			Self::Empty => Some(Loadable::Empty),
+			Self::Loading => Some(Loadable::Loading),
 			Self::Err(err) => Some(Loadable::Err(err)),
 			Self::Loaded(Some(value)) => Some(Loadable::Loaded(value)),
 			Self::Loaded(None) => None,
@@ -212,6 +288,7 @@ impl<T, E> Loadable<Option<T>, E> {
 	{
 		match self {
 			Self::Empty | Self::Loaded(None) => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) => Loadable::Err(err),
 			Self::Loaded(Some(value)) => Loadable::Loaded(value),
 		}
@@ -226,6 +303,7 @@ impl<T, E> Loadable<Result<T, E>, E> {
 	{
 		match self {
 			Self::Empty => Ok(Loadable::Empty),
+			Self::Loading => Ok(Loadable::Loading),
 			Self::Err(err) => Ok(Loadable::Err(err)),
 			Self::Loaded(Ok(value)) => Ok(Loadable::Loaded(value)),
 			Self::Loaded(Err(err)) => Err(err),
@@ -239,6 +317,7 @@ impl<T, E> Loadable<Result<T, E>, E> {
 	{
 		match self {
 			Self::Empty => Loadable::Empty,
+			Self::Loading => Loadable::Loading,
 			Self::Err(err) | Self::Loaded(Err(err)) => Loadable::Err(err),
 			Self::Loaded(Ok(value)) => Loadable::Loaded(value),
 		}
@@ -286,6 +365,7 @@ impl<T, E> Try for Loadable<T, E> {
 	fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
 		match self {
 			Self::Empty => ControlFlow::Break(Loadable::Empty),
+			Self::Loading => ControlFlow::Break(Loadable::Loading),
 			Self::Err(err) => ControlFlow::Break(Loadable::Err(err)),
 			Self::Loaded(value) => ControlFlow::Continue(value),
 		}
@@ -299,6 +379,7 @@ where
 	fn from_residual(residual: Loadable<!, E2>) -> Self {
 		match residual {
 			Loadable::Empty => Self::Empty,
+			Loadable::Loading => Self::Loading,
 			Loadable::Err(err) => Self::Err(err.into()),
 			Loadable::Loaded(never) => never,
 		}
@@ -333,7 +414,7 @@ impl<T, E> Residual<T> for Loadable<!, E> {
 /// Collects an iterator of `Loadable<T, E>` into a `Loadable<C, E>`,
 /// where `C` is a collection of `T`s.
 ///
-/// If any empty, or error loadables are found, this immediately short-circuits
+/// If any empty, loading, or error loadables are found, this immediately short-circuits
 /// and returns them
 impl<C, T, E> FromIterator<Loadable<T, E>> for Loadable<C, E>
 where
@@ -342,9 +423,10 @@ where
 	fn from_iter<I: IntoIterator<Item = Loadable<T, E>>>(iter: I) -> Self {
 		let mut collection = C::default();
 		for item in iter {
-			// If we find any empty, or errors, return them immediately
+			// If we find any empty, loading, or errors, return them immediately
 			let item = match item {
 				Loadable::Empty => return Self::Empty,
+				Loadable::Loading => return Self::Loading,
 				Loadable::Err(err) => return Self::Err(err),
 				Loadable::Loaded(value) => value,
 			};
@@ -425,6 +507,7 @@ where
 	fn get_signal(storage: &Self::SignalsStorage, kind: &Self::SigKind) -> Option<Self::Signal> {
 		let signal = match kind {
 			Loadable::Empty => Loadable::Empty,
+			Loadable::Loading => Loadable::Loading,
 			Loadable::Err(()) => Loadable::Err(storage.err.as_ref()?.signal()),
 			Loadable::Loaded(()) => Loadable::Loaded(storage.loaded.as_ref()?.signal()),
 		};
@@ -446,7 +529,7 @@ where
 				Some(storage) => storage.set(new_value),
 				None => storage.err = Some(ctx.create_signal_storage(new_value, Self::Err)),
 			},
-			Self::Empty => (),
+			Self::Empty | Self::Loading => (),
 		}
 	}
 }
@@ -534,8 +617,9 @@ where
 
 			// If the inner value didn't have anything, try to get the next value
 			match self.inner.next()? {
-				// If empty, or error, return them
+				// If empty, loading, or error, return them
 				Loadable::Empty => return Some(Loadable::Empty),
+				Loadable::Loading => return Some(Loadable::Loading),
 				Loadable::Err(err) => return Some(Loadable::Err(err)),
 
 				// On loaded, set the value iterator and try to extract it again
@@ -568,6 +652,7 @@ where
 	fn next(&mut self) -> Option<Self::Item> {
 		let value = match self.inner.next()? {
 			Loadable::Empty => return Some(Loadable::Empty),
+			Loadable::Loading => return Some(Loadable::Loading),
 			Loadable::Err(err) => return Some(Loadable::Err(err)),
 			Loadable::Loaded(value) => value,
 		};
@@ -583,3 +668,74 @@ pub impl<T> T {
 		Loadable::Loaded(self)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	type L<T> = Loadable<T, &'static str>;
+
+	#[test]
+	fn loading_is_not_loaded() {
+		let loading = L::<i32>::Loading;
+		assert!(loading.is_loading());
+		assert!(!loading.is_empty());
+		assert!(!loading.is_loaded());
+		assert_eq!(loading.loaded(), None);
+		assert_eq!(loading.loaded_or(5), Ok(5));
+	}
+
+	#[test]
+	fn loading_combinators() {
+		assert_eq!(L::<i32>::Loading.as_ref(), Loadable::Loading);
+		assert_eq!(L::<i32>::Loading.map(|value| value + 1), Loadable::Loading);
+		assert_eq!(L::<i32>::Loading.map_err(|_| 0_i32), Loadable::Loading);
+		assert_eq!(L::<i32>::Loading.and_then(|_| L::<i32>::Loaded(1)), Loadable::Loading);
+	}
+
+	#[test]
+	fn loading_zip() {
+		assert_eq!(L::<i32>::Loading.zip(L::<i32>::Loaded(1)), Loadable::Loading);
+		assert_eq!(L::<i32>::Loaded(1).zip(L::<i32>::Loading), Loadable::Loading);
+		assert_eq!(L::<i32>::Loading.zip(L::<i32>::Loading), Loadable::Loading);
+		assert_eq!(L::<i32>::Loading.zip(L::<i32>::Empty), Loadable::Empty);
+		assert_eq!(L::<i32>::Empty.zip(L::<i32>::Loading), Loadable::Empty);
+		assert_eq!(L::<i32>::Loading.zip(L::<i32>::Err("err")), Loadable::Err("err"));
+	}
+
+	#[test]
+	fn loading_transpose_flatten() {
+		assert_eq!(L::<Option<i32>>::Loading.transpose(), Some(Loadable::Loading));
+		assert_eq!(L::<Option<i32>>::Loading.flatten(), Loadable::Loading);
+		assert_eq!(L::<Result<i32, &str>>::Loading.transpose(), Ok(Loadable::Loading));
+		assert_eq!(L::<Result<i32, &str>>::Loading.flatten(), Loadable::Loading);
+	}
+
+	#[test]
+	fn loading_try() {
+		fn add_one(value: L<i32>) -> L<i32> {
+			Loadable::Loaded(value? + 1)
+		}
+
+		assert_eq!(add_one(Loadable::Loading), Loadable::Loading);
+		assert_eq!(add_one(Loadable::Empty), Loadable::Empty);
+		assert_eq!(add_one(Loadable::Err("err")), Loadable::Err("err"));
+		assert_eq!(add_one(Loadable::Loaded(1)), Loadable::Loaded(2));
+	}
+
+	#[test]
+	fn loading_collect() {
+		let values = [L::<i32>::Loaded(1), L::<i32>::Loading, L::<i32>::Loaded(2)];
+		assert_eq!(values.into_iter().collect::<L<Vec<_>>>(), Loadable::Loading);
+
+		let values = [L::<i32>::Loading, L::<i32>::Empty];
+		assert_eq!(values.into_iter().collect::<L<Vec<_>>>(), Loadable::Loading);
+
+		let values = [L::<Vec<i32>>::Loaded(vec![1]), L::<Vec<i32>>::Loading];
+		assert_eq!(values.into_iter().flatten_loaded().collect::<Vec<_>>(), [
+			Loadable::Loaded(1),
+			Loadable::Loading
+		]);
+	}
+}
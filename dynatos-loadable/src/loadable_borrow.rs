@@ -57,6 +57,7 @@ where
 		let borrow = self.borrow();
 		match &*borrow {
 			Loadable::Empty => Loadable::Empty,
+			Loadable::Loading => Loadable::Loading,
 			Loadable::Err(err) => Loadable::Err(err.clone()),
 			Loadable::Loaded(_) => Loadable::Loaded(LoadableBorrow(borrow)),
 		}
@@ -123,6 +124,7 @@ where
 		let borrow = self.borrow_mut();
 		match &*borrow {
 			Loadable::Empty => Loadable::Empty,
+			Loadable::Loading => Loadable::Loading,
 			Loadable::Err(err) => Loadable::Err(err.clone()),
 			Loadable::Loaded(_) => Loadable::Loaded(LoadableBorrow(borrow)),
 		}
@@ -9,6 +9,7 @@ use {
 	},
 	dynatos_reactive::{
 		AsyncSignal,
+		Derived,
 		SignalBorrow,
 		SignalBorrowMut,
 		SignalGetClone,
@@ -21,7 +22,10 @@ use {
 		SignalWith,
 		SignalWithDefaultImpl,
 		async_signal::{self, Loader},
+		derived::DerivedRun,
+		effect,
 	},
+	dynatos_sync_types::SyncBounds,
 };
 
 // TODO: Allow the loader to return a `Loadable<impl Future<Output = Result<T, E>>, E>`,
@@ -90,28 +94,105 @@ where
 	#[must_use]
 	#[track_caller]
 	pub fn borrow_unloaded(&self) -> Loadable<BorrowRef<'_, F>, E> {
-		let res = self.inner.borrow_unloaded();
-		match res {
-			Some(res) => match &*res {
-				Ok(_) => Loadable::Loaded(BorrowRef(res)),
-				Err(err) => Loadable::Err(err.clone()),
-			},
-			None => Loadable::Empty,
-		}
+		self::borrow_loadable_from(&self.inner, self.inner.borrow_unloaded())
 	}
 
 	/// Borrows the value, without loading it or gathering subscribers
 	#[must_use]
 	#[track_caller]
 	pub fn borrow_unloaded_no_dep(&self) -> Loadable<BorrowRef<'_, F>, E> {
-		let res = self.inner.borrow_unloaded_no_dep();
-		match res {
-			Some(res) => match &*res {
-				Ok(_) => Loadable::Loaded(BorrowRef(res)),
-				Err(err) => Loadable::Err(err.clone()),
-			},
-			None => Loadable::Empty,
-		}
+		// Note: Checking whether we're loading mustn't gather subscribers either.
+		effect::with_no_dep(|| self::borrow_loadable_from(&self.inner, self.inner.borrow_unloaded()))
+	}
+}
+
+impl<F, T, E> LoadableSignal<F>
+where
+	F: Loader<Output = Result<T, E>>,
+	T: SyncBounds + 'static,
+	E: SyncBounds + Clone + 'static,
+{
+	/// Returns a signal of whether this signal is empty.
+	///
+	/// Accessing the returned signal doesn't start loading this signal.
+	#[must_use]
+	#[track_caller]
+	pub fn is_empty_signal(&self) -> Derived<bool, impl DerivedRun<bool> + use<F, T, E>> {
+		self.state_signal(Loadable::is_empty)
+	}
+
+	/// Returns a signal of whether this signal is loading.
+	///
+	/// Accessing the returned signal doesn't start loading this signal.
+	#[must_use]
+	#[track_caller]
+	pub fn is_loading_signal(&self) -> Derived<bool, impl DerivedRun<bool> + use<F, T, E>> {
+		self.state_signal(Loadable::is_loading)
+	}
+
+	/// Returns a signal of whether this signal is loaded.
+	///
+	/// Like [`Loadable::is_loaded`], this means it's either an error or a value.
+	///
+	/// Accessing the returned signal doesn't start loading this signal.
+	#[must_use]
+	#[track_caller]
+	pub fn is_loaded_signal(&self) -> Derived<bool, impl DerivedRun<bool> + use<F, T, E>> {
+		self.state_signal(Loadable::is_loaded)
+	}
+
+	/// Returns a signal of whether this signal is an error.
+	///
+	/// Accessing the returned signal doesn't start loading this signal.
+	#[must_use]
+	#[track_caller]
+	pub fn is_error_signal(&self) -> Derived<bool, impl DerivedRun<bool> + use<F, T, E>> {
+		self.state_signal(Loadable::is_err)
+	}
+
+	/// Returns a signal of `pred` applied to the current state of this signal
+	#[track_caller]
+	fn state_signal(&self, pred: fn(&Loadable<(), E>) -> bool) -> Derived<bool, impl DerivedRun<bool> + use<F, T, E>> {
+		let signal = self.clone();
+		Derived::new(move || pred(&signal.borrow_unloaded().map(|_| ())))
+	}
+}
+
+/// Extension trait to borrow an [`AsyncSignal`] as a [`Loadable`].
+///
+/// This allows borrowing an [`AsyncSignal`] that outputs a `Result<T, E>`
+/// without wrapping it in a [`LoadableSignal`].
+#[extend::ext(name = AsyncSignalBorrowLoadable)]
+pub impl<F, T, E> AsyncSignal<F>
+where
+	F: Loader<Output = Result<T, E>>,
+	T: 'static,
+	E: Clone + 'static,
+{
+	/// Borrows this signal as a `Loadable<Borrow<T>, E>`
+	#[track_caller]
+	fn borrow_loadable(&self) -> Loadable<BorrowRef<'_, F>, E> {
+		self::borrow_loadable_from(self, self.borrow())
+	}
+}
+
+/// Converts a borrow of `signal` into a `Loadable<Borrow<T>, E>`.
+fn borrow_loadable_from<'a, F, T, E>(
+	signal: &AsyncSignal<F>,
+	res: Option<async_signal::BorrowRef<'a, F>>,
+) -> Loadable<BorrowRef<'a, F>, E>
+where
+	F: Loader<Output = Result<T, E>>,
+	T: 'static,
+	E: Clone + 'static,
+{
+	match res {
+		Some(res) => match &*res {
+			Ok(_) => Loadable::Loaded(BorrowRef(res)),
+			Err(err) => Loadable::Err(err.clone()),
+		},
+		None if signal.is_loading() => Loadable::Loading,
+		None => Loadable::Empty,
 	}
 }
 
@@ -204,14 +285,7 @@ where
 		Self: 'a;
 
 	fn borrow(&self) -> Self::Ref<'_> {
-		let res = self.inner.borrow();
-		match res {
-			Some(res) => match &*res {
-				Ok(_) => Loadable::Loaded(BorrowRef(res)),
-				Err(err) => Loadable::Err(err.clone()),
-			},
-			None => Loadable::Empty,
-		}
+		self.inner.borrow_loadable()
 	}
 }
 
@@ -292,6 +366,7 @@ where
 				Ok(_) => Loadable::Loaded(BorrowRefMut(res)),
 				Err(err) => Loadable::Err(err.clone()),
 			},
+			None if self.inner.is_loading() => Loadable::Loading,
 			None => Loadable::Empty,
 		}
 	}
@@ -12,11 +12,14 @@ use {
 		SignalGetClonedDefaultImpl,
 		SignalGetCopy,
 		SignalGetDefaultImpl,
+		SignalReplace,
+		SignalSet,
 		SignalSetDefaultImpl,
 		SignalUpdate,
 		SignalUpdateDefaultImpl,
 		SignalWith,
 		SignalWithDefaultImpl,
+		SuspenseContext,
 		THREAD_WORLD,
 		Trigger,
 		effect,
@@ -26,12 +29,15 @@ use {
 		world::{WorldTag, WorldTagGuard},
 	},
 	core::{
+		any::Any,
 		fmt,
 		future::Future,
+		mem,
 		ops::{Deref, DerefMut},
+		panic::AssertUnwindSafe,
 	},
 	dynatos_sync_types::{IMutRw, IMutRwRef, IMutRwRefMut, RcPtr, SyncBounds},
-	futures::{future, stream::AbortHandle},
+	futures::{FutureExt, future, stream::AbortHandle},
 	zutil_cloned::cloned,
 };
 
@@ -46,6 +52,9 @@ struct Inner<F: Loader> {
 
 	/// Task handle
 	handle: Option<AbortHandle>,
+
+	/// Panic from the last load, if any
+	panic: Option<LoadPanic>,
 }
 
 impl<F: Loader> Inner<F> {
@@ -102,23 +111,36 @@ impl<F: Loader> Inner<F> {
 				(fut, effect_fn)
 			},
 		};
-		let (fut, handle) = future::abortable(fut);
-		#[cloned(inner = effect_fn.inner, trigger = effect_fn.trigger)]
+		let (fut, handle) = future::abortable(AssertUnwindSafe(fut).catch_unwind());
+		#[cloned(inner = effect_fn.inner, trigger = effect_fn.trigger, loading_trigger = effect_fn.loading_trigger, suspense = effect_fn.suspense)]
 		util::spawn_task(async move {
 			// Load the value
-			// Note: If we get aborted, just remove the handle
-			let Ok(value) = fut.await else {
-				inner.write().handle = None;
+			// Note: If we get aborted, the handle was already removed (and possibly
+			//       replaced by a new one), so there's nothing to do.
+			let Ok(res) = fut.await else {
 				return;
 			};
 
-			// Then write it and remove the handle
+			// Then write it (or the panic) and remove the handle
+			// Note: If we panicked, we keep the previous value, if any.
 			let mut inner = inner.write();
-			inner.value = Some(value);
+			match res {
+				Ok(value) => {
+					inner.value = Some(value);
+					inner.panic = None;
+				},
+				Err(payload) => inner.panic = Some(LoadPanic::from_payload(&*payload)),
+			}
 			inner.handle = None;
 			drop(inner);
 
 			// Finally trigger
+			// Note: We execute both triggers together so that any subscribers
+			//       to both only run once.
+			let _loading_trigger_exec = loading_trigger.exec_inner(caller_loc);
+			if let Some(suspense) = &suspense {
+				suspense.remove_pending();
+			}
 			trigger.exec_inner(caller_loc);
 		});
 		self.handle = Some(handle);
@@ -174,17 +196,23 @@ impl<F: Loader> AsyncSignal<F> {
 	///
 	/// When any inputs to `loader` change, the signal's
 	/// future will be restarted.
+	///
+	/// If created within a [`SuspenseContext`], the signal is registered as pending
+	/// on it while it's loading.
 	#[track_caller]
 	#[must_use]
 	pub fn new(loader: F) -> Self {
 		Self {
 			load: Effect::new_raw(EffectFn {
-				inner:   RcPtr::new(IMutRw::new(Inner {
+				inner:           RcPtr::new(IMutRw::new(Inner {
 					value: None,
 					loader,
 					handle: None,
+					panic: None,
 				})),
-				trigger: Trigger::new(),
+				trigger:         Trigger::new(),
+				loading_trigger: Trigger::new(),
+				suspense:        SuspenseContext::current(),
 			}),
 		}
 	}
@@ -197,7 +225,13 @@ impl<F: Loader> AsyncSignal<F> {
 		reason = "The user may not care whether the future existed"
 	)]
 	pub fn stop_loading(&self) -> bool {
-		self.load.inner_fn().inner.write().stop_loading()
+		let effect_fn = self.load.inner_fn();
+		let stopped = effect_fn.inner.write().stop_loading();
+		if stopped {
+			effect_fn.loading_changed(false);
+		}
+
+		stopped
 	}
 
 	/// Starts a new loading future.
@@ -217,11 +251,13 @@ impl<F: Loader> AsyncSignal<F> {
 	where
 		F: Loader,
 	{
-		self.load
-			.inner_fn()
-			.inner
-			.write()
-			.start_loading(InnerParentRef::Signal(self))
+		let effect_fn = self.load.inner_fn();
+		let started = effect_fn.inner.write().start_loading(InnerParentRef::Signal(self));
+		if started {
+			effect_fn.loading_changed(true);
+		}
+
+		started
 	}
 
 	/// Restarts the currently loading future.
@@ -242,17 +278,66 @@ impl<F: Loader> AsyncSignal<F> {
 	where
 		F: Loader,
 	{
-		self.load
-			.inner_fn()
-			.inner
-			.write()
-			.restart_loading(InnerParentRef::Signal(self))
+		let effect_fn = self.load.inner_fn();
+		let had_fut = effect_fn.inner.write().restart_loading(InnerParentRef::Signal(self));
+		if !had_fut {
+			effect_fn.loading_changed(true);
+		}
+
+		had_fut
 	}
 
 	/// Returns if there exists a loading future.
+	///
+	/// Gathers a dependency that only changes when the signal
+	/// starts or stops loading.
 	#[must_use]
+	#[track_caller]
 	pub fn is_loading(&self) -> bool {
-		self.load.inner_fn().inner.read().is_loading()
+		let effect_fn = self.load.inner_fn();
+		effect_fn.loading_trigger.gather_subs();
+		effect_fn.inner.read().is_loading()
+	}
+
+	/// Maps the value of this signal.
+	///
+	/// The returned signal loads by waiting for this signal to load,
+	/// and then calling `f` on its value. Whenever this signal's value
+	/// changes, the returned signal is reloaded.
+	///
+	/// Loading the returned signal will start loading this one, but restarting it
+	/// won't restart this one. To reload the value, restart this signal instead.
+	#[track_caller]
+	#[must_use]
+	pub fn map<U, G>(self, f: G) -> AsyncSignal<impl Loader<Output = U>>
+	where
+		U: SyncBounds + 'static,
+		G: SyncBounds + Fn(&F::Output) -> U + 'static,
+	{
+		// Note: If we're not loaded yet, we just wait forever, since once we
+		//       do load, we'll be restarted with the value.
+		AsyncSignal::new(move || match self.borrow() {
+			Some(value) => future::Either::Left(future::ready(f(&value))),
+			None => future::Either::Right(future::pending()),
+		})
+	}
+
+	/// Returns the panic of the last load, if it panicked.
+	///
+	/// When the loader panics, the signal stops loading and keeps its
+	/// previous value, if any. The panic is cleared once a load succeeds.
+	///
+	/// Panics are only captured on targets that unwind. With `panic = "abort"`
+	/// (the default on `wasm32`), a panicking loader aborts instead, so this
+	/// always returns `None`.
+	///
+	/// Gathers a dependency on the signal.
+	#[must_use]
+	#[track_caller]
+	pub fn load_panic(&self) -> Option<LoadPanic> {
+		let effect_fn = self.load.inner_fn();
+		effect_fn.trigger.gather_subs();
+		effect_fn.inner.read().panic.clone()
 	}
 
 	/// Borrows the value, without loading it
@@ -284,17 +369,52 @@ where
 	F::Output: fmt::Debug,
 {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Note: We must not go through `borrow` here, since that would start loading
+		//       the value as a side effect of formatting it.
 		let effect_fn = self.load.inner_fn();
 		let inner = effect_fn.inner.read();
-		f.debug_struct("AsyncSignal")
-			.field("value", &inner.value)
-			.field("handle", &inner.handle)
+		let mut s = f.debug_struct("AsyncSignal");
+		match (&inner.value, inner.is_loading()) {
+			(Some(value), _) => s.field("value", value),
+			(None, true) => s.field("value", &format_args!("<loading>")),
+			(None, false) => s.field("value", &format_args!("<unloaded>")),
+		};
+		s.field("handle", &inner.handle)
+			.field("panic", &inner.panic)
 			.field("effect", &self.load)
 			.field("trigger", &effect_fn.trigger)
+			.field("loading_trigger", &effect_fn.loading_trigger)
 			.finish()
 	}
 }
 
+/// Panic that occurred while loading an [`AsyncSignal`].
+///
+/// Only created on targets that unwind, see [`AsyncSignal::load_panic`].
+#[derive(Clone, Debug)]
+pub struct LoadPanic {
+	/// Message, if the payload was a string
+	message: Option<String>,
+}
+
+impl LoadPanic {
+	/// Creates the panic from its payload
+	fn from_payload(payload: &(dyn Any + Send)) -> Self {
+		let message = match payload.downcast_ref::<&'static str>() {
+			Some(message) => Some((*message).to_owned()),
+			None => payload.downcast_ref::<String>().cloned(),
+		};
+
+		Self { message }
+	}
+
+	/// Returns the panic message, if the payload was a string.
+	#[must_use]
+	pub fn message(&self) -> Option<&str> {
+		self.message.as_deref()
+	}
+}
+
 /// Reference type for [`SignalBorrow`] impl
 pub struct BorrowRef<'a, F: Loader>(IMutRwRef<'a, Inner<F>>);
 
@@ -354,7 +474,11 @@ impl<F: Loader> SignalBorrow for AsyncSignal<F> {
 				}
 
 				drop(inner);
-				effect_fn.inner.write().start_loading(InnerParentRef::Signal(self));
+				let started = effect_fn.inner.write().start_loading(InnerParentRef::Signal(self));
+				if started {
+					effect_fn.loading_changed(true);
+				}
+
 				None
 			},
 		}
@@ -449,12 +573,48 @@ where
 	}
 }
 
-impl<F: Loader> SignalSetDefaultImpl for AsyncSignal<F> {}
+impl<F: Loader> SignalSet<F::Output> for AsyncSignal<F> {
+	/// Sets the value, stopping any loading future.
+	///
+	/// This is useful for optimistic updates, where the value is known
+	/// before the loader would yield it.
+	fn set(&self, new_value: F::Output) {
+		let effect_fn = self.load.inner_fn();
+		let mut inner = effect_fn.inner.write();
+		let stopped = inner.stop_loading();
+		inner.value = Some(new_value);
+		inner.panic = None;
+		drop(inner);
+
+		// Note: We execute both triggers together so that any subscribers
+		//       to both only run once.
+		let _trigger_exec = effect_fn.trigger.exec();
+		if stopped {
+			effect_fn.loading_changed(false);
+		}
+	}
+}
+
+impl<F: Loader> SignalReplace<F::Output> for AsyncSignal<F> {
+	type Value = Option<F::Output>;
+
+	/// Replaces the value, returning the previous one.
+	///
+	/// If the value isn't loaded yet, it isn't replaced, and `None` is returned.
+	fn replace(&self, new_value: F::Output) -> Self::Value {
+		let mut value = self.borrow_mut()?;
+		Some(mem::replace(&mut *value, new_value))
+	}
+}
+
 impl<F: Loader> SignalGetDefaultImpl for AsyncSignal<F> {}
 impl<F: Loader> SignalGetClonedDefaultImpl for AsyncSignal<F> {}
 
 // Note: We want to return an `Option<&T>` instead of `&Option<T>`,
-//       so we can't use the default impl
+//       so we can't use the default impl.
+//       Setting the value must also stop loading, so we can't use the default
+//       impl for it either.
+impl<F: Loader> !SignalSetDefaultImpl for AsyncSignal<F> {}
 impl<F: Loader> !SignalWithDefaultImpl for AsyncSignal<F> {}
 impl<F: Loader> !SignalUpdateDefaultImpl for AsyncSignal<F> {}
 
@@ -512,12 +672,78 @@ struct EffectFn<F: Loader> {
 
 	/// Trigger
 	trigger: Trigger,
+
+	/// Trigger for when we start or stop loading
+	loading_trigger: Trigger,
+
+	/// Suspense context we were created in
+	suspense: Option<SuspenseContext>,
+}
+
+impl<F: Loader> EffectFn<F> {
+	/// Notifies that we started or stopped loading
+	#[track_caller]
+	fn loading_changed(&self, is_loading: bool) {
+		let _loading_trigger_exec = self.loading_trigger.exec();
+		if let Some(suspense) = &self.suspense {
+			match is_loading {
+				true => suspense.add_pending(),
+				false => suspense.remove_pending(),
+			}
+		}
+	}
 }
 
 impl<F: Loader> EffectRun for EffectFn<F> {
 	effect::effect_run_impl_inner! {}
 
 	fn run(&self, _ctx: EffectRunCtx<'_>) {
-		self.inner.write().restart_loading(InnerParentRef::EffectFn(self));
+		let had_fut = self.inner.write().restart_loading(InnerParentRef::EffectFn(self));
+		if !had_fut {
+			self.loading_changed(true);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use {super::*, dynatos_util::Counter};
+
+	#[test]
+	fn debug_no_load() {
+		/// Counts the number of times the loader was called
+		static LOADS: Counter = Counter::new();
+
+		let signal = AsyncSignal::new(|| {
+			LOADS.bump();
+			future::ready(5_i32)
+		});
+
+		let s = format!("{signal:?}");
+		assert_eq!(LOADS.get(), 0, "Formatting the signal started loading it");
+		assert!(s.contains("<unloaded>"), "Unexpected debug output: {s:?}");
+	}
+
+	#[test]
+	fn replace() {
+		/// Counts the number of times the effect ran
+		static RUNS: Counter = Counter::new();
+
+		let signal = AsyncSignal::new(|| future::ready(5_i32));
+		assert_eq!(signal.replace(6), None, "Replaced an unloaded value");
+
+		signal.set(6);
+
+		#[cloned(signal)]
+		let _effect = Effect::new(move || {
+			_ = signal.borrow();
+			RUNS.bump();
+		});
+		assert_eq!(RUNS.get(), 1);
+
+		assert_eq!(signal.replace(7), Some(6));
+		assert_eq!(signal.borrow().as_deref(), Some(&7));
+		assert_eq!(RUNS.get(), 2, "Replacing the value didn't re-run the effect");
 	}
 }
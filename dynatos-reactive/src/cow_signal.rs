@@ -0,0 +1,243 @@
+//! Copy-on-write signal
+//!
+//! A signal over a shared value, that only clones
+//! the value when modifying it while it's shared.
+
+// Imports
+use {
+	crate::{
+		Signal,
+		SignalBorrow,
+		SignalBorrowMut,
+		SignalGetClonedDefaultImpl,
+		SignalGetDefaultImpl,
+		SignalGetRc,
+		SignalSet,
+		SignalSetDefaultImpl,
+		SignalUpdateDefaultImpl,
+		SignalWithDefaultImpl,
+		signal,
+	},
+	core::{
+		fmt,
+		ops::{Deref, DerefMut},
+	},
+	dynatos_sync_types::{RcPtr, SyncBounds},
+};
+
+/// Copy-on-write signal
+///
+/// Wraps a `Signal<RcPtr<T>>`, where the value may be cheaply shared
+/// with [`get_rc`](SignalGetRc::get_rc). When mutably borrowing the signal,
+/// the value is only cloned if it's currently shared, like [`Rc::make_mut`](std::rc::Rc::make_mut).
+pub struct CowSignal<T> {
+	/// Inner signal
+	inner: Signal<RcPtr<T>>,
+}
+
+impl<T> CowSignal<T> {
+	/// Creates a new copy-on-write signal
+	#[track_caller]
+	pub fn new(value: T) -> Self {
+		Self::from_rc(RcPtr::new(value))
+	}
+
+	/// Creates a new copy-on-write signal from a shared value
+	#[must_use]
+	#[track_caller]
+	pub fn from_rc(value: RcPtr<T>) -> Self {
+		Self {
+			inner: Signal::new(value),
+		}
+	}
+}
+
+impl<T: SyncBounds + 'static> CowSignal<T> {
+	/// Mutably borrows the value, cloning it only if it's currently shared.
+	///
+	/// This is the same as [`borrow_mut`](SignalBorrowMut::borrow_mut).
+	#[must_use]
+	#[track_caller]
+	pub fn make_mut(&self) -> BorrowRefMut<'_, T>
+	where
+		T: Clone,
+	{
+		let mut value = self.inner.borrow_mut();
+		_ = RcPtr::make_mut(&mut value);
+		BorrowRefMut(value)
+	}
+}
+
+impl<T> Clone for CowSignal<T> {
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+		}
+	}
+}
+
+#[coverage(off)]
+impl<T: fmt::Debug> fmt::Debug for CowSignal<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CowSignal").field("inner", &self.inner).finish()
+	}
+}
+
+/// Reference type for [`SignalBorrow`] impl
+pub struct BorrowRef<'a, T: 'a>(signal::BorrowRef<'a, RcPtr<T>>);
+
+impl<T> Deref for BorrowRef<'_, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+#[coverage(off)]
+impl<T: fmt::Debug> fmt::Debug for BorrowRef<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("BorrowRef").field(&**self).finish()
+	}
+}
+
+impl<T: SyncBounds + 'static> SignalBorrow for CowSignal<T> {
+	type Ref<'a>
+		= BorrowRef<'a, T>
+	where
+		Self: 'a;
+
+	fn borrow(&self) -> Self::Ref<'_> {
+		BorrowRef(self.inner.borrow())
+	}
+}
+
+/// Reference type for [`SignalBorrowMut`] impl
+pub struct BorrowRefMut<'a, T: 'a>(signal::BorrowRefMut<'a, RcPtr<T>>);
+
+impl<T> Deref for BorrowRefMut<'_, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<T: Clone> DerefMut for BorrowRefMut<'_, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		// Note: We made the value unique when creating this reference, and
+		//       nobody else can have accessed it since, so this never clones.
+		RcPtr::make_mut(&mut self.0)
+	}
+}
+
+#[coverage(off)]
+impl<T: fmt::Debug> fmt::Debug for BorrowRefMut<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("BorrowRefMut").field(&**self).finish()
+	}
+}
+
+impl<T: Clone + SyncBounds + 'static> SignalBorrowMut for CowSignal<T> {
+	type RefMut<'a>
+		= BorrowRefMut<'a, T>
+	where
+		Self: 'a;
+
+	fn borrow_mut(&self) -> Self::RefMut<'_> {
+		self.make_mut()
+	}
+}
+
+// Note: We disable the default impl because setting the value
+//       doesn't need to clone it first, like updating it would.
+impl<T> !SignalSetDefaultImpl for CowSignal<T> {}
+
+impl<T> SignalGetDefaultImpl for CowSignal<T> {}
+impl<T> SignalGetClonedDefaultImpl for CowSignal<T> {}
+impl<T> SignalWithDefaultImpl for CowSignal<T> {}
+impl<T> SignalUpdateDefaultImpl for CowSignal<T> {}
+
+impl<T: SyncBounds + 'static> SignalSet<T> for CowSignal<T> {
+	fn set(&self, new_value: T) {
+		*self.inner.borrow_mut() = RcPtr::new(new_value);
+	}
+}
+
+// Note: While the returned pointer is alive, the next mutable
+//       borrow of this signal will need to clone the value.
+impl<T: SyncBounds + 'static> SignalGetRc for CowSignal<T> {
+	type Ptr = RcPtr<T>;
+
+	fn get_rc(&self) -> Self::Ptr {
+		RcPtr::clone(&self.inner.borrow())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use {
+		super::*,
+		crate::{Effect, SignalGet, SignalUpdate},
+		dynatos_util::Counter,
+		zutil_cloned::cloned,
+	};
+
+	/// Value that counts how many times it's been cloned
+	#[derive(Debug)]
+	struct Value {
+		value: usize,
+	}
+
+	static TIMES_CLONED: Counter = Counter::new();
+
+	impl Clone for Value {
+		fn clone(&self) -> Self {
+			TIMES_CLONED.bump();
+			Self { value: self.value }
+		}
+	}
+
+	#[test]
+	fn make_mut() {
+		let signal = CowSignal::new(Value { value: 5 });
+
+		static TIMES_CHANGED: Counter = Counter::new();
+		#[cloned(signal)]
+		let _effect = Effect::new(move || {
+			_ = signal.borrow();
+			TIMES_CHANGED.bump();
+		});
+		assert_eq!(TIMES_CHANGED.get(), 1);
+
+		// While uniquely owned, we don't clone
+		signal.make_mut().value = 6;
+		signal.update(|value| value.value += 1);
+		assert_eq!(TIMES_CLONED.get(), 0, "Value was cloned while unique");
+		assert_eq!(TIMES_CHANGED.get(), 3);
+		assert_eq!(signal.borrow().value, 7);
+
+		// But once shared, we clone it exactly once
+		let shared = signal.get_rc();
+		signal.make_mut().value = 8;
+		assert_eq!(TIMES_CLONED.get(), 1, "Value wasn't cloned once while shared");
+		assert_eq!(TIMES_CHANGED.get(), 4);
+		assert_eq!(shared.value, 7, "Shared value was modified");
+		assert_eq!(signal.borrow().value, 8);
+
+		// And afterwards, it's unique again
+		signal.make_mut().value = 9;
+		assert_eq!(TIMES_CLONED.get(), 1, "Value was cloned while unique");
+		assert_eq!(TIMES_CHANGED.get(), 5);
+	}
+
+	#[test]
+	fn set() {
+		let signal = CowSignal::new(5_usize);
+		let shared = signal.get_rc();
+
+		signal.set(6);
+		assert_eq!(signal.get(), 6);
+		assert_eq!(*shared, 5, "Shared value was modified");
+	}
+}
@@ -6,7 +6,11 @@ use {
 	core::error::Error as StdError,
 	dynatos_sync_types::IMutRw,
 	itertools::Itertools,
-	petgraph::prelude::{EdgeRef, NodeIndex, StableGraph},
+	petgraph::{
+		algo,
+		prelude::{EdgeRef, NodeIndex, StableGraph},
+		visit::EdgeFiltered,
+	},
 	std::collections::HashMap,
 };
 
@@ -15,6 +19,9 @@ use {
 pub struct EffectDepInfo {
 	/// Location this dependency was gathered
 	pub gathered_loc: Loc,
+
+	/// Whether this dependency is removed after the trigger is first executed
+	pub once: bool,
 }
 
 /// Effect subscriber info
@@ -24,6 +31,13 @@ pub struct EffectSubInfo {
 	pub exec_loc: Loc,
 }
 
+/// Effect ordering info
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct EffectOrderInfo {
+	/// Location this ordering was defined
+	pub defined_loc: Loc,
+}
+
 /// Graph node
 #[derive(PartialEq, Eq, Clone, Hash, Debug)]
 #[derive(derive_more::From, derive_more::TryInto)]
@@ -35,24 +49,39 @@ enum Node {
 	Effect(WeakEffect),
 }
 
+impl Node {
+	/// Returns if the trigger/effect of this node is still alive
+	fn is_alive(&self) -> bool {
+		match self {
+			Self::Trigger(trigger) => trigger.upgrade().is_some(),
+			Self::Effect(effect) => effect.upgrade().is_some(),
+		}
+	}
+}
+
 /// Graph edge
 // TODO: Make this a ZST in release mode?
 #[derive(PartialEq, Eq, Clone, Hash, Debug)]
 #[derive(derive_more::From, derive_more::TryInto)]
+#[expect(clippy::enum_variant_names, reason = "All edges are from/to an effect")]
 enum Edge {
 	/// Effect dependency
 	EffectDep(EffectDepInfo),
 
 	/// Effect subscriber
 	EffectSub(EffectSubInfo),
+
+	/// Effect ordering
+	EffectOrder(EffectOrderInfo),
 }
 
 impl Edge {
 	/// Creates an effect dependency edge
 	#[track_caller]
-	pub const fn effect_dep() -> Self {
+	pub const fn effect_dep(once: bool) -> Self {
 		Self::EffectDep(EffectDepInfo {
 			gathered_loc: Loc::caller(),
+			once,
 		})
 	}
 
@@ -60,6 +89,19 @@ impl Edge {
 	pub const fn effect_sub(exec_loc: Loc) -> Self {
 		Self::EffectSub(EffectSubInfo { exec_loc })
 	}
+
+	/// Creates an effect ordering edge
+	#[track_caller]
+	pub const fn effect_order() -> Self {
+		Self::EffectOrder(EffectOrderInfo {
+			defined_loc: Loc::caller(),
+		})
+	}
+
+	/// Returns if this is an effect ordering edge
+	const fn is_effect_order(&self) -> bool {
+		matches!(self, Self::EffectOrder(_))
+	}
 }
 
 /// Inner
@@ -72,6 +114,31 @@ struct Inner {
 	graph: StableGraph<Node, Edge>,
 }
 
+impl Inner {
+	/// Returns if `effect` must run after `before`.
+	fn effect_runs_after(&self, effect: &WeakEffect, before: &WeakEffect) -> bool {
+		let (Some(&effect_idx), Some(&before_idx)) = (
+			self.nodes.get(&Node::Effect(effect.clone())),
+			self.nodes.get(&Node::Effect(before.clone())),
+		) else {
+			return false;
+		};
+
+		// Note: Most effects won't have any ordering, so check that first
+		//       to avoid searching the graph.
+		let has_order = self
+			.graph
+			.edges_directed(effect_idx, petgraph::Direction::Incoming)
+			.any(|edge| edge.weight().is_effect_order());
+		if !has_order {
+			return false;
+		}
+
+		let order_graph = EdgeFiltered::from_fn(&self.graph, |edge| edge.weight().is_effect_order());
+		effect_idx != before_idx && algo::has_path_connecting(&order_graph, before_idx, effect_idx, None)
+	}
+}
+
 /// Dependency graph
 #[derive(Debug)]
 pub struct DepGraph {
@@ -102,7 +169,9 @@ impl DepGraph {
 			.or_insert_with_key(|node| inner.graph.add_node(node.clone()))
 	}
 
-	/// Clears an effect's dependencies and subscribers
+	/// Clears an effect's dependencies and subscribers.
+	///
+	/// Any ordering with other effects is kept.
 	pub fn clear_effect<F: ?Sized + EffectRun>(&self, effect: &Effect<F>) {
 		let mut inner = self.inner.write();
 		let Some(&effect_idx) = inner.nodes.get(&Node::Effect(effect.downgrade().unsize())) else {
@@ -111,6 +180,10 @@ impl DepGraph {
 
 		let mut deps = inner.graph.neighbors_undirected(effect_idx).detach();
 		while let Some(edge) = deps.next_edge(&inner.graph) {
+			if inner.graph[edge].is_effect_order() {
+				continue;
+			}
+
 			inner.graph.remove_edge(edge);
 		}
 	}
@@ -131,6 +204,7 @@ impl DepGraph {
 		let neighbors = inner
 			.graph
 			.edges_directed(trigger_idx, W::DIR)
+			.filter(|edge| !edge.weight().is_effect_order())
 			.chunk_by(|edge| match W::DIR {
 				petgraph::Direction::Outgoing => edge.target(),
 				petgraph::Direction::Incoming => edge.source(),
@@ -180,8 +254,20 @@ impl DepGraph {
 	/// Adds an effect dependency
 	#[track_caller]
 	pub fn add_effect_dep(&self, effect: &Effect, trigger: &Trigger) {
+		self.add_effect_dep_inner(effect, trigger, false);
+	}
+
+	/// Adds an effect dependency that is removed once `trigger` is executed
+	#[track_caller]
+	pub fn add_effect_dep_once(&self, effect: &Effect, trigger: &Trigger) {
+		self.add_effect_dep_inner(effect, trigger, true);
+	}
+
+	/// Inner function for [`Self::add_effect_dep`] and [`Self::add_effect_dep_once`]
+	#[track_caller]
+	fn add_effect_dep_inner(&self, effect: &Effect, trigger: &Trigger, once: bool) {
 		tracing::trace!(
-			"Adding effect dependency\nEffect  : {}\nTrigger : {}\nGathered: {}",
+			"Adding effect dependency\nEffect  : {}\nTrigger : {}\nGathered: {}\nOnce    : {once}",
 			effect.defined_loc(),
 			trigger.defined_loc(),
 			Loc::caller(),
@@ -193,7 +279,25 @@ impl DepGraph {
 		self.inner
 			.write()
 			.graph
-			.add_edge(trigger_idx, effect_idx, Edge::effect_dep());
+			.add_edge(trigger_idx, effect_idx, Edge::effect_dep(once));
+	}
+
+	/// Removes all dependencies on `trigger` that should only be used once
+	pub fn remove_trigger_once_deps(&self, trigger: WeakTrigger) {
+		let mut inner = self.inner.write();
+		let Some(&trigger_idx) = inner.nodes.get(&Node::Trigger(trigger)) else {
+			return;
+		};
+
+		let mut subs = inner
+			.graph
+			.neighbors_directed(trigger_idx, petgraph::Direction::Outgoing)
+			.detach();
+		while let Some(edge) = subs.next_edge(&inner.graph) {
+			if let Edge::EffectDep(EffectDepInfo { once: true, .. }) = inner.graph[edge] {
+				inner.graph.remove_edge(edge);
+			}
+		}
 	}
 
 	/// Adds an effect subscriber
@@ -214,6 +318,94 @@ impl DepGraph {
 			.add_edge(effect_idx, trigger_idx, Edge::effect_sub(caller_loc));
 	}
 
+	/// Adds an effect ordering, so that `effect` runs after `before`
+	/// whenever both are in the run queue.
+	///
+	/// If this would create a cycle in the effect ordering, a warning
+	/// is emitted and the ordering isn't added.
+	#[track_caller]
+	pub fn add_effect_order(&self, effect: &Effect, before: &Effect) {
+		tracing::trace!(
+			"Adding effect ordering\nEffect : {}\nBefore : {}\nDefined: {}",
+			effect.defined_loc(),
+			before.defined_loc(),
+			Loc::caller(),
+		);
+
+		let effect_idx = self.get_or_insert_node(Node::Effect(effect.downgrade()));
+		let before_idx = self.get_or_insert_node(Node::Effect(before.downgrade()));
+
+		let mut inner = self.inner.write();
+		let order_graph = EdgeFiltered::from_fn(&inner.graph, |edge| edge.weight().is_effect_order());
+		if algo::has_path_connecting(&order_graph, effect_idx, before_idx, None) {
+			tracing::warn!(
+				"Ignoring effect ordering that would create a cycle\nEffect : {}\nBefore : {}\nDefined: {}",
+				effect.defined_loc(),
+				before.defined_loc(),
+				Loc::caller(),
+			);
+			return;
+		}
+
+		inner.graph.add_edge(before_idx, effect_idx, Edge::effect_order());
+	}
+
+	/// Returns if `effect` must run after `before`.
+	///
+	/// This is the case when there's a chain of effect orderings from `before` to `effect`.
+	pub fn effect_runs_after(&self, effect: &WeakEffect, before: &WeakEffect) -> bool {
+		self.inner.read().effect_runs_after(effect, before)
+	}
+
+	/// Returns, for each effect in `effects`, the indices of all effects in
+	/// `effects` that it must run after.
+	///
+	/// See [`effect_runs_after`](Self::effect_runs_after) for details.
+	pub fn effects_run_after(&self, effects: &[WeakEffect]) -> Vec<Vec<usize>> {
+		let inner = self.inner.read();
+		effects
+			.iter()
+			.map(|effect| {
+				effects
+					.iter()
+					.positions(|before| inner.effect_runs_after(effect, before))
+					.collect()
+			})
+			.collect()
+	}
+
+	/// Returns the number of nodes in the graph.
+	///
+	/// Includes any nodes of dropped triggers/effects that haven't been pruned yet.
+	pub fn node_count(&self) -> usize {
+		self.inner.read().graph.node_count()
+	}
+
+	/// Removes all nodes of dropped triggers/effects, along with their edges.
+	///
+	/// Dropped triggers/effects aren't removed from the graph automatically,
+	/// so long-lived applications should call this occasionally.
+	///
+	/// Returns the number of removed nodes.
+	pub fn prune(&self) -> usize {
+		let mut inner = self.inner.write();
+		let Inner { nodes, graph } = &mut *inner;
+
+		let prev_len = nodes.len();
+		nodes.retain(|node, &mut node_idx| {
+			let is_alive = node.is_alive();
+			if !is_alive {
+				graph.remove_node(node_idx);
+			}
+
+			is_alive
+		});
+
+		let removed = prev_len - nodes.len();
+		tracing::trace!("Pruned {removed} nodes from the dependency graph");
+		removed
+	}
+
 	/// Exports the dependency graph as a dot graph.
 	pub fn export_dot(&self) -> String {
 		let inner = &self.inner.read();
@@ -229,8 +421,12 @@ impl DepGraph {
 				},
 			},
 			|_edge_idx, edge| match edge {
-				Edge::EffectDep(info) => format!("Gather({})", info.gathered_loc),
+				Edge::EffectDep(info) => match info.once {
+					true => format!("GatherOnce({})", info.gathered_loc),
+					false => format!("Gather({})", info.gathered_loc),
+				},
 				Edge::EffectSub(info) => format!("Exec({})", info.exec_loc),
+				Edge::EffectOrder(info) => format!("After({})", info.defined_loc),
 			},
 		);
 
@@ -204,8 +204,13 @@ impl<T: fmt::Debug, F: ?Sized> fmt::Debug for Derived<T, F> {
 		debug.field("effect", &self.effect);
 		debug.field("trigger", &effect_fn.trigger);
 
+		// Note: We must not go through `borrow` here, since that would initialize
+		//       the value as a side effect of formatting it.
 		match effect_fn.value.try_read() {
-			Ok(value) => debug.field("value", &*value).finish(),
+			Ok(value) => match &*value {
+				Some(value) => debug.field("value", value).finish(),
+				None => debug.field("value", &format_args!("<uninitialized>")).finish(),
+			},
 			Err(_) => debug.finish_non_exhaustive(),
 		}
 	}
@@ -329,4 +334,18 @@ mod tests {
 		_ = f.borrow();
 		assert_eq!(COUNT.get(), 1, "Lazy effect was run again after access");
 	}
+
+	#[test]
+	fn debug_lazy() {
+		static COUNT: Counter = Counter::new();
+
+		let f = Derived::new_lazy(|| COUNT.bump());
+		let s = format!("{f:?}");
+		assert_eq!(COUNT.get(), 0, "Formatting the derived initialized it");
+		assert!(s.contains("<uninitialized>"), "Unexpected debug output: {s:?}");
+
+		_ = f.borrow();
+		let s = format!("{f:?}");
+		assert!(s.contains("value: ()"), "Unexpected debug output: {s:?}");
+	}
 }
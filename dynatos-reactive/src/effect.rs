@@ -7,6 +7,8 @@
 //       which doesn't allow casting to `Rc<dyn Any>`, required by `Rc::downcast`.
 
 // Modules
+mod async_fn;
+mod deferred;
 mod deps_gatherer;
 mod run;
 mod suppressed;
@@ -14,6 +16,8 @@ mod weak;
 
 // Exports
 pub use self::{
+	async_fn::AsyncEffectFn,
+	deferred::DeferredEffectFn,
 	deps_gatherer::EffectDepsGatherer,
 	run::{EffectRun, EffectRunCtx, effect_run_impl_inner},
 	suppressed::EffectSuppressed,
@@ -34,7 +38,7 @@ use {
 		ops::{CoerceUnsized, Deref},
 		sync::atomic,
 	},
-	dynatos_sync_types::{CellBool, RcPtr},
+	dynatos_sync_types::{CellBool, CellU8, RcPtr},
 };
 
 /// Effect inner
@@ -46,9 +50,15 @@ pub struct Inner<F: ?Sized> {
 	/// Whether this effect is currently suppressed
 	suppressed: CellBool,
 
+	/// Whether this effect missed any runs while suppressed
+	missed_run: CellBool,
+
 	/// Whether we're currently checking dependencies.
 	checking_deps: CellBool,
 
+	/// Priority in the run queue
+	priority: CellU8,
+
 	/// Where this effect was defined
 	defined_loc: Loc,
 
@@ -99,7 +109,9 @@ impl<F> Effect<F> {
 		let inner = Inner {
 			fresh: CellBool::new(false),
 			suppressed: CellBool::new(false),
+			missed_run: CellBool::new(false),
 			checking_deps: CellBool::new(false),
+			priority: CellU8::new(0),
 			defined_loc: Loc::caller(),
 			run,
 		};
@@ -178,6 +190,11 @@ impl<F: ?Sized> Effect<F> {
 	/// Gathers dependencies for this effect.
 	///
 	/// All signals used within `gather` will have this effect as a dependency.
+	///
+	/// While `gather` runs, this effect is the running effect (see [`running`]).
+	/// Unlike [`force_run`](Self::force_run), any existing dependencies are kept, and
+	/// the effect itself isn't run, so this may be used to add dependencies to an effect
+	/// from outside of it.
 	pub fn gather_deps<G, O>(&self, gather: G) -> O
 	where
 		F: EffectRun + 'static,
@@ -218,8 +235,14 @@ impl<F: ?Sized> Effect<F> {
 			});
 		self.inner.checking_deps.set(false, atomic::Ordering::Release);
 
-		// If we're suppressed or fresh, we don't need to run.
-		if self.is_suppressed() || self.is_fresh() {
+		// If we're fresh, we don't need to run.
+		if self.is_fresh() {
+			return;
+		}
+
+		// If we're suppressed, we can't run, but remember that we missed it.
+		if self.is_suppressed() {
+			self.set_missed_run();
 			return;
 		}
 
@@ -252,6 +275,28 @@ impl<F: ?Sized> Effect<F> {
 		self.inner.fresh.set(true, atomic::Ordering::Release);
 	}
 
+	/// Makes this effect run after `other`.
+	///
+	/// Whenever both effects are in the run queue at the same time,
+	/// `other` will be run first. This also applies transitively, so
+	/// if `other` runs after a third effect, so does this effect.
+	///
+	/// The ordering is kept until both effects are dropped, and
+	/// isn't affected by either effect's dependencies changing.
+	///
+	/// If `other` already runs after this effect, this would create
+	/// a cycle, so a warning is emitted and the ordering is ignored.
+	#[track_caller]
+	pub fn run_after<F2>(&self, other: &Effect<F2>)
+	where
+		F: EffectRun,
+		F2: ?Sized + EffectRun,
+	{
+		GLOBAL_WORLD
+			.dep_graph()
+			.add_effect_order(&self.clone().unsize(), &other.clone().unsize());
+	}
+
 	/// Sets the effect as stale
 	pub fn set_stale(&self) {
 		self.inner.fresh.set(false, atomic::Ordering::Release);
@@ -269,17 +314,62 @@ impl<F: ?Sized> Effect<F> {
 		!self.is_fresh()
 	}
 
+	/// Sets the priority of this effect.
+	///
+	/// When multiple effects are queued to run, the ones with
+	/// a higher priority run first. Effects with the same
+	/// priority run in the order they were queued.
+	///
+	/// Effects start with a priority of `0`.
+	///
+	/// If the effect is already queued, the new priority
+	/// only applies the next time it's queued.
+	///
+	/// Note that [`run_after`](Self::run_after) takes precedence
+	/// over the priority.
+	pub fn set_priority(&self, priority: u8) {
+		self.inner.priority.set(priority, atomic::Ordering::Release);
+	}
+
+	/// Returns the priority of this effect
+	#[must_use]
+	pub fn priority(&self) -> u8 {
+		self.inner.priority.get(atomic::Ordering::Acquire)
+	}
+
 	/// Suppresses this effect.
 	pub fn suppress(&self) -> EffectSuppressed<'_, F> {
 		EffectSuppressed::new(self)
 	}
 
+	/// Sets whether this effect is suppressed.
+	///
+	/// Unlike [`suppress`](Self::suppress), this isn't scoped, so the
+	/// effect stays suppressed until this is called with `false`.
+	pub fn set_suppressed(&self, suppressed: bool) {
+		self.inner.suppressed.set(suppressed, atomic::Ordering::Release);
+	}
+
 	/// Returns whether the effect is suppressed
 	#[must_use]
 	pub fn is_suppressed(&self) -> bool {
 		self.inner.suppressed.get(atomic::Ordering::Acquire)
 	}
 
+	/// Returns whether this effect missed any runs while suppressed, and clears it.
+	///
+	/// An effect misses a run when any of its dependencies are executed
+	/// while it's suppressed.
+	#[must_use]
+	pub fn take_missed_run(&self) -> bool {
+		self.inner.missed_run.swap(false, atomic::Ordering::AcqRel)
+	}
+
+	/// Sets that this effect missed a run while suppressed
+	pub(crate) fn set_missed_run(&self) {
+		self.inner.missed_run.set(true, atomic::Ordering::Release);
+	}
+
 	/// Formats this effect into `s`
 	#[coverage(off)]
 	fn fmt_debug(&self, mut s: fmt::DebugStruct<'_, '_>) -> Result<(), fmt::Error> {
@@ -287,6 +377,8 @@ impl<F: ?Sized> Effect<F> {
 
 		s.field("suppressed", &self.inner.suppressed.get(atomic::Ordering::Acquire));
 
+		s.field("priority", &self.priority());
+
 		s.field("defined_loc", &self.defined_loc());
 
 		s.finish()
@@ -0,0 +1,73 @@
+//! Async effect function
+
+// Imports
+use {
+	super::{Effect, EffectRun, EffectRunCtx, effect_run_impl_inner},
+	crate::util,
+	core::future::Future,
+	dynatos_sync_types::{IMut, SyncBounds},
+	futures::{future, stream::AbortHandle},
+};
+
+/// Async effect function.
+///
+/// See [`Effect::new_async`] for details.
+pub struct AsyncEffectFn<F> {
+	/// Function
+	f: F,
+
+	/// Handle to the in-flight task, if any
+	handle: IMut<Option<AbortHandle>>,
+}
+
+impl<F, Fut> Effect<AsyncEffectFn<F>>
+where
+	F: SyncBounds + Fn() -> Fut + 'static,
+	Fut: SyncBounds + Future<Output = ()> + 'static,
+{
+	/// Creates a new async effect.
+	///
+	/// Each time the effect runs, `f` is called, and the future it returns is spawned.
+	/// If a previous future is still in-flight, it is aborted first. The in-flight
+	/// future is also aborted when the effect is dropped.
+	///
+	/// # Dependencies
+	/// Only the dependencies accessed while calling `f` are gathered.
+	/// Any signals accessed within the future itself (after it's first polled)
+	/// won't cause the effect to re-run.
+	#[track_caller]
+	pub fn new_async(f: F) -> Self {
+		Self::new(AsyncEffectFn {
+			f,
+			handle: IMut::new(None),
+		})
+	}
+}
+
+impl<F, Fut> EffectRun for AsyncEffectFn<F>
+where
+	F: SyncBounds + Fn() -> Fut + 'static,
+	Fut: SyncBounds + Future<Output = ()> + 'static,
+{
+	effect_run_impl_inner! {}
+
+	fn run(&self, _ctx: EffectRunCtx<'_>) {
+		let (fut, handle) = future::abortable((self.f)());
+		let prev_handle = self.handle.lock().replace(handle);
+		if let Some(prev_handle) = prev_handle {
+			prev_handle.abort();
+		}
+
+		// Note: If we get aborted, there's nothing to do
+		util::spawn_task(async move { _ = fut.await });
+	}
+}
+
+impl<F> Drop for AsyncEffectFn<F> {
+	fn drop(&mut self) {
+		let handle = self.handle.lock().take();
+		if let Some(handle) = handle {
+			handle.abort();
+		}
+	}
+}
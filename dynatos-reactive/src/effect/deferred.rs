@@ -0,0 +1,99 @@
+//! Deferred effect function
+
+// Imports
+use {
+	super::{Effect, EffectRun, EffectRunCtx, effect_run_impl_inner},
+	crate::{effect, util},
+	core::sync::atomic,
+	dynatos_sync_types::{CellBool, RcPtr, SyncBounds},
+};
+
+/// Deferred effect function.
+///
+/// See [`Effect::new_deferred`] for details.
+pub struct DeferredEffectFn<F> {
+	/// Function
+	f: F,
+
+	/// State, shared with the scheduled task
+	state: RcPtr<State>,
+}
+
+/// Deferred effect state
+struct State {
+	/// Whether a run is scheduled
+	scheduled: CellBool,
+
+	/// Whether the next run should call the function
+	run_now: CellBool,
+}
+
+impl<F> Effect<DeferredEffectFn<F>>
+where
+	F: SyncBounds + Fn() + 'static,
+{
+	/// Creates a new deferred effect.
+	///
+	/// Each time the effect runs, instead of calling `f` immediately, a
+	/// task is spawned to call it once the current call stack unwinds (on
+	/// a microtask, with the `wasm-js-promise` feature).
+	/// This means any writes made while running won't re-enter the effect
+	/// synchronously.
+	///
+	/// If the effect runs again before the task is executed, no new task is
+	/// spawned, and `f` will only be called once. The effect is considered
+	/// fresh once the task is spawned, since `f` will see any later changes.
+	///
+	/// # Dependencies
+	/// Dependencies are gathered while calling `f` within the task, so the
+	/// effect won't have any dependencies until it's first called.
+	#[track_caller]
+	pub fn new_deferred(f: F) -> Self {
+		Self::new(DeferredEffectFn {
+			f,
+			state: RcPtr::new(State {
+				scheduled: CellBool::new(false),
+				run_now:   CellBool::new(false),
+			}),
+		})
+	}
+}
+
+impl<F> EffectRun for DeferredEffectFn<F>
+where
+	F: SyncBounds + Fn() + 'static,
+{
+	effect_run_impl_inner! {}
+
+	fn run(&self, _ctx: EffectRunCtx<'_>) {
+		// If we're being run from the scheduled task, call the function.
+		// Note: We take the flag, so that any runs caused by the function
+		//       itself get scheduled again instead of recursing.
+		if self.state.run_now.swap(false, atomic::Ordering::AcqRel) {
+			(self.f)();
+			return;
+		}
+
+		// If we're already scheduled, the task will pick up any changes
+		if self.state.scheduled.swap(true, atomic::Ordering::AcqRel) {
+			return;
+		}
+
+		let effect = effect::running()
+			.expect("Deferred effect should be the running effect")
+			.downgrade();
+		let state = RcPtr::clone(&self.state);
+		util::spawn_task(async move {
+			state.scheduled.set(false, atomic::Ordering::Release);
+
+			// Note: If the effect was dropped in the meantime, there's nothing to do
+			let Some(effect) = effect.upgrade() else {
+				return;
+			};
+
+			// Note: We force run to ensure the dependencies gathered are the ones of `f`.
+			state.run_now.set(true, atomic::Ordering::Release);
+			effect.force_run();
+		});
+	}
+}
@@ -8,7 +8,24 @@ mod storage;
 // Exports
 pub use self::{
 	ctx::EnumSplitValueUpdateCtx,
-	either::{All1, All2, All3, Either1, Either2, Either3},
+	either::{
+		All1,
+		All2,
+		All3,
+		All4,
+		All5,
+		All6,
+		All7,
+		All8,
+		Either1,
+		Either2,
+		Either3,
+		Either4,
+		Either5,
+		Either6,
+		Either7,
+		Either8,
+	},
 	storage::SignalStorage,
 };
 
@@ -24,6 +41,7 @@ use {
 		SignalGetClonedDefaultImpl,
 		SignalGetDefaultImpl,
 		SignalSet,
+		SignalWith,
 		SignalWithDefaultImpl,
 		Trigger,
 	},
@@ -119,12 +137,23 @@ impl<S, T: EnumSplitValue<S>> SignalGetCloned for EnumSplitSignal<S, T> {
 	}
 }
 
-impl<S, T: EnumSplitValue<S>> SignalWithDefaultImpl for EnumSplitSignal<S, T> {}
+impl<S, T: EnumSplitValue<S>> SignalWith for EnumSplitSignal<S, T> {
+	type Value<'a> = T::Signal;
+
+	fn with<F, O>(&self, f: F) -> O
+	where
+		F: for<'a> FnOnce(Self::Value<'a>) -> O,
+	{
+		f(self.borrow())
+	}
+}
 
 // Note: Since our `Borrow` impl doesn't return a reference, we implement
-//       `GetCloned` manually, so we don't want the default impl
+//       `GetCloned` and `With` manually, so we don't want the default impl.
+//       There's also no `SignalGet` impl, since the signals aren't `Copy`.
 impl<S, T: EnumSplitValue<S>> !SignalGetDefaultImpl for EnumSplitSignal<S, T> {}
 impl<S, T: EnumSplitValue<S>> !SignalGetClonedDefaultImpl for EnumSplitSignal<S, T> {}
+impl<S, T: EnumSplitValue<S>> !SignalWithDefaultImpl for EnumSplitSignal<S, T> {}
 
 /// Effect fn inner
 struct EffectFnInner<S, T: EnumSplitValue<S>> {
@@ -251,7 +280,7 @@ pub impl<S> S {
 mod tests {
 	use {
 		super::*,
-		crate::{Effect, Signal, SignalGet},
+		crate::{Effect, Signal, SignalGet, SignalWith},
 		dynatos_sync_types::{OnceCell, thread_local_or_global},
 		dynatos_util::Counter,
 		zutil_cloned::cloned,
@@ -324,6 +353,60 @@ mod tests {
 		assert_eq!(get_times(), [2, 3, 2, 3]);
 	}
 
+	#[test]
+	fn four_variants() {
+		let input = Signal::new(Either4::<usize, char, bool, i32>::T1(0));
+		let signal = EnumSplitSignal::new(input.clone());
+
+		static TIMES_RUN: [Counter; 4] = [const { Counter::new() }; 4];
+		fn get_times() -> [usize; 4] {
+			TIMES_RUN.each_ref().map(Counter::get)
+		}
+
+		// Go through all variants, adding an effect to each signal
+		let _effects = [Either4::T1(0), Either4::T2('a'), Either4::T3(false), Either4::T4(0)].map(|value| {
+			input.set(value);
+			match signal.borrow() {
+				Either4::T1(signal) => Effect::new(move || {
+					_ = signal.get();
+					TIMES_RUN[0].bump();
+				})
+				.unsize(),
+				Either4::T2(signal) => Effect::new(move || {
+					_ = signal.get();
+					TIMES_RUN[1].bump();
+				})
+				.unsize(),
+				Either4::T3(signal) => Effect::new(move || {
+					_ = signal.get();
+					TIMES_RUN[2].bump();
+				})
+				.unsize(),
+				Either4::T4(signal) => Effect::new(move || {
+					_ = signal.get();
+					TIMES_RUN[3].bump();
+				})
+				.unsize(),
+			}
+		});
+		assert_eq!(get_times(), [1, 1, 1, 1]);
+
+		input.set(Either4::T2('b'));
+		assert_eq!(get_times(), [1, 2, 1, 1]);
+
+		input.set(Either4::T4(1));
+		assert_eq!(get_times(), [1, 2, 1, 2]);
+
+		input.set(Either4::T1(1));
+		assert_eq!(get_times(), [2, 2, 1, 2]);
+
+		input.set(Either4::T3(true));
+		assert_eq!(get_times(), [2, 2, 2, 2]);
+
+		input.set(Either4::T3(false));
+		assert_eq!(get_times(), [2, 2, 3, 2]);
+	}
+
 	#[test]
 	fn write_back() {
 		// Start with `T1`
@@ -392,4 +475,21 @@ mod tests {
 		assert_eq!(TIMES_RUN_INNER1.get(), 3);
 		assert_eq!(TIMES_RUN_INNER2.get(), 3);
 	}
+
+	#[test]
+	fn accessors() {
+		let input = Signal::new(Some(5_usize));
+		let signal = EnumSplitSignal::new(input.clone());
+
+		let get_value = |inner: Option<Signal<usize>>| inner.as_ref().map(Signal::get);
+		assert_eq!(get_value(signal.borrow()), Some(5));
+		assert_eq!(get_value(signal.get_cloned()), Some(5));
+		assert_eq!(get_value(signal.get_cloned_no_dep()), Some(5));
+		assert_eq!(signal.with(get_value), Some(5));
+		assert_eq!(signal.with_no_dep(get_value), Some(5));
+
+		input.set(None);
+		assert_eq!(get_value(signal.get_cloned()), None);
+		assert_eq!(signal.with(get_value), None);
+	}
 }
@@ -64,3 +64,8 @@ macro gen_either($Either:ident, $All:ident, $($t:ident: $T:ident),* $(,)?) {
 gen_either! { Either1, All1, t1: T1 }
 gen_either! { Either2, All2, t1: T1, t2: T2 }
 gen_either! { Either3, All3, t1: T1, t2: T2, t3: T3 }
+gen_either! { Either4, All4, t1: T1, t2: T2, t3: T3, t4: T4 }
+gen_either! { Either5, All5, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5 }
+gen_either! { Either6, All6, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6 }
+gen_either! { Either7, All7, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7 }
+gen_either! { Either8, All8, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8 }
@@ -0,0 +1,183 @@
+//! Hydration registry
+
+// Imports
+use {
+	crate::{SignalSet, SignalWith, effect, run_queue},
+	core::fmt,
+	dynatos_sync_types::{IMut, SyncBounds},
+	serde::{Serialize, de::DeserializeOwned},
+	std::collections::BTreeMap,
+};
+
+/// Hydration registry.
+///
+/// Signals are registered under a key, and then their values may be serialized
+/// (e.g. on the server) with [`serialize`](Self::serialize), and later restored
+/// (e.g. on the client) with [`hydrate`](Self::hydrate), before any effects run.
+///
+/// The values are serialized as a json object, with each signal's value under its key.
+pub struct HydrationRegistry {
+	/// Entries, by key
+	entries: IMut<BTreeMap<String, Box<dyn Entry>>>,
+}
+
+impl HydrationRegistry {
+	/// Creates a new, empty, registry
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			entries: IMut::new(BTreeMap::new()),
+		}
+	}
+
+	/// Registers a signal under `key`.
+	///
+	/// If another signal was already registered under `key`, it's replaced.
+	pub fn register<S, T>(&self, key: impl Into<String>, signal: S)
+	where
+		S: for<'a> SignalWith<Value<'a> = &'a T> + SignalSet<T> + SyncBounds + 'static,
+		T: Serialize + DeserializeOwned + 'static,
+	{
+		let key = key.into();
+		let prev_entry = self.entries.lock().insert(key.clone(), Box::new(signal));
+		if prev_entry.is_some() {
+			tracing::debug!("Replaced signal registered under {key:?}");
+		}
+	}
+
+	/// Removes the signal registered under `key`.
+	///
+	/// Returns if any signal was registered
+	pub fn unregister(&self, key: &str) -> bool {
+		self.entries.lock().remove(key).is_some()
+	}
+
+	/// Serializes the values of all registered signals as json.
+	///
+	/// Doesn't gather any dependencies.
+	pub fn serialize(&self) -> Result<String, HydrationError> {
+		let entries = self.entries.lock();
+		let values = effect::with_no_dep(|| {
+			entries
+				.iter()
+				.map(|(key, entry)| match entry.serialize() {
+					Ok(value) => Ok((key.clone(), value)),
+					Err(err) => Err(HydrationError::Serialize { key: key.clone(), err }),
+				})
+				.collect::<Result<serde_json::Map<_, _>, _>>()
+		})?;
+
+		Ok(serde_json::Value::Object(values).to_string())
+	}
+
+	/// Hydrates all registered signals from `json`, as returned by [`serialize`](Self::serialize).
+	///
+	/// Any keys in `json` without a registered signal are ignored, and any registered signals
+	/// without a value in `json` are left untouched.
+	///
+	/// All signals are set before running any effects. On error, any signals already
+	/// hydrated keep their new values.
+	pub fn hydrate(&self, json: &str) -> Result<(), HydrationError> {
+		let values =
+			serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json).map_err(HydrationError::Parse)?;
+
+		// Note: We declare the guard first so the entries are unlocked before any effects run.
+		let _defer_effects = run_queue::defer_effects();
+		let entries = self.entries.lock();
+		for (key, value) in values {
+			let Some(entry) = entries.get(&key) else {
+				tracing::debug!("Ignoring hydration value without a registered signal: {key:?}");
+				continue;
+			};
+
+			entry
+				.hydrate(value)
+				.map_err(|err| HydrationError::Deserialize { key, err })?;
+		}
+
+		Ok(())
+	}
+
+	/// Returns the number of registered signals
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.entries.lock().len()
+	}
+
+	/// Returns if there are no registered signals
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.entries.lock().is_empty()
+	}
+}
+
+#[coverage(off)]
+impl Default for HydrationRegistry {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[coverage(off)]
+impl fmt::Debug for HydrationRegistry {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("HydrationRegistry")
+			.field("keys", &self.entries.lock().keys().collect::<Vec<_>>())
+			.finish()
+	}
+}
+
+/// Error for [`HydrationRegistry`]
+#[derive(thiserror::Error, Debug)]
+pub enum HydrationError {
+	/// Unable to serialize a signal's value
+	#[error("Unable to serialize the value of signal {key:?}")]
+	Serialize {
+		/// Signal key
+		key: String,
+
+		/// Inner error
+		#[source]
+		err: serde_json::Error,
+	},
+
+	/// Unable to parse the hydration json
+	#[error("Unable to parse hydration json")]
+	Parse(#[source] serde_json::Error),
+
+	/// Unable to deserialize a signal's value
+	#[error("Unable to deserialize the value of signal {key:?}")]
+	Deserialize {
+		/// Signal key
+		key: String,
+
+		/// Inner error
+		#[source]
+		err: serde_json::Error,
+	},
+}
+
+/// Registry entry
+trait Entry: SyncBounds {
+	/// Serializes the value of this entry
+	fn serialize(&self) -> Result<serde_json::Value, serde_json::Error>;
+
+	/// Hydrates this entry from a value
+	fn hydrate(&self, value: serde_json::Value) -> Result<(), serde_json::Error>;
+}
+
+impl<S, T> Entry for S
+where
+	S: for<'a> SignalWith<Value<'a> = &'a T> + SignalSet<T> + SyncBounds,
+	T: Serialize + DeserializeOwned + 'static,
+{
+	fn serialize(&self) -> Result<serde_json::Value, serde_json::Error> {
+		self.with(|value| serde_json::to_value(value))
+	}
+
+	fn hydrate(&self, value: serde_json::Value) -> Result<(), serde_json::Error> {
+		let value = serde_json::from_value(value)?;
+		self.set(value);
+		Ok(())
+	}
+}
@@ -16,7 +16,6 @@
 	try_trait_v2,
 	try_trait_v2_residual,
 	never_type,
-	unwrap_infallible,
 	arbitrary_self_types,
 	coverage_attribute,
 	macro_attr
@@ -24,29 +23,38 @@
 
 // Modules
 pub mod async_signal;
+pub mod cow_signal;
 pub mod dep_graph;
 pub mod derived;
 pub mod effect;
 pub mod effect_stack;
 pub mod enum_split;
+#[cfg(feature = "hydration")]
+pub mod hydration;
 mod loc;
 pub mod mapped_signal;
 pub mod memo;
 pub mod run_queue;
 pub mod signal;
+pub mod signal_vec;
+pub mod suspense;
 pub mod trigger;
 mod util;
 pub mod with_default;
 pub mod world;
 
 // Exports
+#[cfg(feature = "hydration")]
+pub use self::hydration::{HydrationError, HydrationRegistry};
 pub use self::{
-	async_signal::AsyncSignal,
+	async_signal::{AsyncSignal, LoadPanic},
+	cow_signal::CowSignal,
 	derived::Derived,
 	effect::{Effect, EffectRun, EffectRunCtx, WeakEffect, effect_run_impl_inner},
 	enum_split::{EnumSplitSignal, SignalEnumSplit},
-	mapped_signal::{MappedSignal, SignalMapped, TryMappedSignal},
-	memo::Memo,
+	mapped_signal::{MappedSignal, SignalMapped, TryMappedSignal, lens},
+	memo::{Memo, MemoRun},
+	run_queue::{DeferEffects, defer_effects, flush},
 	signal::{
 		Signal,
 		SignalBorrow,
@@ -57,6 +65,7 @@ pub use self::{
 		SignalGetClonedDefaultImpl,
 		SignalGetCopy,
 		SignalGetDefaultImpl,
+		SignalGetRc,
 		SignalReplace,
 		SignalSet,
 		SignalSetDefaultImpl,
@@ -65,8 +74,13 @@ pub use self::{
 		SignalUpdateDefaultImpl,
 		SignalWith,
 		SignalWithDefaultImpl,
+		Subscription,
+		set_all,
 	},
-	trigger::{Trigger, WeakTrigger},
+	signal_vec::{SignalVec, VecDiff, VecDiffs},
+	suspense::SuspenseContext,
+	trigger::{Trigger, WeakTrigger, without_subscriber_warning},
+	util::spawn_task,
 	with_default::{SignalWithDefault, WithDefault},
 	world::{GLOBAL_WORLD, GlobalWorld, THREAD_WORLD},
 };
@@ -5,7 +5,7 @@
 
 // Imports
 use {
-	crate::{Effect, Signal, SignalGetCloned, SignalSet, SignalUpdate, SignalWith, Trigger, WeakEffect},
+	crate::{Effect, Signal, SignalGetClonedDefaultImpl, SignalSet, SignalUpdate, SignalWith, Trigger, WeakEffect},
 	core::ops::{ControlFlow, FromResidual, Residual, Try},
 	dynatos_sync_types::{IMutRw, OnceCell, RcPtr, SyncBounds},
 	zutil_cloned::cloned,
@@ -175,24 +175,30 @@ where
 	}
 }
 
-impl<T> SignalGetCloned for TryMappedSignal<T>
+impl<T> SignalWith for TryMappedSignal<T>
 where
 	T: Try<Residual: Residual<Signal<T::Output>>>,
-	SignalTry<T>: Clone,
+	SignalTry<T>: Clone + 'static,
 {
-	type Value = SignalTry<T>;
+	type Value<'a> = &'a SignalTry<T>;
 
-	fn get_cloned(&self) -> Self::Value {
+	fn with<F, O>(&self, f: F) -> O
+	where
+		F: for<'a> FnOnce(Self::Value<'a>) -> O,
+	{
+		// Note: We clone the output signal so we don't keep it locked while
+		//       calling `f`, since writing to the output signal from `f`
+		//       would require the get effect to lock it.
 		self.inner.trigger.gather_subs();
-		self.inner
-			.output
-			.read()
-			.as_ref()
-			.expect("Output signal was missing")
-			.clone()
+		let output = self.inner.output.read().clone().expect("Output signal was missing");
+		f(&output)
 	}
 }
 
+// Note: Since signals aren't `Copy`, there's no `SignalGet` impl.
+//       Use `SignalGetCloned` or `SignalWith` instead.
+impl<T> SignalGetClonedDefaultImpl for TryMappedSignal<T> where T: Try<Residual: Residual<Signal<T::Output>>> {}
+
 /// Output signal type
 type OutputSignal<T> = RcPtr<IMutRw<Option<SignalTry<T>>>>;
 
@@ -250,14 +256,24 @@ impl<T> Clone for MappedSignal<T> {
 	}
 }
 
-impl<T> SignalGetCloned for MappedSignal<T> {
-	type Value = Signal<T>;
+impl<T: 'static> SignalWith for MappedSignal<T> {
+	type Value<'a> = &'a Signal<T>;
 
-	fn get_cloned(&self) -> Self::Value {
-		self.0.get_cloned().into_ok()
+	fn with<F, O>(&self, f: F) -> O
+	where
+		F: for<'a> FnOnce(Self::Value<'a>) -> O,
+	{
+		self.0.with(|signal| match signal {
+			Ok(signal) => f(signal),
+			Err(never) => *never,
+		})
 	}
 }
 
+// Note: Since signals aren't `Copy`, there's no `SignalGet` impl.
+//       Use `SignalGetCloned` or `SignalWith` instead.
+impl<T> SignalGetClonedDefaultImpl for MappedSignal<T> {}
+
 /// Extension trait to add a map a signal
 #[extend::ext_sized(name = SignalMapped)]
 pub impl<S> S
@@ -286,10 +302,38 @@ where
 	}
 }
 
+/// Creates a [`MappedSignal`] focused on a field of a signal.
+///
+/// The field is cloned out of the signal when reading, and
+/// cloned into the signal when writing.
+///
+/// ```
+/// # use dynatos_reactive::{Signal, SignalGetCloned, SignalSet, lens};
+/// let person = Signal::new(("Alice".to_owned(), 30));
+/// let name = lens!(person.clone(), 0);
+///
+/// name.get_cloned().set("Bob".to_owned());
+/// assert_eq!(person.get_cloned(), ("Bob".to_owned(), 30));
+/// ```
+///
+/// Nested fields may also be accessed with `lens!(signal, field.inner)`.
+pub macro lens($input:expr, $($field:tt).+ $(,)?) {
+	MappedSignal::new(
+		$input,
+		|value| value.$($field).+.clone(),
+		|value, new_value| value.$($field).+ = new_value.clone(),
+	)
+}
+
 
 #[cfg(test)]
 mod tests {
-	use {super::*, crate::SignalGet, core::assert_matches, dynatos_util::Counter};
+	use {
+		super::*,
+		crate::{SignalGet, SignalGetCloned},
+		core::assert_matches,
+		dynatos_util::Counter,
+	};
 
 	#[test]
 	fn basic() {
@@ -357,4 +401,94 @@ mod tests {
 		outer.set(Ok(2));
 		assert_eq!(TIMES_RUN.get(), 4);
 	}
+
+	#[test]
+	fn try_mapped_accessors() {
+		let outer = Signal::new(Some(5));
+		let mapped = TryMappedSignal::new(outer.clone(), |opt| *opt, |opt, &value| *opt = Some(value));
+
+		assert_eq!(mapped.get_cloned().map(|inner| inner.get()), Some(5));
+		assert_eq!(mapped.get_cloned_no_dep().map(|inner| inner.get()), Some(5));
+		assert_eq!(mapped.with(|inner| inner.as_ref().map(Signal::get)), Some(5));
+		assert_eq!(mapped.with_no_dep(|inner| inner.as_ref().map(Signal::get)), Some(5));
+
+		outer.set(None);
+		assert_matches!(mapped.get_cloned(), None);
+		assert_matches!(mapped.with(Option::is_none), true);
+	}
+
+	#[test]
+	fn mapped_accessors() {
+		let outer = Signal::new((5, 'a'));
+		let mapped = MappedSignal::new(
+			outer.clone(),
+			|&(value, _)| value,
+			|(value, _), &new_value| *value = new_value,
+		);
+
+		assert_eq!(mapped.get_cloned().get(), 5);
+		assert_eq!(mapped.get_cloned_no_dep().get(), 5);
+		assert_eq!(mapped.with(Signal::get), 5);
+		assert_eq!(mapped.with_no_dep(Signal::get), 5);
+
+		mapped.with(|inner| inner.set(6));
+		assert_eq!(outer.get(), (6, 'a'));
+	}
+
+	#[test]
+	fn lens() {
+		#[derive(Clone, PartialEq, Debug)]
+		struct Person {
+			name: String,
+			age:  u32,
+		}
+
+		let outer = Signal::new(Person {
+			name: "a".to_owned(),
+			age:  5,
+		});
+		let mapped = super::lens!(outer.clone(), name);
+		let name = mapped.get_cloned();
+		assert_eq!(name.get_cloned(), "a");
+
+		// Counts the times that the mapped signal was run
+		static TIMES_RUN: Counter = Counter::new();
+		let _effect = Effect::new(move || {
+			mapped.with(|_| ());
+			TIMES_RUN.bump();
+		});
+		assert_eq!(TIMES_RUN.get(), 1);
+
+		name.set("b".to_owned());
+		assert_eq!(outer.get_cloned(), Person {
+			name: "b".to_owned(),
+			age:  5,
+		});
+
+		outer.update(|person| person.name = "c".to_owned());
+		assert_eq!(name.get_cloned(), "c");
+
+		outer.update(|person| person.age = 6);
+		assert_eq!(name.get_cloned(), "c");
+		assert_eq!(TIMES_RUN.get(), 1);
+	}
+
+	#[test]
+	fn with_gathers_deps() {
+		let outer = Signal::new(Ok::<usize, usize>(5));
+		let mapped = TryMappedSignal::new(outer.clone(), |opt| *opt, |opt, &value| *opt = Ok(value));
+
+		// Counts the times that the mapped signal was run
+		static TIMES_RUN: Counter = Counter::new();
+		let _effect = Effect::new(move || {
+			mapped.with(|_| ());
+			TIMES_RUN.bump();
+		});
+
+		assert_eq!(TIMES_RUN.get(), 1);
+		outer.set(Ok(6));
+		assert_eq!(TIMES_RUN.get(), 1);
+		outer.set(Err(1));
+		assert_eq!(TIMES_RUN.get(), 2);
+	}
 }
@@ -16,9 +16,10 @@ use {
 	core::{
 		fmt,
 		marker::{PhantomData, Unsize},
-		ops::{CoerceUnsized, Deref},
+		ops::{CoerceUnsized, Deref, Sub},
+		sync::atomic,
 	},
-	dynatos_sync_types::{IMutRw, IMutRwRef, SyncBounds},
+	dynatos_sync_types::{CellBool, IMutRw, IMutRwRef, SyncBounds},
 };
 
 /// Memo signal.
@@ -34,13 +35,14 @@ impl<T, F> Memo<T, F> {
 	#[track_caller]
 	pub fn new(f: F) -> Self
 	where
-		T: SyncBounds + PartialEq + 'static,
-		F: SyncBounds + Fn() -> T + 'static,
+		T: SyncBounds + 'static,
+		F: MemoRun<T> + 'static,
 	{
 		let value = IMutRw::new(None);
 		let effect = Effect::new(EffectFn {
 			trigger: Trigger::new(),
 			value,
+			changed_last: CellBool::new(false),
 			f,
 		});
 
@@ -48,6 +50,22 @@ impl<T, F> Memo<T, F> {
 	}
 }
 
+impl<T, F> Memo<T, WithTolerance<T, F>> {
+	/// Creates a new memo'd signal that considers values within
+	/// `tolerance` of each other as unchanged.
+	///
+	/// This is useful for values derived from continuous inputs, where
+	/// small amounts of jitter shouldn't update any subscribers.
+	#[track_caller]
+	pub fn new_with_tolerance(f: F, tolerance: T) -> Self
+	where
+		T: SyncBounds + Copy + PartialOrd + Sub<Output = T> + 'static,
+		F: SyncBounds + Fn() -> T + 'static,
+	{
+		Self::new(WithTolerance { f, tolerance })
+	}
+}
+
 // TODO: `F: ?Sized`
 #[expect(clippy::multiple_inherent_impl, reason = "We'll add a bound eventually")]
 impl<T, F> Memo<T, F> {
@@ -55,8 +73,8 @@ impl<T, F> Memo<T, F> {
 	#[track_caller]
 	pub fn suppress(&self) -> EffectSuppressed<'_, impl EffectRun>
 	where
-		T: SyncBounds + PartialEq + 'static,
-		F: SyncBounds + Fn() -> T + 'static,
+		T: SyncBounds + 'static,
+		F: MemoRun<T> + 'static,
 	{
 		self.effect.suppress()
 	}
@@ -67,6 +85,17 @@ impl<T, F> Memo<T, F> {
 	pub fn update_no_run(&self, value: T) {
 		*self.effect.inner_fn().value.write() = Some(value);
 	}
+
+	/// Returns whether the most recent computation produced a new value.
+	///
+	/// This is `false` if the value computed was considered the same as the
+	/// previous one (as per [`MemoRun::is_same`]), and thus no subscribers were updated.
+	///
+	/// Does not gather a dependency, nor does it run the memo function.
+	#[must_use]
+	pub fn changed_last(&self) -> bool {
+		self.effect.inner_fn().changed_last.get(atomic::Ordering::Acquire)
+	}
 }
 
 /// Reference type for [`SignalBorrow`] impl
@@ -119,8 +148,13 @@ impl<T: fmt::Debug, F: ?Sized> fmt::Debug for Memo<T, F> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let effect_fn = self.effect.inner_fn();
 		let mut debug = f.debug_struct("Memo");
+		// Note: We must not go through `borrow` here, since that would initialize
+		//       the value as a side effect of formatting it.
 		match effect_fn.value.try_read() {
-			Ok(value) => debug.field("value", &*value).finish(),
+			Ok(value) => match &*value {
+				Some(value) => debug.field("value", value).finish(),
+				None => debug.field("value", &format_args!("<uninitialized>")).finish(),
+			},
 			Err(_) => debug.finish_non_exhaustive(),
 		}
 	}
@@ -141,25 +175,28 @@ struct EffectFn<T, F: ?Sized> {
 	/// Value
 	value: IMutRw<Option<T>>,
 
+	/// Whether the last run changed the value
+	changed_last: CellBool,
+
 	/// Function
 	f: F,
 }
 
 impl<T, F> EffectRun for EffectFn<T, F>
 where
-	T: SyncBounds + PartialEq + 'static,
-	F: SyncBounds + Fn() -> T + 'static,
+	T: SyncBounds + 'static,
+	F: MemoRun<T> + 'static,
 {
 	crate::effect_run_impl_inner! {}
 
 	fn run(&self, _ctx: EffectRunCtx<'_>) {
-		let new_value = (self.f)();
+		let new_value = self.f.run();
 		let mut value = self.value.write();
 
 		// Write the new value, if it's different from the previous
-		// Note: Since we're comparing against `Some(_)`, any `None` values
-		//       will always be written to.
-		let is_same = value.as_ref() == Some(&new_value);
+		// Note: Any `None` values will always be written to.
+		let is_same = value.as_ref().is_some_and(|value| self.f.is_same(value, &new_value));
+		self.changed_last.set(!is_same, atomic::Ordering::Release);
 		if !is_same {
 			*value = Some(new_value);
 			drop(value);
@@ -167,3 +204,116 @@ where
 		}
 	}
 }
+
+/// Memo run
+pub trait MemoRun<T>: SyncBounds {
+	/// Runs the memo function, yielding a value
+	fn run(&self) -> T;
+
+	/// Returns whether `new_value` should be considered the same as `old_value`.
+	///
+	/// If so, the memo won't update any subscribers.
+	fn is_same(&self, old_value: &T, new_value: &T) -> bool;
+}
+
+impl<T, F> MemoRun<T> for F
+where
+	T: PartialEq,
+	F: SyncBounds + Fn() -> T,
+{
+	fn run(&self) -> T {
+		self()
+	}
+
+	fn is_same(&self, old_value: &T, new_value: &T) -> bool {
+		old_value == new_value
+	}
+}
+
+/// Memo function for [`Memo::new_with_tolerance`]
+pub struct WithTolerance<T, F> {
+	/// Function
+	f: F,
+
+	/// Tolerance
+	tolerance: T,
+}
+
+impl<T, F> MemoRun<T> for WithTolerance<T, F>
+where
+	T: SyncBounds + Copy + PartialOrd + Sub<Output = T>,
+	F: SyncBounds + Fn() -> T,
+{
+	fn run(&self) -> T {
+		(self.f)()
+	}
+
+	fn is_same(&self, old_value: &T, new_value: &T) -> bool {
+		let diff = match old_value > new_value {
+			true => *old_value - *new_value,
+			false => *new_value - *old_value,
+		};
+
+		diff <= self.tolerance
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::{Effect, Signal, SignalGet, SignalSet},
+		dynatos_util::Counter,
+		zutil_cloned::cloned,
+	};
+
+	#[test]
+	#[expect(clippy::float_cmp, reason = "We want to check the exact value stored")]
+	fn tolerance() {
+		/// Counts the number of times the effect was run
+		static TIMES_RUN: Counter = Counter::new();
+
+		let value = Signal::new(1.0_f64);
+
+		#[cloned(value)]
+		let memo = Memo::new_with_tolerance(move || value.get(), 0.5);
+
+		#[cloned(memo)]
+		let _effect = Effect::new(move || {
+			memo.get();
+			TIMES_RUN.bump();
+		});
+		assert_eq!(TIMES_RUN.get(), 1);
+
+		value.set(1.25);
+		assert_eq!(TIMES_RUN.get(), 1, "Memo updated with a change within the tolerance");
+		assert_eq!(memo.get(), 1.0);
+
+		value.set(2.0);
+		assert_eq!(
+			TIMES_RUN.get(),
+			2,
+			"Memo didn't update with a change outside of the tolerance"
+		);
+		assert_eq!(memo.get(), 2.0);
+	}
+
+	#[test]
+	fn changed_last() {
+		let value = Signal::new(1.0_f64);
+
+		#[cloned(value)]
+		let memo = Memo::new_with_tolerance(move || value.get(), 0.5);
+		assert!(memo.changed_last(), "Initial computation should count as a change");
+
+		value.set(1.25);
+		assert!(!memo.changed_last(), "Memo changed with a value within the tolerance");
+
+		value.set(2.0);
+		assert!(
+			memo.changed_last(),
+			"Memo didn't change with a value outside of the tolerance"
+		);
+	}
+}
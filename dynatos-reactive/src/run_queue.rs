@@ -2,10 +2,22 @@
 
 // Imports
 use {
-	crate::{WeakEffect, dep_graph::EffectDepInfo},
+	crate::{
+		Effect,
+		GLOBAL_WORLD,
+		THREAD_WORLD,
+		Trigger,
+		WeakEffect,
+		dep_graph::EffectDepInfo,
+		loc::Loc,
+		trigger::TriggerExec,
+	},
 	core::{
 		cell::RefCell,
+		cmp::Reverse,
+		fmt,
 		hash::{Hash, Hasher},
+		mem,
 	},
 	std::collections::VecDeque,
 };
@@ -18,6 +30,9 @@ struct Item {
 
 	/// Info
 	info: Vec<EffectDepInfo>,
+
+	/// Priority, when pushed
+	priority: u8,
 }
 
 impl PartialEq for Item {
@@ -35,16 +50,37 @@ impl Hash for Item {
 }
 
 /// Inner type for the queue impl
-#[derive(Debug)]
 struct Inner {
 	/// Queue
 	queue: VecDeque<Item>,
 
+	/// Whether the queue is ordered.
+	///
+	/// The queue is only ordered once before popping, unless
+	/// more subscribers are pushed.
+	is_ordered: bool,
+
 	/// Reference count
 	ref_count: usize,
 
 	/// Whether currently executing the queue
 	is_exec: bool,
+
+	/// Callbacks to run once the queue is done executing
+	on_flush_complete: Vec<Box<dyn FnOnce()>>,
+}
+
+#[coverage(off)]
+impl fmt::Debug for Inner {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Inner")
+			.field("queue", &self.queue)
+			.field("is_ordered", &self.is_ordered)
+			.field("ref_count", &self.ref_count)
+			.field("is_exec", &self.is_exec)
+			.field("on_flush_complete", &self.on_flush_complete.len())
+			.finish()
+	}
 }
 
 /// Run queue
@@ -59,9 +95,11 @@ impl RunQueue {
 	pub const fn new() -> Self {
 		Self {
 			inner: RefCell::new(Inner {
-				queue:     VecDeque::new(),
-				ref_count: 0,
-				is_exec:   false,
+				queue:             VecDeque::new(),
+				is_ordered:        true,
+				ref_count:         0,
+				is_exec:           false,
+				on_flush_complete: vec![],
 			}),
 		}
 	}
@@ -93,18 +131,94 @@ impl RunQueue {
 		})
 	}
 
+	/// Starts executing the queue.
+	///
+	/// Returns `None` if the queue is empty, or already being executed.
+	pub fn start_exec(&self) -> Option<ExecGuard<'_>> {
+		let mut inner = self.inner.borrow_mut();
+		(!inner.queue.is_empty() && !inner.is_exec).then(|| {
+			inner.is_exec = true;
+			ExecGuard { run_queue: self }
+		})
+	}
+
 	/// Pushes a subscriber to the queue.
-	pub fn push(&self, sub: WeakEffect, info: Vec<EffectDepInfo>) {
+	///
+	/// The subscriber's priority is taken at this point, so
+	/// any changes to it while queued are ignored.
+	pub fn push(&self, sub: &Effect, info: Vec<EffectDepInfo>) {
 		let mut inner = self.inner.borrow_mut();
 
-		inner.queue.push_back(Item { sub, info });
+		inner.queue.push_back(Item {
+			sub: sub.downgrade(),
+			info,
+			priority: sub.priority(),
+		});
+		inner.is_ordered = false;
 	}
 
-	/// Pops a subscriber from the front of the queue
+	/// Pops the highest priority subscriber from the queue that doesn't
+	/// need to run after any other subscriber in the queue.
+	///
+	/// Subscribers with the same priority are popped in the order they were pushed.
+	///
+	/// See [`Effect::run_after`] and [`Effect::set_priority`] for details.
 	pub fn pop(&self) -> Option<(WeakEffect, Vec<EffectDepInfo>)> {
+		// If the queue isn't ordered, order it.
+		// Note: We take the queue out while ordering it, so that we
+		//       don't keep it borrowed while accessing the dependency graph.
+		let unordered_queue = {
+			let mut inner = self.inner.borrow_mut();
+			(!inner.is_ordered).then(|| mem::take(&mut inner.queue))
+		};
+		if let Some(queue) = unordered_queue {
+			let queue = self::order_queue(queue);
+
+			let mut inner = self.inner.borrow_mut();
+			inner.queue = queue;
+			inner.is_ordered = true;
+		}
+
 		let item = self.inner.borrow_mut().queue.pop_front()?;
 		Some((item.sub, item.info))
 	}
+
+	/// Adds a callback to run once the queue is done executing
+	pub fn push_on_flush_complete(&self, f: Box<dyn FnOnce()>) {
+		self.inner.borrow_mut().on_flush_complete.push(f);
+	}
+
+	/// Takes all callbacks to run once the queue is done executing
+	pub fn take_on_flush_complete(&self) -> Vec<Box<dyn FnOnce()>> {
+		mem::take(&mut self.inner.borrow_mut().on_flush_complete)
+	}
+}
+
+/// Orders a queue.
+///
+/// Each subscriber is placed after any subscribers it must run after, then by
+/// highest priority, and then in the order they were pushed.
+fn order_queue(queue: VecDeque<Item>) -> VecDeque<Item> {
+	let subs = queue.iter().map(|item| item.sub.clone()).collect::<Vec<_>>();
+	let runs_after = GLOBAL_WORLD.dep_graph().effects_run_after(&subs);
+	let priorities = queue.iter().map(|item| item.priority).collect::<Vec<_>>();
+
+	let mut items = queue.into_iter().map(Some).collect::<Vec<_>>();
+	let mut ordered_queue = VecDeque::with_capacity(items.len());
+	while let Some(first_idx) = items.iter().position(Option::is_some) {
+		// Note: Orderings can't have cycles, but if we somehow don't find any
+		//       subscriber to run, just run the first one.
+		let idx = (first_idx..items.len())
+			.filter(|&idx| items[idx].is_some())
+			.filter(|&idx| runs_after[idx].iter().all(|&before_idx| items[before_idx].is_none()))
+			.min_by_key(|&idx| Reverse(priorities[idx]))
+			.unwrap_or(first_idx);
+
+		let item = items[idx].take().expect("Item should exist");
+		ordered_queue.push_back(item);
+	}
+
+	ordered_queue
 }
 
 #[coverage(off)]
@@ -120,9 +234,95 @@ pub struct ExecGuard<'a> {
 	run_queue: &'a RunQueue,
 }
 
+impl ExecGuard<'_> {
+	/// Runs all effects in the queue until it's empty, and then
+	/// any callbacks registered with [`on_flush_complete`].
+	///
+	/// `trace` is called before running each effect.
+	pub fn run<F>(self, trace: F)
+	where
+		F: Fn(&Effect, &[EffectDepInfo]),
+	{
+		let run_queue = self.run_queue;
+		while let Some((sub, sub_info)) = run_queue.pop() {
+			let Some(effect) = sub.upgrade() else {
+				continue;
+			};
+
+			trace(&effect, &sub_info);
+			effect.run();
+		}
+
+		// Finally, once we're done executing, run any callbacks.
+		// Note: We drop the guard first so that any triggers executed by
+		//       the callbacks start a new execution of the run queue.
+		drop(self);
+		for f in run_queue.take_on_flush_complete() {
+			f();
+		}
+	}
+}
+
 impl Drop for ExecGuard<'_> {
 	fn drop(&mut self) {
 		let mut inner = self.run_queue.inner.borrow_mut();
 		inner.is_exec = false;
 	}
 }
+
+/// Registers a callback to run once the current (or next)
+/// run queue execution finishes running all effects.
+///
+/// Callbacks are run in the order they're registered, and only once.
+pub fn on_flush_complete<F>(f: F)
+where
+	F: FnOnce() + 'static,
+{
+	THREAD_WORLD.run_queue().push_on_flush_complete(Box::new(f));
+}
+
+/// Runs all effects in the run queue, unless it's already being executed.
+///
+/// Normally the run queue is executed once the last trigger execution is
+/// dropped, so this is mostly useful within [`defer_effects`], for testing
+/// intermediate states, or to end a batch early.
+#[track_caller]
+pub fn flush() {
+	let flush_loc = Loc::caller();
+	let Some(exec_guard) = THREAD_WORLD.run_queue().start_exec() else {
+		return;
+	};
+
+	exec_guard.run(|effect, _| {
+		tracing::trace!(
+			"Running effect due to flush\nEffect   : {}\nFlush    : {flush_loc}",
+			effect.defined_loc()
+		);
+	});
+}
+
+/// Defers running any effects until the returned guard is dropped.
+///
+/// Any triggers executed while the guard is alive only add their subscribers
+/// to the run queue, which may be executed early with [`flush`].
+///
+/// This is mostly useful for tests and for batching several changes together.
+pub fn defer_effects() -> DeferEffects {
+	DeferEffects {
+		_trigger_exec: Trigger::exec_noop(),
+	}
+}
+
+/// Guard returned by [`defer_effects`]
+#[must_use = "Effects are only deferred while the guard is alive"]
+pub struct DeferEffects {
+	/// Trigger execution keeping the run queue from being executed
+	_trigger_exec: Option<TriggerExec>,
+}
+
+#[coverage(off)]
+impl fmt::Debug for DeferEffects {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("DeferEffects").finish_non_exhaustive()
+	}
+}
@@ -16,6 +16,7 @@ pub use ops::{
 	SignalGetClonedDefaultImpl,
 	SignalGetCopy,
 	SignalGetDefaultImpl,
+	SignalGetRc,
 	SignalReplace,
 	SignalSet,
 	SignalSetDefaultImpl,
@@ -24,18 +25,20 @@ pub use ops::{
 	SignalUpdateDefaultImpl,
 	SignalWith,
 	SignalWithDefaultImpl,
+	set_all,
 };
 
 // Imports
 use {
-	crate::{Trigger, trigger::TriggerExec},
+	crate::{Derived, Effect, Trigger, derived::DerivedRun, trigger::TriggerExec},
 	core::{
 		fmt,
 		marker::Unsize,
 		mem,
 		ops::{CoerceUnsized, Deref, DerefMut},
 	},
-	dynatos_sync_types::{IMutRw, IMutRwRef, IMutRwRefMut, RcPtr},
+	dynatos_sync_types::{IMut, IMutRw, IMutRwRef, IMutRwRefMut, RcPtr, SyncBounds},
+	std::{rc::Rc, sync::Arc},
 };
 
 /// Inner
@@ -65,6 +68,89 @@ impl<T> Signal<T> {
 			inner: RcPtr::new(inner),
 		}
 	}
+
+	/// Creates a new signal with the current value of `src`.
+	///
+	/// `src` is read without gathering any dependencies, and
+	/// the new signal isn't kept in sync with it afterwards.
+	#[track_caller]
+	pub fn from_signal<S>(src: &S) -> Self
+	where
+		S: SignalGetCloned<Value = T>,
+	{
+		Self::new(src.get_cloned_no_dep())
+	}
+}
+
+impl<T: Clone + SyncBounds + 'static> Signal<T> {
+	/// Creates a derived signal with a shared clone of this signal's value.
+	///
+	/// Each time this signal changes, the value is cloned once into an [`RcPtr`].
+	/// Consumers of the derived signal then only clone the pointer, which is
+	/// cheaper than each cloning the value, if it's expensive to clone.
+	#[must_use]
+	#[track_caller]
+	pub fn shared_clone_memo(&self) -> Derived<RcPtr<T>, impl DerivedRun<RcPtr<T>> + use<T>> {
+		let signal = self.clone();
+		Derived::new(move || RcPtr::new(signal.borrow().clone()))
+	}
+}
+
+impl<T: ?Sized + SyncBounds + 'static> Signal<T> {
+	/// Subscribes to this signal from outside of an effect.
+	///
+	/// Calls `f` with the current value, and then again each time
+	/// this signal changes, until the returned subscription is dropped.
+	#[track_caller]
+	pub fn subscribe<F>(&self, f: F) -> Subscription
+	where
+		F: FnMut(&T) + SyncBounds + 'static,
+	{
+		let signal = self.clone();
+		let f = IMut::new(f);
+		let effect = Effect::new(move || signal.with(|value| (f.lock())(value)));
+
+		Subscription {
+			_effect: effect.unsize(),
+		}
+	}
+}
+
+impl<T: ?Sized> Signal<T> {
+	/// Updates this signal's value, returning a value to the caller.
+	///
+	/// `f` returns whether it changed the value, along with the value to return.
+	/// If it didn't change the value, no subscribers are run.
+	///
+	/// This is useful when only part of the value (e.g. a single field) might
+	/// change, and the caller knows whether it did.
+	#[track_caller]
+	pub fn update_returning<F, R>(&self, f: F) -> R
+	where
+		F: FnOnce(&mut T) -> (bool, R),
+	{
+		let mut value = self.inner.value.write();
+		let (changed, output) = f(&mut value);
+
+		// Note: The value must be unlocked before executing the trigger, since
+		//       any subscribers will want to borrow it.
+		drop(value);
+		if changed {
+			drop(self.inner.trigger.exec());
+		}
+
+		output
+	}
+}
+
+/// Subscription to a signal.
+///
+/// See [`Signal::subscribe`] for details.
+#[must_use = "The subscription is cancelled when dropped"]
+#[derive(Debug)]
+pub struct Subscription {
+	/// Effect calling the subscriber
+	_effect: Effect,
 }
 
 // TODO: Add `Signal::<dyn Any>::downcast` once we add `{T, U}: ?Sized` to the `CoerceUnsized` impl of `Inner`.
@@ -173,6 +259,19 @@ impl<T: ?Sized + 'static> SignalBorrowMut for Signal<T> {
 }
 
 
+#[duplicate::duplicate_item(
+	RcTy;
+	[Rc];
+	[Arc];
+)]
+impl<T: ?Sized + 'static> SignalGetRc for Signal<RcTy<T>> {
+	type Ptr = RcTy<T>;
+
+	fn get_rc(&self) -> Self::Ptr {
+		RcTy::clone(&self.borrow())
+	}
+}
+
 impl<T: ?Sized> SignalSetDefaultImpl for Signal<T> {}
 impl<T: ?Sized> SignalGetDefaultImpl for Signal<T> {}
 impl<T: ?Sized> SignalGetClonedDefaultImpl for Signal<T> {}
@@ -200,7 +299,30 @@ impl<T: ?Sized + fmt::Debug> fmt::Debug for Signal<T> {
 #[cfg(test)]
 mod tests {
 	// Imports
-	use {super::*, crate::Effect, zutil_cloned::cloned};
+	use {super::*, dynatos_util::Counter, zutil_cloned::cloned};
+
+	#[test]
+	fn from_signal() {
+		let src = Signal::new(5_usize);
+
+		// Counts the number of times the effect was run
+		static TIMES_RUN: Counter = Counter::new();
+		#[cloned(src)]
+		let _effect = Effect::new(move || {
+			let signal = Signal::from_signal(&src);
+			assert_eq!(signal.get(), 5);
+			TIMES_RUN.bump();
+		});
+		assert_eq!(TIMES_RUN.get(), 1);
+
+		let signal = Signal::from_signal(&src);
+		src.set(6);
+		assert_eq!(TIMES_RUN.get(), 1, "Creating the signal gathered a dependency");
+		assert_eq!(signal.get(), 5);
+
+		signal.set(7);
+		assert_eq!(src.get(), 6);
+	}
 
 	#[test]
 	fn multiple_mut() {
@@ -216,4 +338,135 @@ mod tests {
 		let _a = a.borrow_mut();
 		let _b = b.borrow_mut();
 	}
+
+	#[test]
+	fn set_all_runs_once() {
+		/// Counts the number of times the effect was run
+		static TIMES_RUN: Counter = Counter::new();
+
+		let signals = [Signal::new(1_i32), Signal::new(2_i32), Signal::new(3_i32)];
+
+		#[cloned(signals)]
+		let _effect = Effect::new(move || {
+			for signal in &signals {
+				signal.get();
+			}
+			TIMES_RUN.bump();
+		});
+		assert_eq!(TIMES_RUN.get(), 1);
+
+		set_all(&signals, [4, 5, 6]);
+		assert_eq!(TIMES_RUN.get(), 2, "Effect should only run once for all sets");
+		assert_eq!(signals.each_ref().map(Signal::get), [4, 5, 6]);
+
+		// Mismatched lengths only set the shorter of the two
+		set_all(&signals, [7]);
+		assert_eq!(TIMES_RUN.get(), 3);
+		assert_eq!(signals.each_ref().map(Signal::get), [7, 5, 6]);
+	}
+
+	#[test]
+	fn shared_clone_memo() {
+		/// Counts the number of times the value was cloned
+		static TIMES_CLONED: Counter = Counter::new();
+
+		/// Value that counts its clones
+		struct Value(i32);
+
+		impl Clone for Value {
+			fn clone(&self) -> Self {
+				TIMES_CLONED.bump();
+				Self(self.0)
+			}
+		}
+
+		let signal = Signal::new(Value(1));
+		let shared = signal.shared_clone_memo();
+		assert_eq!(TIMES_CLONED.get(), 1);
+
+		let consumers = [(); 3].map(|()| {
+			#[cloned(shared)]
+			Effect::new(move || _ = shared.get_cloned().0)
+		});
+		assert_eq!(TIMES_CLONED.get(), 1, "Consumers shouldn't clone the value");
+
+		signal.set(Value(2));
+		assert_eq!(TIMES_CLONED.get(), 2, "Value should only be cloned once per change");
+		assert_eq!(shared.get_cloned().0, 2);
+
+		drop(consumers);
+	}
+
+	#[test]
+	fn get_rc() {
+		let signal = Signal::new(Rc::new([0_u8; 64]));
+
+		let rc1 = signal.get_rc();
+		let rc2 = signal.get_rc();
+		assert!(Rc::ptr_eq(&rc1, &rc2), "Value was cloned");
+		assert_eq!(Rc::strong_count(&rc1), 3);
+
+		let signal = Signal::new(Arc::<str>::from("Hello"));
+		assert!(
+			Arc::ptr_eq(&signal.get_rc(), &signal.get_rc_no_dep()),
+			"Value was cloned"
+		);
+	}
+
+	#[test]
+	fn update_returning() {
+		/// Counts the number of times the effect was run
+		static TIMES_RUN: Counter = Counter::new();
+
+		#[derive(Clone, Copy, Debug)]
+		struct Value {
+			a: i32,
+			b: i32,
+		}
+
+		let signal = Signal::new(Value { a: 1, b: 2 });
+
+		#[cloned(signal)]
+		let _effect = Effect::new(move || {
+			_ = signal.get();
+			TIMES_RUN.bump();
+		});
+		assert_eq!(TIMES_RUN.get(), 1);
+
+		// Setting a field to its current value doesn't run the effect
+		let set_a = |new_a: i32| {
+			signal.update_returning(|value| {
+				let prev_a = mem::replace(&mut value.a, new_a);
+				(prev_a != new_a, prev_a)
+			})
+		};
+		assert_eq!(set_a(1), 1);
+		assert_eq!(TIMES_RUN.get(), 1, "Effect ran without any changes");
+
+		assert_eq!(set_a(3), 1);
+		assert_eq!(TIMES_RUN.get(), 2, "Effect didn't run after a change");
+		assert_eq!(set_a(3), 3);
+		assert_eq!(TIMES_RUN.get(), 2, "Effect ran without any changes");
+
+		let value = signal.get();
+		assert_eq!((value.a, value.b), (3, 2));
+	}
+
+	#[test]
+	fn subscribe() {
+		let signal = Signal::new(1_i32);
+		let values = RcPtr::new(IMut::new(vec![]));
+
+		#[cloned(values)]
+		let subscription = signal.subscribe(move |&value| values.lock().push(value));
+		assert_eq!(*values.lock(), [1]);
+
+		signal.set(2);
+		signal.set(3);
+		assert_eq!(*values.lock(), [1, 2, 3]);
+
+		drop(subscription);
+		signal.set(4);
+		assert_eq!(*values.lock(), [1, 2, 3], "Subscriber was called after being dropped");
+	}
 }
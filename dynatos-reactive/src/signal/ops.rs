@@ -5,6 +5,7 @@ mod borrow;
 mod borrow_mut;
 mod get;
 mod get_cloned;
+mod get_rc;
 mod replace;
 mod set;
 mod update;
@@ -16,8 +17,9 @@ pub use self::{
 	borrow_mut::SignalBorrowMut,
 	get::{SignalGet, SignalGetCopy, SignalGetDefaultImpl},
 	get_cloned::{SignalGetClone, SignalGetCloned, SignalGetClonedDefaultImpl},
+	get_rc::SignalGetRc,
 	replace::SignalReplace,
-	set::{SignalSet, SignalSetDefaultImpl, SignalSetWith},
+	set::{SignalSet, SignalSetDefaultImpl, SignalSetWith, set_all},
 	update::{SignalUpdate, SignalUpdateDefaultImpl},
 	with::{SignalWith, SignalWithDefaultImpl},
 };
@@ -0,0 +1,24 @@
+//! [`SignalGetRc`]
+
+// Imports
+use crate::effect;
+
+/// Signal get reference-counted pointer.
+///
+/// Implemented by signals that store a reference-counted pointer,
+/// such as `Signal<Rc<T>>`, to get a new reference to the shared value
+/// without cloning it.
+pub trait SignalGetRc {
+	/// Pointer type
+	type Ptr;
+
+	/// Gets a new reference to the shared value.
+	#[track_caller]
+	fn get_rc(&self) -> Self::Ptr;
+
+	/// Gets a new reference to the shared value without gathering dependencies.
+	#[track_caller]
+	fn get_rc_no_dep(&self) -> Self::Ptr {
+		effect::with_no_dep(|| self.get_rc())
+	}
+}
@@ -47,6 +47,27 @@ where
 	}
 }
 
+/// Sets all signals in `signals` to `values`.
+///
+/// All sets happen under a single run queue flush, so any effects
+/// depending on multiple of the signals only run once.
+///
+/// If `signals` and `values` have different lengths, only the shorter
+/// of the two is written.
+#[track_caller]
+pub fn set_all<S, T>(signals: &[S], values: impl IntoIterator<Item = T>)
+where
+	S: SignalSet<T>,
+{
+	// Note: We use a no-op exec to ensure that we only run the queue once
+	//       during all of the sets.
+	let _exec = Trigger::exec_noop();
+
+	for (signal, value) in signals.iter().zip(values) {
+		signal.set(value);
+	}
+}
+
 macro impl_tuple($($S:ident : $T:ident),* $(,)?) {
 	#[allow(clippy::allow_attributes, non_snake_case, reason = "Macro generated code")]
 	impl<$( $S, $T, )*> SignalSet<( $( $T, )* )> for ( $( &'_ $S, )* )
@@ -0,0 +1,522 @@
+//! Vector signal
+//!
+//! A vector signal that, besides updating subscribers
+//! when changed, also reports each change made to it.
+
+// Imports
+use {
+	crate::{
+		SignalBorrow,
+		SignalGetClonedDefaultImpl,
+		SignalGetDefaultImpl,
+		SignalSetDefaultImpl,
+		SignalUpdateDefaultImpl,
+		SignalWithDefaultImpl,
+		Trigger,
+	},
+	core::{fmt, mem, ops::Deref},
+	dynatos_sync_types::{IMut, IMutRw, IMutRwRef, RcPtr, WeakRcPtr},
+};
+
+/// A change to a vector
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum VecDiff<T> {
+	/// Inserts `value` at `idx`
+	Insert { idx: usize, value: T },
+
+	/// Removes the value at `idx`
+	Remove { idx: usize },
+
+	/// Moves the value at `from` to `to`.
+	///
+	/// This is equivalent to removing the value at `from`
+	/// and then inserting it at `to`.
+	Move { from: usize, to: usize },
+
+	/// Replaces the value at `idx` with `value`
+	Replace { idx: usize, value: T },
+
+	/// Removes all values
+	Clear,
+}
+
+impl<T> VecDiff<T> {
+	/// Applies this change to `values`.
+	///
+	/// # Panics
+	/// Panics if any indices are out of bounds.
+	pub fn apply(self, values: &mut Vec<T>) {
+		match self {
+			Self::Insert { idx, value } => values.insert(idx, value),
+			Self::Remove { idx } => _ = values.remove(idx),
+			Self::Move { from, to } => {
+				let value = values.remove(from);
+				values.insert(to, value);
+			},
+			Self::Replace { idx, value } => values[idx] = value,
+			Self::Clear => values.clear(),
+		}
+	}
+}
+
+/// Changes not yet taken by a reader
+type Diffs<T> = IMut<Vec<VecDiff<T>>>;
+
+/// Inner
+struct Inner<T> {
+	/// Values
+	values: IMutRw<Vec<T>>,
+
+	/// Trigger for the values
+	trigger: Trigger,
+
+	/// Trigger for the changes
+	diffs_trigger: Trigger,
+
+	/// Change readers
+	readers: IMut<Vec<WeakRcPtr<Diffs<T>>>>,
+}
+
+/// Vector signal.
+///
+/// Unlike a `Signal<Vec<T>>`, this signal may only be modified through
+/// its methods, each of which reports the change as a [`VecDiff`] to
+/// all readers created by [`diffs`](Self::diffs).
+///
+/// Reading the whole vector is done through [`SignalBorrow`] and friends,
+/// and subscribers of it are updated on every change, as usual.
+pub struct SignalVec<T> {
+	/// Inner
+	inner: RcPtr<Inner<T>>,
+}
+
+impl<T> SignalVec<T> {
+	/// Creates a new vector signal
+	#[must_use]
+	#[track_caller]
+	pub fn new(values: Vec<T>) -> Self {
+		let inner = Inner {
+			values:        IMutRw::new(values),
+			trigger:       Trigger::new(),
+			diffs_trigger: Trigger::new(),
+			readers:       IMut::new(vec![]),
+		};
+		Self {
+			inner: RcPtr::new(inner),
+		}
+	}
+
+	/// Creates a reader of all changes made to this signal from now on.
+	#[must_use]
+	pub fn diffs(&self) -> VecDiffs<T> {
+		let diffs = RcPtr::new(IMut::new(vec![]));
+		self.inner.readers.lock().push(RcPtr::downgrade(&diffs));
+
+		VecDiffs {
+			diffs,
+			trigger: self.inner.diffs_trigger.clone(),
+		}
+	}
+}
+
+impl<T: 'static> SignalVec<T> {
+	/// Returns the number of values.
+	///
+	/// Gathers a dependency on the whole vector.
+	#[must_use]
+	#[track_caller]
+	pub fn len(&self) -> usize {
+		self.borrow().len()
+	}
+
+	/// Returns if there are no values.
+	///
+	/// Gathers a dependency on the whole vector.
+	#[must_use]
+	#[track_caller]
+	pub fn is_empty(&self) -> bool {
+		self.borrow().is_empty()
+	}
+}
+
+impl<T: Clone> SignalVec<T> {
+	/// Pushes a value to the end
+	#[track_caller]
+	pub fn push(&self, value: T) {
+		self.modify(|values| {
+			let idx = values.len();
+			values.push(value.clone());
+			((), vec![VecDiff::Insert { idx, value }])
+		});
+	}
+
+	/// Pops a value from the end
+	#[must_use]
+	#[track_caller]
+	pub fn pop(&self) -> Option<T> {
+		self.modify(|values| match values.pop() {
+			Some(value) => (Some(value), vec![VecDiff::Remove { idx: values.len() }]),
+			None => (None, vec![]),
+		})
+	}
+
+	/// Inserts a value at `idx`.
+	///
+	/// # Panics
+	/// Panics if `idx > len`.
+	#[track_caller]
+	pub fn insert(&self, idx: usize, value: T) {
+		self.modify(|values| {
+			values.insert(idx, value.clone());
+			((), vec![VecDiff::Insert { idx, value }])
+		});
+	}
+
+	/// Removes the value at `idx`.
+	///
+	/// # Panics
+	/// Panics if `idx` is out of bounds.
+	#[must_use]
+	#[track_caller]
+	pub fn remove(&self, idx: usize) -> T {
+		self.modify(|values| (values.remove(idx), vec![VecDiff::Remove { idx }]))
+	}
+
+	/// Replaces the value at `idx`, returning the previous value.
+	///
+	/// # Panics
+	/// Panics if `idx` is out of bounds.
+	#[track_caller]
+	pub fn replace(&self, idx: usize, value: T) -> T {
+		self.modify(|values| {
+			let prev_value = mem::replace(&mut values[idx], value.clone());
+			(prev_value, vec![VecDiff::Replace { idx, value }])
+		})
+	}
+
+	/// Swaps the values at `lhs` and `rhs`.
+	///
+	/// This is reported as up to two [`VecDiff::Move`]s.
+	///
+	/// # Panics
+	/// Panics if either index is out of bounds.
+	#[track_caller]
+	pub fn swap(&self, lhs: usize, rhs: usize) {
+		self.modify(|values| {
+			values.swap(lhs, rhs);
+
+			let (first, second) = (lhs.min(rhs), lhs.max(rhs));
+			let diffs = match second - first {
+				0 => vec![],
+				1 => vec![VecDiff::Move {
+					from: second,
+					to:   first,
+				}],
+				_ => vec![
+					VecDiff::Move {
+						from: second,
+						to:   first,
+					},
+					VecDiff::Move {
+						from: first + 1,
+						to:   second,
+					},
+				],
+			};
+
+			((), diffs)
+		});
+	}
+
+	/// Removes all values
+	#[track_caller]
+	pub fn clear(&self) {
+		self.modify(|values| {
+			values.clear();
+			((), vec![VecDiff::Clear])
+		});
+	}
+
+	/// Modifies the values with `f`, reporting all changes it returns.
+	///
+	/// If no changes are returned, no subscribers are updated.
+	#[track_caller]
+	fn modify<F, O>(&self, f: F) -> O
+	where
+		F: FnOnce(&mut Vec<T>) -> (O, Vec<VecDiff<T>>),
+	{
+		let (output, diffs) = f(&mut self.inner.values.write());
+		if diffs.is_empty() {
+			return output;
+		}
+
+		// Send the changes to all readers, removing any that no longer exist
+		self.inner.readers.lock().retain(|reader| {
+			let Some(reader) = reader.upgrade() else {
+				return false;
+			};
+
+			reader.lock().extend(diffs.iter().cloned());
+			true
+		});
+
+		// Note: We execute both triggers together, so any subscribers
+		//       of both only run once.
+		let _trigger_exec = self.inner.trigger.exec();
+		let _diffs_trigger_exec = self.inner.diffs_trigger.exec();
+
+		output
+	}
+}
+
+impl<T> Clone for SignalVec<T> {
+	fn clone(&self) -> Self {
+		Self {
+			inner: RcPtr::clone(&self.inner),
+		}
+	}
+}
+
+#[coverage(off)]
+impl<T: fmt::Debug> fmt::Debug for SignalVec<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("SignalVec")
+			.field("values", &*self.inner.values.read())
+			.field("trigger", &self.inner.trigger)
+			.field("diffs_trigger", &self.inner.diffs_trigger)
+			.finish_non_exhaustive()
+	}
+}
+
+/// Reference type for [`SignalBorrow`] impl
+pub struct BorrowRef<'a, T: 'a>(IMutRwRef<'a, Vec<T>>);
+
+impl<T> Deref for BorrowRef<'_, T> {
+	type Target = Vec<T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+#[coverage(off)]
+impl<T: fmt::Debug> fmt::Debug for BorrowRef<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("BorrowRef").field(&*self.0).finish()
+	}
+}
+
+impl<T: 'static> SignalBorrow for SignalVec<T> {
+	type Ref<'a>
+		= BorrowRef<'a, T>
+	where
+		Self: 'a;
+
+	fn borrow(&self) -> Self::Ref<'_> {
+		self.inner.trigger.gather_subs();
+
+		let values = self.inner.values.read();
+		BorrowRef(values)
+	}
+}
+
+impl<T> SignalGetClonedDefaultImpl for SignalVec<T> {}
+impl<T> SignalWithDefaultImpl for SignalVec<T> {}
+
+// Note: Modifying the values must go through the methods, so that all changes are reported.
+impl<T> !SignalGetDefaultImpl for SignalVec<T> {}
+impl<T> !SignalSetDefaultImpl for SignalVec<T> {}
+impl<T> !SignalUpdateDefaultImpl for SignalVec<T> {}
+
+/// Reader of the changes of a [`SignalVec`].
+///
+/// Created by [`SignalVec::diffs`].
+pub struct VecDiffs<T> {
+	/// Changes not yet taken
+	diffs: RcPtr<Diffs<T>>,
+
+	/// Trigger for the changes
+	trigger: Trigger,
+}
+
+impl<T> VecDiffs<T> {
+	/// Takes all changes since the last call.
+	///
+	/// Gathers a dependency on the changes, so that any effect calling this
+	/// will be re-run after each change.
+	#[must_use]
+	#[track_caller]
+	pub fn take(&self) -> Vec<VecDiff<T>> {
+		self.trigger.gather_subs();
+		mem::take(&mut *self.diffs.lock())
+	}
+}
+
+#[coverage(off)]
+impl<T: fmt::Debug> fmt::Debug for VecDiffs<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("VecDiffs")
+			.field("diffs", &*self.diffs.lock())
+			.field("trigger", &self.trigger)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use {super::*, crate::Effect, dynatos_util::Counter, zutil_cloned::cloned};
+
+	/// Takes all changes from `diffs`, checking that they transform `mirror` into the values of `signal`.
+	fn take_checked(signal: &SignalVec<char>, diffs: &VecDiffs<char>, mirror: &mut Vec<char>) -> Vec<VecDiff<char>> {
+		let diffs = diffs.take();
+		for diff in diffs.clone() {
+			diff.apply(mirror);
+		}
+		assert_eq!(
+			*mirror,
+			*signal.borrow(),
+			"Changes didn't result in the same values: {diffs:?}"
+		);
+
+		diffs
+	}
+
+	#[test]
+	fn push_pop() {
+		let signal = SignalVec::new(vec!['a']);
+		let diffs = signal.diffs();
+		let mut mirror = vec!['a'];
+
+		signal.push('b');
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [VecDiff::Insert {
+			idx:   1,
+			value: 'b',
+		}]);
+
+		assert_eq!(signal.pop(), Some('b'));
+		assert_eq!(signal.pop(), Some('a'));
+		assert_eq!(signal.pop(), None);
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [
+			VecDiff::Remove { idx: 1 },
+			VecDiff::Remove { idx: 0 }
+		]);
+	}
+
+	#[test]
+	fn insert_remove() {
+		let signal = SignalVec::new(vec!['a', 'c']);
+		let diffs = signal.diffs();
+		let mut mirror = vec!['a', 'c'];
+
+		signal.insert(1, 'b');
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [VecDiff::Insert {
+			idx:   1,
+			value: 'b',
+		}]);
+
+		assert_eq!(signal.remove(0), 'a');
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [VecDiff::Remove { idx: 0 }]);
+		assert_eq!(mirror, ['b', 'c']);
+	}
+
+	#[test]
+	fn replace() {
+		let signal = SignalVec::new(vec!['a', 'b']);
+		let diffs = signal.diffs();
+		let mut mirror = vec!['a', 'b'];
+
+		assert_eq!(signal.replace(1, 'c'), 'b');
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [VecDiff::Replace {
+			idx:   1,
+			value: 'c',
+		}]);
+	}
+
+	#[test]
+	fn swap() {
+		let signal = SignalVec::new(vec!['a', 'b', 'c', 'd']);
+		let diffs = signal.diffs();
+		let mut mirror = vec!['a', 'b', 'c', 'd'];
+
+		signal.swap(0, 3);
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [
+			VecDiff::Move { from: 3, to: 0 },
+			VecDiff::Move { from: 1, to: 3 }
+		]);
+		assert_eq!(mirror, ['d', 'b', 'c', 'a']);
+
+		signal.swap(2, 1);
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [VecDiff::Move {
+			from: 2,
+			to:   1,
+		}]);
+		assert_eq!(mirror, ['d', 'c', 'b', 'a']);
+
+		signal.swap(2, 2);
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), []);
+	}
+
+	#[test]
+	fn clear() {
+		let signal = SignalVec::new(vec!['a', 'b']);
+		let diffs = signal.diffs();
+		let mut mirror = vec!['a', 'b'];
+
+		signal.clear();
+		assert_eq!(take_checked(&signal, &diffs, &mut mirror), [VecDiff::Clear]);
+		assert!(signal.is_empty());
+	}
+
+	#[test]
+	fn readers() {
+		let signal = SignalVec::new(vec![]);
+		let diffs1 = signal.diffs();
+		signal.push('a');
+
+		// Readers only see changes made after they were created
+		let diffs2 = signal.diffs();
+		signal.push('b');
+		assert_eq!(diffs1.take(), [
+			VecDiff::Insert { idx: 0, value: 'a' },
+			VecDiff::Insert { idx: 1, value: 'b' }
+		]);
+		assert_eq!(diffs2.take(), [VecDiff::Insert { idx: 1, value: 'b' }]);
+		assert_eq!(diffs1.take(), []);
+
+		// Dropped readers are removed
+		drop(diffs2);
+		signal.push('c');
+		assert_eq!(signal.inner.readers.lock().len(), 1);
+	}
+
+	#[test]
+	fn effects() {
+		let signal = SignalVec::new(vec![]);
+		let diffs = signal.diffs();
+
+		// Counts the number of times each effect was run
+		static TIMES_RUN_DIFFS: Counter = Counter::new();
+		static TIMES_RUN_VALUES: Counter = Counter::new();
+
+		let _effect_diffs = Effect::new(move || {
+			_ = diffs.take();
+			TIMES_RUN_DIFFS.bump();
+		});
+
+		#[cloned(signal)]
+		let _effect_values = Effect::new(move || {
+			_ = signal.len();
+			TIMES_RUN_VALUES.bump();
+		});
+
+		assert_eq!([TIMES_RUN_DIFFS.get(), TIMES_RUN_VALUES.get()], [1, 1]);
+
+		signal.push('a');
+		assert_eq!([TIMES_RUN_DIFFS.get(), TIMES_RUN_VALUES.get()], [2, 2]);
+
+		// Operations without any changes don't run any effects
+		signal.swap(0, 0);
+		assert_eq!([TIMES_RUN_DIFFS.get(), TIMES_RUN_VALUES.get()], [2, 2]);
+	}
+}
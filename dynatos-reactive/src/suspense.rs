@@ -0,0 +1,139 @@
+//! Suspense context
+
+// Imports
+use {
+	crate::{Signal, SignalGet, SignalUpdate, THREAD_WORLD},
+	core::cell::RefCell,
+};
+
+/// Suspense context.
+///
+/// Tracks how many async signals created within it are currently loading,
+/// so a single "loading" view may be shown for all of them.
+///
+/// Async signals (and anything built on top of them) are registered with the
+/// context that was current when they were created, see [`SuspenseContext::provide`].
+#[derive(Clone, Debug)]
+pub struct SuspenseContext {
+	/// Number of pending async signals
+	pending: Signal<usize>,
+}
+
+impl SuspenseContext {
+	/// Creates a new suspense context, with nothing pending.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			pending: Signal::new(0),
+		}
+	}
+
+	/// Calls `f` with this context as the current one.
+	///
+	/// Any async signals created within `f` will be registered with this context.
+	pub fn provide<F, O>(&self, f: F) -> O
+	where
+		F: FnOnce() -> O,
+	{
+		THREAD_WORLD.suspense_stack().push(self.clone());
+
+		// Note: We pop the context in a guard, so that it's popped even if `f` panics.
+		let _guard = ProvideGuard;
+		f()
+	}
+
+	/// Returns the current context, if any.
+	#[must_use]
+	pub fn current() -> Option<Self> {
+		THREAD_WORLD.suspense_stack().top()
+	}
+
+	/// Returns the number of pending async signals.
+	///
+	/// Gathers a dependency on the number of pending async signals.
+	#[must_use]
+	#[track_caller]
+	pub fn pending(&self) -> usize {
+		self.pending.get()
+	}
+
+	/// Returns if any async signals are pending.
+	///
+	/// Gathers a dependency on the number of pending async signals.
+	#[must_use]
+	#[track_caller]
+	pub fn is_pending(&self) -> bool {
+		self.pending() != 0
+	}
+
+	/// Adds a pending async signal
+	#[track_caller]
+	pub(crate) fn add_pending(&self) {
+		self.pending.update(|pending| *pending += 1);
+	}
+
+	/// Removes a pending async signal
+	#[track_caller]
+	pub(crate) fn remove_pending(&self) {
+		self.pending.update(|pending| {
+			*pending = pending
+				.checked_sub(1)
+				.expect("Removed more pending signals than were added");
+		});
+	}
+}
+
+#[coverage(off)]
+impl Default for SuspenseContext {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Guard that pops the current suspense context once dropped
+struct ProvideGuard;
+
+impl Drop for ProvideGuard {
+	fn drop(&mut self) {
+		THREAD_WORLD.suspense_stack().pop();
+	}
+}
+
+/// Suspense context stack
+#[derive(Debug)]
+pub struct SuspenseStack {
+	/// Stack
+	stack: RefCell<Vec<SuspenseContext>>,
+}
+
+impl SuspenseStack {
+	/// Creates a new, empty, suspense stack
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			stack: RefCell::new(vec![]),
+		}
+	}
+
+	/// Pushes a context to the stack.
+	pub fn push(&self, ctx: SuspenseContext) {
+		self.stack.borrow_mut().push(ctx);
+	}
+
+	/// Pops a context from the stack
+	pub fn pop(&self) {
+		self.stack.borrow_mut().pop().expect("Missing added suspense context");
+	}
+
+	/// Returns the top context of the stack
+	pub fn top(&self) -> Option<SuspenseContext> {
+		self.stack.borrow().last().cloned()
+	}
+}
+
+#[coverage(off)]
+impl Default for SuspenseStack {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -5,7 +5,7 @@
 
 // Imports
 use {
-	crate::{GLOBAL_WORLD, THREAD_WORLD, effect, loc::Loc, world::WorldTag},
+	crate::{Effect, EffectRun, GLOBAL_WORLD, THREAD_WORLD, effect, loc::Loc, world::WorldTag},
 	core::{
 		fmt,
 		hash::{Hash, Hasher},
@@ -76,9 +76,7 @@ impl Trigger {
 		match effect::running() {
 			Some(effect) => GLOBAL_WORLD.dep_graph().add_effect_dep(&effect, self),
 
-			// TODO: Add some way to turn off this warning at a global
-			//       scale, with something like
-			//       `fn without_warning(f: impl FnOnce() -> O) -> O`
+			None if THREAD_WORLD.has_tag(WorldTag::NoSubWarning) => (),
 			None => tracing::warn!(
 				trigger=?self,
 				location=%Loc::caller(),
@@ -87,11 +85,32 @@ impl Trigger {
 				signals outside of an effect, which means the code won't \
 				be re-run when the signal changes. If this is intention, \
 				try to use one of the `*_no_dep` methods that don't \
-				gather subscribers to make it intentional"
+				gather subscribers, or `without_subscriber_warning`, \
+				to make it intentional"
 			),
 		}
 	}
 
+	/// Subscribes `effect` to only the next execution of this trigger.
+	///
+	/// Unlike a dependency gathered with [`gather_subs`](Self::gather_subs), which
+	/// lasts until the effect is re-run, this subscription is removed as soon as
+	/// this trigger is executed, so the effect runs only once because of it.
+	///
+	/// Note that any dependencies `effect` gathers while running are still
+	/// normal dependencies. For an effect that should run once and never
+	/// again, create it with [`Effect::new_raw`] and avoid gathering any
+	/// dependencies within it.
+	#[track_caller]
+	pub fn subscribe_once<F>(&self, effect: &Effect<F>)
+	where
+		F: ?Sized + EffectRun,
+	{
+		GLOBAL_WORLD
+			.dep_graph()
+			.add_effect_dep_once(&effect.clone().unsize(), self);
+	}
+
 	/// Executes this trigger.
 	///
 	/// Adds all subscribers to the run queue, and once the returned
@@ -137,24 +156,33 @@ impl Trigger {
 		THREAD_WORLD.run_queue().inc_ref();
 
 		// Then add all subscribers to the run queue
+		let mut has_once_subs = false;
 		GLOBAL_WORLD
 			.dep_graph()
 			.with_trigger_subs(self.downgrade(), |sub, sub_info| {
+				has_once_subs |= sub_info.iter().any(|info| info.once);
+
 				// If the effect doesn't exist anymore, skip it
 				let Some(effect) = sub.upgrade() else {
 					return;
 				};
 
-				// Skip suppressed effects
+				// Skip suppressed effects, but remember they missed a run
 				if effect.is_suppressed() {
+					effect.set_missed_run();
 					return;
 				}
 
 				// Then set the effect as stale and add it to the run queue
 				effect.set_stale();
-				THREAD_WORLD.run_queue().push(effect.downgrade(), sub_info);
+				THREAD_WORLD.run_queue().push(&effect, sub_info);
 			});
 
+		// And remove any subscribers that only wanted a single execution
+		if has_once_subs {
+			GLOBAL_WORLD.dep_graph().remove_trigger_once_deps(self.downgrade());
+		}
+
 		Some(TriggerExec {
 			trigger_defined_loc: self.defined_loc(),
 			exec_defined_loc:    caller_loc,
@@ -281,6 +309,19 @@ impl fmt::Debug for WeakTrigger {
 	}
 }
 
+/// Suppresses the warning when accessing triggers outside of an effect within `f`.
+///
+/// Unlike [`with_no_dep`](effect::with_no_dep), dependencies are still gathered
+/// if an effect is running, so this is useful for intentional reads outside
+/// of an effect in code that may also be run inside of one.
+pub fn without_subscriber_warning<F, O>(f: F) -> O
+where
+	F: FnOnce() -> O,
+{
+	let _guard = THREAD_WORLD.add_tag(WorldTag::NoSubWarning);
+	f()
+}
+
 /// Trigger executor
 pub struct TriggerExec {
 	/// Trigger defined location
@@ -300,17 +341,12 @@ pub struct TriggerExec {
 impl Drop for TriggerExec {
 	fn drop(&mut self) {
 		// Decrease the reference count, and if we weren't the last, quit
-		let Some(_exec_guard) = THREAD_WORLD.run_queue().dec_ref() else {
+		let Some(exec_guard) = THREAD_WORLD.run_queue().dec_ref() else {
 			return;
 		};
 
-		// If we were the last, keep popping effects and running them until
-		// the run queue is empty
-		while let Some((sub, sub_info)) = THREAD_WORLD.run_queue().pop() {
-			let Some(effect) = sub.upgrade() else {
-				continue;
-			};
-
+		// If we were the last, run all effects in the queue
+		exec_guard.run(|effect, sub_info| {
 			tracing::trace!(
 				"Running effect due to trigger\nEffect   : {}\nGathered : {}\nTrigger  : {}\nExecution: {}",
 				effect.defined_loc(),
@@ -325,8 +361,6 @@ impl Drop for TriggerExec {
 				self.trigger_defined_loc,
 				self.exec_defined_loc,
 			);
-
-			effect.run();
-		}
+		});
 	}
 }
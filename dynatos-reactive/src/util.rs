@@ -3,6 +3,9 @@
 // Imports
 use dynatos_sync_types::SyncBounds;
 
+/// Spawns a task onto the current executor.
+///
+/// Uses `wasm_bindgen_futures` with the `wasm-js-promise` feature, and `tokio` with the `tokio` feature.
 // TODO: Allow the user to specify this a big better than just wasm_bindgen / tokio?
 pub fn spawn_task<F: Future<Output = ()> + SyncBounds + 'static>(f: F) {
 	cfg_select! {
@@ -15,7 +15,12 @@ use {
 		SignalWith,
 		SignalWithDefaultImpl,
 	},
-	core::ops::{Deref, DerefMut},
+	core::{
+		cell::OnceCell,
+		fmt,
+		ops::{Deref, DerefMut},
+	},
+	dynatos_sync_types::{RcPtr, SyncBounds},
 };
 
 /// Wrapper for a `Signal<Option<T>>` with a default value
@@ -25,13 +30,122 @@ pub struct WithDefault<S, T: ?Sized> {
 	inner: S,
 
 	/// Default
-	default: T,
+	default: DefaultValue<T>,
 }
 
 impl<S, T> WithDefault<S, T> {
-	/// Wraps a signal with a default value
-	pub const fn new(inner: S, default: T) -> Self {
-		Self { inner, default }
+	/// Wraps a signal with a default value.
+	///
+	/// Note that this isn't a `const fn`, so it can't be used to initialize
+	/// a `static` or `const`, since the default is stored behind a shared pointer.
+	pub fn new(inner: S, default: T) -> Self {
+		Self {
+			inner,
+			default: DefaultValue::Value(RcPtr::new(default)),
+		}
+	}
+
+	/// Wraps a signal with a default value computed by `default`.
+	///
+	/// `default` is called each time the default value is needed, so
+	/// any signals used within it will be gathered as dependencies.
+	pub fn new_fn<F>(inner: S, default: F) -> Self
+	where
+		F: DefaultFn<T> + 'static,
+	{
+		Self {
+			inner,
+			default: DefaultValue::Fn(RcPtr::new(default)),
+		}
+	}
+
+	/// Sets the default value.
+	///
+	/// Only this signal is affected, not any of its clones, and anything
+	/// that used the previous default value won't be updated. This should only
+	/// be used while constructing the signal, before it's shared.
+	pub fn set_default(&mut self, default: T) {
+		self.default = DefaultValue::Value(RcPtr::new(default));
+	}
+
+	/// Sets the function to compute the default value.
+	///
+	/// See [`set_default`](Self::set_default) for details.
+	pub fn set_default_fn<F>(&mut self, default: F)
+	where
+		F: DefaultFn<T> + 'static,
+	{
+		self.default = DefaultValue::Fn(RcPtr::new(default));
+	}
+}
+
+/// Default value function
+pub trait DefaultFn<T: ?Sized>: SyncBounds {
+	/// Computes the default value
+	fn default(&self) -> T
+	where
+		T: Sized;
+}
+
+impl<F, T> DefaultFn<T> for F
+where
+	F: SyncBounds + Fn() -> T,
+{
+	fn default(&self) -> T {
+		self()
+	}
+}
+
+/// Default value
+enum DefaultValue<T: ?Sized> {
+	/// Constant value.
+	// Note: This is behind a pointer so that `T` may be unsized.
+	Value(RcPtr<T>),
+
+	/// Computed value
+	Fn(RcPtr<dyn DefaultFn<T>>),
+}
+
+impl<T> DefaultValue<T> {
+	/// Uses the default value
+	fn with<F, O>(&self, f: F) -> O
+	where
+		F: FnOnce(&T) -> O,
+	{
+		match self {
+			Self::Value(value) => f(value),
+			Self::Fn(default) => f(&default.default()),
+		}
+	}
+
+	/// Gets the default value
+	fn get(&self) -> T
+	where
+		T: Copy,
+	{
+		match self {
+			Self::Value(value) => **value,
+			Self::Fn(default) => default.default(),
+		}
+	}
+}
+
+impl<T: ?Sized> Clone for DefaultValue<T> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Value(value) => Self::Value(RcPtr::clone(value)),
+			Self::Fn(default) => Self::Fn(RcPtr::clone(default)),
+		}
+	}
+}
+
+#[coverage(off)]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for DefaultValue<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Value(value) => f.debug_tuple("Value").field(value).finish(),
+			Self::Fn(_) => f.debug_tuple("Fn").finish_non_exhaustive(),
+		}
 	}
 }
 
@@ -42,7 +156,10 @@ pub struct BorrowRef<'a, S: SignalBorrow + 'a, T> {
 	value: S::Ref<'a>,
 
 	/// Default value
-	default: &'a T,
+	default: &'a DefaultValue<T>,
+
+	/// Computed default value
+	computed_default: OnceCell<T>,
 }
 
 impl<'a, S, T> Deref for BorrowRef<'a, S, T>
@@ -55,7 +172,10 @@ where
 	fn deref(&self) -> &Self::Target {
 		match &*self.value {
 			Some(value) => value,
-			None => self.default,
+			None => match self.default {
+				DefaultValue::Value(default) => default,
+				DefaultValue::Fn(default) => self.computed_default.get_or_init(|| default.default()),
+			},
 		}
 	}
 }
@@ -68,8 +188,9 @@ impl<S: SignalBorrow, T> SignalBorrow for WithDefault<S, T> {
 
 	fn borrow(&self) -> Self::Ref<'_> {
 		BorrowRef {
-			value:   self.inner.borrow(),
-			default: &self.default,
+			value:            self.inner.borrow(),
+			default:          &self.default,
+			computed_default: OnceCell::new(),
 		}
 	}
 }
@@ -89,7 +210,7 @@ where
 	{
 		self.inner.with(|value| match value.into() {
 			Some(value) => f(value),
-			None => f(&self.default),
+			None => self.default.with(f),
 		})
 	}
 }
@@ -138,7 +259,9 @@ where
 	type Value = T;
 
 	fn replace(&self, new_value: T) -> Self::Value {
-		self.inner.replace(Some(new_value)).unwrap_or(self.default)
+		self.inner
+			.replace(Some(new_value))
+			.unwrap_or_else(|| self.default.get())
 	}
 }
 
@@ -150,7 +273,7 @@ where
 	type Value = T;
 
 	fn replace(&self, new_value: Option<T>) -> Self::Value {
-		self.inner.replace(new_value).unwrap_or(self.default)
+		self.inner.replace(new_value).unwrap_or_else(|| self.default.get())
 	}
 }
 
@@ -195,7 +318,7 @@ where
 
 	fn borrow_mut(&self) -> Self::RefMut<'_> {
 		let mut value = self.inner.borrow_mut();
-		value.get_or_insert(self.default);
+		value.get_or_insert_with(|| self.default.get());
 
 		BorrowRefMut { value }
 	}
@@ -212,7 +335,8 @@ where
 	where
 		F: for<'a> FnOnce(Self::Value<'a>) -> O,
 	{
-		self.inner.update(|value| f(value.get_or_insert(self.default)))
+		self.inner
+			.update(|value| f(value.get_or_insert_with(|| self.default.get())))
 	}
 }
 
@@ -223,4 +347,90 @@ pub impl<S> S {
 	fn with_default<T>(self, default: T) -> WithDefault<S, T> {
 		WithDefault::new(self, default)
 	}
+
+	/// Wraps this signal with a default value computed by `default`.
+	///
+	/// See [`WithDefault::new_fn`] for details.
+	fn with_default_fn<T, F>(self, default: F) -> WithDefault<S, T>
+	where
+		F: DefaultFn<T> + 'static,
+	{
+		WithDefault::new_fn(self, default)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use {
+		super::*,
+		crate::{Effect, Signal, SignalGet},
+		dynatos_util::Counter,
+		zutil_cloned::cloned,
+	};
+
+	#[test]
+	fn default_value() {
+		let inner = Signal::new(None::<usize>);
+		let mut signal = inner.clone().with_default(5);
+		assert_eq!(signal.get(), 5);
+		assert_eq!(*signal.borrow(), 5);
+
+		signal.set_default(6);
+		assert_eq!(signal.get(), 6);
+
+		inner.set(Some(1));
+		assert_eq!(signal.get(), 1);
+	}
+
+	#[test]
+	fn default_fn() {
+		let default = Signal::new(5_usize);
+		let inner = Signal::new(None::<usize>);
+
+		#[cloned(default)]
+		let signal = inner.clone().with_default_fn(move || default.get());
+		assert_eq!(signal.get(), 5);
+		assert_eq!(*signal.borrow(), 5);
+
+		default.set(6);
+		assert_eq!(signal.get(), 6);
+		assert_eq!(*signal.borrow(), 6);
+
+		inner.set(Some(1));
+		assert_eq!(signal.get(), 1);
+		assert_eq!(SignalReplace::<usize>::replace(&signal, 2), 1);
+		assert_eq!(SignalReplace::<Option<usize>>::replace(&signal, None), 2);
+		assert_eq!(signal.get(), 6);
+
+		signal.update(|value| *value += 1);
+		assert_eq!(inner.get(), Some(7));
+	}
+
+	#[test]
+	fn default_fn_deps() {
+		let default = Signal::new(5_usize);
+		let inner = Signal::new(None::<usize>);
+
+		#[cloned(default)]
+		let signal = inner.clone().with_default_fn(move || default.get());
+
+		// Counts the number of times the effect was run
+		static TIMES_RUN: Counter = Counter::new();
+		let _effect = Effect::new(move || {
+			_ = signal.get();
+			TIMES_RUN.bump();
+		});
+		assert_eq!(TIMES_RUN.get(), 1);
+
+		default.set(6);
+		assert_eq!(TIMES_RUN.get(), 2);
+
+		inner.set(Some(1));
+		assert_eq!(TIMES_RUN.get(), 3);
+
+		// Note: While the inner value exists, the default isn't used,
+		//       so it's not a dependency.
+		default.set(7);
+		assert_eq!(TIMES_RUN.get(), 3);
+	}
 }
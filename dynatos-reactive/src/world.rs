@@ -9,7 +9,7 @@ pub use self::tags::{WorldTag, WorldTagGuard};
 // Imports
 use {
 	self::tags::{WorldTagState, WorldTagsData},
-	crate::{dep_graph::DepGraph, effect_stack::EffectStack, run_queue::RunQueue},
+	crate::{dep_graph::DepGraph, effect_stack::EffectStack, run_queue::RunQueue, suspense::SuspenseStack},
 	dynatos_sync_types::{LazyCell, thread_local_or_global},
 };
 
@@ -62,6 +62,9 @@ pub struct ThreadWorld {
 
 	/// Run queue
 	run_queue: RunQueue,
+
+	/// Suspense context stack
+	suspense_stack: SuspenseStack,
 }
 
 impl ThreadWorld {
@@ -69,9 +72,10 @@ impl ThreadWorld {
 	#[must_use]
 	pub const fn new() -> Self {
 		Self {
-			tags:         WorldTagsData::new(),
-			effect_stack: EffectStack::new(),
-			run_queue:    RunQueue::new(),
+			tags:           WorldTagsData::new(),
+			effect_stack:   EffectStack::new(),
+			run_queue:      RunQueue::new(),
+			suspense_stack: SuspenseStack::new(),
 		}
 	}
 
@@ -87,6 +91,12 @@ impl ThreadWorld {
 		&self.run_queue
 	}
 
+	/// Returns the suspense context stack
+	#[must_use]
+	pub const fn suspense_stack(&self) -> &SuspenseStack {
+		&self.suspense_stack
+	}
+
 	/// Returns if a tag is present and enabled
 	pub fn has_tag(&self, tag: WorldTag) -> bool {
 		self.tags.get(tag).is_some_and(|tag| tag == WorldTagState::Enabled)
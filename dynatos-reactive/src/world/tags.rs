@@ -104,6 +104,15 @@ decl_tags! {
 	/// to anything, no triggers happen.
 	NoRun(no_run),
 
+	/// "no-subscriber-warning" tag.
+	///
+	/// This tag suppresses the warning when a trigger gathers
+	/// subscribers while no effect is running.
+	///
+	/// This tag is never removed automatically, but it only
+	/// matters while no effect is running anyway.
+	NoSubWarning(no_sub_warning),
+
 	/// "unloaded" tag.
 	///
 	/// This tag is never removed automatically, to ensure
@@ -130,3 +130,29 @@ impl Drop for OpaqueHandle<'_> {
 		let _: Box<dyn Any> = self.take_inner();
 	}
 }
+
+/// A handle to several store values.
+///
+/// When dropped, all store values are dropped, in the reverse order they were pushed.
+#[must_use = "The handle object keeps values in the store. If dropped, the values are also dropped"]
+pub struct MultiHandle<'a> {
+	pub(crate) handles: Vec<OpaqueHandle<'a>>,
+}
+
+impl MultiHandle<'_> {
+	/// Forgets the values in this handle
+	pub fn forget(mut self) {
+		for handle in self.handles.drain(..) {
+			mem::forget(handle);
+		}
+	}
+}
+
+impl Drop for MultiHandle<'_> {
+	#[track_caller]
+	fn drop(&mut self) {
+		while let Some(handle) = self.handles.pop() {
+			drop(handle);
+		}
+	}
+}
@@ -9,7 +9,7 @@ mod handle;
 mod store;
 
 // Exports
-pub use self::store::ValueStore;
+pub use self::store::{MissingValue, PushMany, ValueStore};
 
 #[cfg(test)]
 mod tests {
@@ -72,6 +72,70 @@ mod tests {
 		assert_eq!(store.try_get::<usize>(), None);
 	}
 
+	#[test]
+	fn get_or_push_absent() {
+		let store = ValueStore::new();
+
+		let (value, handle) = store.get_or_push::<usize, _>(|| 5);
+		assert_eq!(value, 5);
+		assert_eq!(store.try_get::<usize>(), Some(5));
+
+		// Dropping the handle removes the pushed value
+		let handle = handle.expect("Value should have been pushed");
+		drop(handle);
+		assert_eq!(store.try_get::<usize>(), None);
+	}
+
+	#[test]
+	fn get_or_push_present() {
+		let store = ValueStore::new();
+
+		let _handle = store.push::<usize>(5);
+		let (value, handle) = store.get_or_push::<usize, _>(|| panic!("Value shouldn't be created"));
+		assert_eq!(value, 5);
+		assert!(handle.is_none(), "Value shouldn't have been pushed");
+		assert_eq!(store.try_get::<usize>(), Some(5));
+	}
+
+	#[test]
+	fn try_expect() {
+		let store = ValueStore::new();
+
+		let err = MissingValue::of::<usize>();
+		assert_eq!(store.try_get_expect::<usize>(), Err(err));
+		assert_eq!(store.try_with_expect::<usize, _, _>(|&value| value + 1), Err(err));
+
+		let _handle = store.push::<usize>(5);
+		assert_eq!(store.try_get_expect::<usize>(), Ok(5));
+		assert_eq!(store.try_with_expect::<usize, _, _>(|&value| value + 1), Ok(6));
+	}
+
+	#[test]
+	fn push_many() {
+		let store = ValueStore::new();
+
+		let handle = store.push_many((5_usize, "a", 'b'));
+		assert_eq!(store.try_get::<usize>(), Some(5));
+		assert_eq!(store.try_get::<&str>(), Some("a"));
+		assert_eq!(store.try_get::<char>(), Some('b'));
+
+		drop(handle);
+		assert_eq!(store.try_get::<usize>(), None);
+		assert_eq!(store.try_get::<&str>(), None);
+		assert_eq!(store.try_get::<char>(), None);
+	}
+
+	#[test]
+	fn push_many_same_type() {
+		let store = ValueStore::new();
+
+		let handle = store.push_many((5_usize, 6_usize));
+		assert_eq!(store.try_get::<usize>(), Some(6));
+
+		drop(handle);
+		assert_eq!(store.try_get::<usize>(), None);
+	}
+
 	#[test]
 	fn opaque() {
 		let store = ValueStore::new();
@@ -2,7 +2,7 @@
 
 // Imports
 use {
-	crate::handle::Handle,
+	crate::handle::{Handle, MultiHandle, OpaqueHandle},
 	core::{
 		any::{self, Any, TypeId},
 		hash::BuildHasherDefault,
@@ -56,6 +56,44 @@ impl ValueStore {
 		}
 	}
 
+	/// Pushes several values to this store, with a single handle.
+	///
+	/// `values` is a tuple of the values to push, which are pushed in order.
+	/// When the handle is dropped, they're removed in the reverse order.
+	pub fn push_many<V>(&self, values: V) -> MultiHandle<'_>
+	where
+		V: PushMany,
+	{
+		MultiHandle {
+			handles: values.push_many(self),
+		}
+	}
+
+	/// Gets a value of `T` from this store, or pushes one created by `f`.
+	///
+	/// If a value already exists, it's returned without a handle, since it's owned
+	/// by whoever pushed it. Otherwise, the value created by `f` is pushed, and
+	/// returned alongside its handle.
+	///
+	/// Like with [`push`](Self::push), the pushed value is only kept in the store while
+	/// the handle is alive, so dropping it removes the value. Use [`Handle::forget`] to
+	/// keep the value in the store indefinitely.
+	///
+	/// Note that `f` is called without any locks held, so it may access this store.
+	pub fn get_or_push<T, F>(&self, f: F) -> (T, Option<Handle<'_, T>>)
+	where
+		T: Any + SyncBounds + Clone,
+		F: FnOnce() -> T,
+	{
+		if let Some(value) = self.try_get::<T>() {
+			return (value, None);
+		}
+
+		let value = f();
+		let handle = self.push(value.clone());
+		(value, Some(handle))
+	}
+
 	/// Gets a value of `T` from this store
 	#[must_use]
 	pub fn try_get<T>(&self) -> Option<T>
@@ -117,6 +155,29 @@ impl ValueStore {
 		self.with::<T, _, _>(f).unwrap_or_else(self::on_missing_value::<T, _>)
 	}
 
+	/// Uses a value of `T` from this store, expecting it.
+	///
+	/// Unlike [`with_expect`](Self::with_expect), this returns an error
+	/// instead of panicking if the value doesn't exist.
+	pub fn try_with_expect<T, F, O>(&self, f: F) -> Result<O, MissingValue>
+	where
+		T: 'static,
+		F: FnOnce(&T) -> O,
+	{
+		self.with::<T, _, _>(f).ok_or_else(MissingValue::of::<T>)
+	}
+
+	/// Gets a value of `T` from this store, expecting it.
+	///
+	/// Unlike [`get`](Self::get), this returns an error
+	/// instead of panicking if the value doesn't exist.
+	pub fn try_get_expect<T>(&self) -> Result<T, MissingValue>
+	where
+		T: Clone + 'static,
+	{
+		self.try_get::<T>().ok_or_else(MissingValue::of::<T>)
+	}
+
 	pub(crate) fn with_idx<F, O>(&self, idx: usize, type_id: TypeId, f: F) -> O
 	where
 		F: FnOnce(&(dyn Any + SyncBounds)) -> O,
@@ -141,10 +202,58 @@ impl Default for ValueStore {
 	}
 }
 
+/// Values that may be pushed with [`ValueStore::push_many`].
+///
+/// This is implemented for tuples of up to 8 values.
+pub trait PushMany {
+	/// Pushes all values to `store`, returning their handles
+	fn push_many(self, store: &ValueStore) -> Vec<OpaqueHandle<'_>>;
+}
+
+macro impl_push_many($($T:ident),* $(,)?) {
+	impl<$($T,)*> PushMany for ($($T,)*)
+	where
+		$( $T: Any + SyncBounds, )*
+	{
+		#[expect(non_snake_case, reason = "We re-use the type names as the value names")]
+		fn push_many(self, store: &ValueStore) -> Vec<OpaqueHandle<'_>> {
+			let ($($T,)*) = self;
+			vec![ $( store.push($T).into_opaque(), )* ]
+		}
+	}
+}
+
+impl_push_many! { T0 }
+impl_push_many! { T0, T1 }
+impl_push_many! { T0, T1, T2 }
+impl_push_many! { T0, T1, T2, T3 }
+impl_push_many! { T0, T1, T2, T3, T4 }
+impl_push_many! { T0, T1, T2, T3, T4, T5 }
+impl_push_many! { T0, T1, T2, T3, T4, T5, T6 }
+impl_push_many! { T0, T1, T2, T3, T4, T5, T6, T7 }
+
+/// Error for when a value is missing from the store
+#[derive(thiserror::Error, PartialEq, Eq, Clone, Copy, Debug)]
+#[error("Value for type {type_name:?} was missing")]
+pub struct MissingValue {
+	/// Name of the missing type
+	pub type_name: &'static str,
+}
+
+impl MissingValue {
+	/// Creates the error for a missing `T`
+	#[must_use]
+	pub fn of<T>() -> Self {
+		Self {
+			type_name: any::type_name::<T>(),
+		}
+	}
+}
+
 /// Called when value for type `T` was missing.
 #[cold]
 #[inline(never)]
 #[track_caller]
 fn on_missing_value<T, O>() -> O {
-	panic!("Value for type {:?} was missing", any::type_name::<T>())
+	panic!("{}", MissingValue::of::<T>())
 }
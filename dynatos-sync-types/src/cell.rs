@@ -1,12 +1,13 @@
 //! Cell types
 
 // Imports
-use core::sync::atomic;
+use core::{fmt, sync::atomic};
 
 duplicate::duplicate! {
 	[
 		Name Inner AtomicTy InnerTy;
 		[CellBool] [InnerBool] [AtomicBool] [bool];
+		[CellU8] [InnerU8] [AtomicU8] [u8];
 		[CellUsize] [InnerUsize] [AtomicUsize] [usize];
 	]
 
@@ -61,3 +62,96 @@ duplicate::duplicate! {
 		}
 	}
 }
+
+type SyncCellInner<T> = cfg_select! {
+	feature = "sync" => std::sync::nonpoison::Mutex::<T>,
+	_ => core::cell::Cell::<T>,
+};
+
+/// Cell for any type.
+///
+/// With the `sync` feature, this is backed by a mutex, so prefer
+/// [`CellBool`], [`CellU8`] and [`CellUsize`] when possible, which use atomics instead.
+#[derive(Default)]
+pub struct SyncCell<T>(SyncCellInner<T>);
+
+impl<T> SyncCell<T> {
+	#[must_use]
+	pub const fn new(value: T) -> Self {
+		Self(SyncCellInner::new(value))
+	}
+
+	pub fn set(&self, value: T) {
+		cfg_select! {
+			feature = "sync" => *self.0.lock() = value,
+			_ => self.0.set(value),
+		};
+	}
+
+	#[cfg_attr(
+		not(feature = "sync"),
+		expect(clippy::missing_const_for_fn, reason = "Used with the feature")
+	)]
+	pub fn replace(&self, value: T) -> T {
+		cfg_select! {
+			feature = "sync" => core::mem::replace(&mut *self.0.lock(), value),
+			_ => self.0.replace(value),
+		}
+	}
+
+	pub fn into_inner(self) -> T {
+		self.0.into_inner()
+	}
+}
+
+impl<T: Copy> SyncCell<T> {
+	#[must_use]
+	#[cfg_attr(
+		not(feature = "sync"),
+		expect(clippy::missing_const_for_fn, reason = "Used with the feature")
+	)]
+	pub fn get(&self) -> T {
+		cfg_select! {
+			feature = "sync" => *self.0.lock(),
+			_ => self.0.get(),
+		}
+	}
+}
+
+impl<T: Default> SyncCell<T> {
+	pub fn take(&self) -> T {
+		self.replace(T::default())
+	}
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for SyncCell<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("SyncCell").field(&self.get()).finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn sync_cell() {
+		let cell = SyncCell::new(5_i64);
+		assert_eq!(cell.get(), 5);
+
+		cell.set(6);
+		assert_eq!(cell.get(), 6);
+		assert_eq!(cell.replace(7), 6);
+		assert_eq!(cell.take(), 7);
+		assert_eq!(cell.into_inner(), 0);
+	}
+
+	#[test]
+	fn sync_cell_non_copy() {
+		let cell = SyncCell::new(String::from("a"));
+		cell.set(String::from("b"));
+		assert_eq!(cell.replace(String::from("c")), "b");
+		assert_eq!(cell.into_inner(), "c");
+	}
+}
@@ -28,6 +28,12 @@ impl<T> RcPtr<T> {
 	}
 }
 
+impl<T: Clone> RcPtr<T> {
+	pub fn make_mut(this: &mut Self) -> &mut T {
+		Inner::make_mut(&mut this.0)
+	}
+}
+
 impl<T: ?Sized> RcPtr<T> {
 	#[must_use]
 	pub fn downgrade(this: &Self) -> WeakRcPtr<T> {
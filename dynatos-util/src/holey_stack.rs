@@ -48,6 +48,15 @@ impl<T> HoleyStack<T> {
 		self.values.get(idx).flatten_ref()
 	}
 
+	/// Gets a value mutably by index.
+	///
+	/// Returns `None` if `idx` is out of bounds or
+	/// already taken.
+	#[must_use]
+	pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+		self.values.get_mut(idx)?.as_mut()
+	}
+
 	/// Returns the top of the stack
 	#[must_use]
 	pub fn top(&self) -> Option<&T> {
@@ -23,7 +23,15 @@ use {
 
 /// Calculates the hash of a value using the default hasher
 pub fn hash_of<T: hash::Hash>(t: &T) -> u64 {
-	let mut s = DefaultHasher::new();
+	self::hash_of_with::<DefaultHasher, T>(t)
+}
+
+/// Calculates the hash of a value using a hasher `H`.
+///
+/// A new hasher is created with [`Default`] for each call, so if `H`
+/// has a fixed seed, the hashes will be stable across runs.
+pub fn hash_of_with<H: Hasher + Default, T: hash::Hash>(t: &T) -> u64 {
+	let mut s = H::default();
 	t.hash(&mut s);
 	s.finish()
 }
@@ -53,4 +61,43 @@ pub mod web {
 			}
 		}
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use {super::*, core::hash::Hash};
+
+	/// Hasher that sums all bytes written to it
+	#[derive(Default)]
+	struct SumHasher(u64);
+
+	impl Hasher for SumHasher {
+		fn finish(&self) -> u64 {
+			self.0
+		}
+
+		fn write(&mut self, bytes: &[u8]) {
+			self.0 += bytes.iter().map(|&byte| u64::from(byte)).sum::<u64>();
+		}
+	}
+
+	#[test]
+	fn hash_of_default() {
+		let mut s = DefaultHasher::new();
+		"Hello".hash(&mut s);
+
+		assert_eq!(hash_of(&"Hello"), s.finish());
+		assert_eq!(hash_of(&"Hello"), hash_of_with::<DefaultHasher, _>(&"Hello"));
+	}
+
+	#[test]
+	fn hash_of_with_hasher() {
+		assert_eq!(hash_of_with::<SumHasher, _>(&0x0102_0304_u32), 10);
+		assert_eq!(
+			hash_of_with::<SumHasher, _>(&[1_u8, 2, 3]),
+			6 + 3,
+			"Slices should also hash their length"
+		);
+	}
+}
@@ -1,7 +1,10 @@
 //! `Try` helpers for returning.
 
 // Imports
-use core::ops::{ControlFlow, FromResidual, Residual, Try};
+use core::{
+	marker::PhantomData,
+	ops::{ControlFlow, FromResidual, Residual, Try},
+};
 
 /// `Try` type to return `()` when `T::branch` is `Break`.
 pub struct TryOrReturn<T>(T);
@@ -36,10 +39,112 @@ impl<T: Try> FromResidual<TryOrReturnResidual<T>> for () {
 	fn from_residual(_: TryOrReturnResidual<T>) -> Self {}
 }
 
-/// Extension trait to create a [`TryOrReturn`]
+/// `Try` type to return `F()` when `T::branch` is `Break`.
+pub struct TryOrReturnWith<T: Try, F>(ControlFlow<F, T::Output>);
+
+/// Residual type for [`TryOrReturnWith`]
+pub struct TryOrReturnWithResidual<T, F>(F, PhantomData<fn() -> T>);
+
+impl<T: Try, F> Residual<T::Output> for TryOrReturnWithResidual<T, F> {
+	type TryType = TryOrReturnWith<T, F>;
+}
+
+impl<T: Try, F> Try for TryOrReturnWith<T, F> {
+	type Output = T::Output;
+	type Residual = TryOrReturnWithResidual<T, F>;
+
+	fn from_output(output: Self::Output) -> Self {
+		Self(ControlFlow::Continue(output))
+	}
+
+	fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+		self.0.map_break(|f| TryOrReturnWithResidual(f, PhantomData))
+	}
+}
+
+impl<T: Try, F> FromResidual<TryOrReturnWithResidual<T, F>> for TryOrReturnWith<T, F> {
+	fn from_residual(residual: TryOrReturnWithResidual<T, F>) -> Self {
+		Self(ControlFlow::Break(residual.0))
+	}
+}
+
+// Note: We can't implement this for any type due to the orphan rules,
+//       so we only implement it for some common return types.
+#[duplicate::duplicate_item(
+	Generics Ty;
+	[] [bool];
+	[] [char];
+	[] [u8];
+	[] [u16];
+	[] [u32];
+	[] [u64];
+	[] [u128];
+	[] [usize];
+	[] [i8];
+	[] [i16];
+	[] [i32];
+	[] [i64];
+	[] [i128];
+	[] [isize];
+	[] [f32];
+	[] [f64];
+	[] [String];
+	[U] [Vec<U>];
+	[U] [Option<U>];
+	[U, E] [Result<U, E>];
+)]
+impl<T: Try, F: FnOnce() -> Self, Generics> FromResidual<TryOrReturnWithResidual<T, F>> for Ty {
+	fn from_residual(residual: TryOrReturnWithResidual<T, F>) -> Self {
+		(residual.0)()
+	}
+}
+
+/// Extension trait to create a [`TryOrReturn`] or [`TryOrReturnWith`]
 #[extend::ext(name = TryOrReturnExt)]
 pub impl<T: Try> T {
+	/// Returns `()` from the function on `Break`
 	fn or_return(self) -> TryOrReturn<T> {
 		TryOrReturn(self)
 	}
+
+	/// Returns `f()` from the function on `Break`
+	fn or_return_with<F, R>(self, f: F) -> TryOrReturnWith<T, F>
+	where
+		F: FnOnce() -> R,
+	{
+		TryOrReturnWith(self.branch().map_break(|_| f))
+	}
+
+	/// Returns `value` from the function on `Break`
+	fn or_return_val<R>(self, value: R) -> TryOrReturnWith<T, impl FnOnce() -> R> {
+		self.or_return_with(move || value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn or_return_with() {
+		fn f(value: Option<u32>) -> u32 {
+			let value = value.or_return_with(|| 0)?;
+			value + 1
+		}
+
+		assert_eq!(f(Some(1)), 2);
+		assert_eq!(f(None), 0);
+	}
+
+	#[test]
+	fn or_return_val() {
+		fn f(value: Result<u32, ()>) -> String {
+			let value = value.or_return_val("Error".to_owned())?;
+			value.to_string()
+		}
+
+		assert_eq!(f(Ok(1)), "1");
+		assert_eq!(f(Err(())), "Error");
+	}
 }
@@ -19,7 +19,7 @@ dynatos_util::web::cfg_ssr! {
 		}
 
 		impl<T: Value> ErasableGeneric for T {
-			type Repr = T;
+			type Repr = JsValue;
 		}
 
 		pub mod convert {
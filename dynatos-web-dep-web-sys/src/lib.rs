@@ -14,6 +14,7 @@ dynatos_util::web::cfg_ssr! {
 			Event,
 			EventTarget,
 			FocusEvent,
+			HashChangeEvent,
 			History,
 			HtmlBodyElement,
 			HtmlCanvasElement,
@@ -23,6 +24,8 @@ dynatos_util::web::cfg_ssr! {
 			HtmlHeadElement,
 			HtmlImageElement,
 			HtmlInputElement,
+			HtmlOptionElement,
+			HtmlSelectElement,
 			HtmlTextAreaElement,
 			InputEvent,
 			Location,
@@ -0,0 +1,472 @@
+//! Known html attributes
+//!
+//! See <https://html.spec.whatwg.org/multipage/indices.html#attributes-3>
+
+/// Attributes that may be used on all html elements
+const GLOBAL_ATTRS: &[&str] = &[
+	"accesskey",
+	"autocapitalize",
+	"autocorrect",
+	"autofocus",
+	"class",
+	"contenteditable",
+	"dir",
+	"draggable",
+	"enterkeyhint",
+	"hidden",
+	"id",
+	"inert",
+	"inputmode",
+	"is",
+	"itemid",
+	"itemprop",
+	"itemref",
+	"itemscope",
+	"itemtype",
+	"lang",
+	"nonce",
+	"part",
+	"popover",
+	"role",
+	"slot",
+	"spellcheck",
+	"style",
+	"tabindex",
+	"title",
+	"translate",
+	"writingsuggestions",
+	"xml:lang",
+	"xmlns",
+];
+
+/// Attribute prefixes that may be used on all html elements
+const GLOBAL_ATTR_PREFIXES: &[&str] = &["aria-", "data-"];
+
+/// Events that may be used as `on*` attributes on all html elements
+const GLOBAL_EVENTS: &[&str] = &[
+	"abort",
+	"afterprint",
+	"animationcancel",
+	"animationend",
+	"animationiteration",
+	"animationstart",
+	"auxclick",
+	"beforeinput",
+	"beforematch",
+	"beforeprint",
+	"beforetoggle",
+	"beforeunload",
+	"blur",
+	"cancel",
+	"canplay",
+	"canplaythrough",
+	"change",
+	"click",
+	"close",
+	"command",
+	"contextlost",
+	"contextmenu",
+	"contextrestored",
+	"copy",
+	"cuechange",
+	"cut",
+	"dblclick",
+	"drag",
+	"dragend",
+	"dragenter",
+	"dragleave",
+	"dragover",
+	"dragstart",
+	"drop",
+	"durationchange",
+	"emptied",
+	"ended",
+	"error",
+	"focus",
+	"formdata",
+	"gotpointercapture",
+	"hashchange",
+	"input",
+	"invalid",
+	"keydown",
+	"keypress",
+	"keyup",
+	"languagechange",
+	"load",
+	"loadeddata",
+	"loadedmetadata",
+	"loadstart",
+	"lostpointercapture",
+	"message",
+	"messageerror",
+	"mousedown",
+	"mouseenter",
+	"mouseleave",
+	"mousemove",
+	"mouseout",
+	"mouseover",
+	"mouseup",
+	"offline",
+	"online",
+	"pagehide",
+	"pagereveal",
+	"pageshow",
+	"pageswap",
+	"paste",
+	"pause",
+	"play",
+	"playing",
+	"pointercancel",
+	"pointerdown",
+	"pointerenter",
+	"pointerleave",
+	"pointermove",
+	"pointerout",
+	"pointerover",
+	"pointerrawupdate",
+	"pointerup",
+	"popstate",
+	"progress",
+	"ratechange",
+	"rejectionhandled",
+	"reset",
+	"resize",
+	"scroll",
+	"scrollend",
+	"securitypolicyviolation",
+	"seeked",
+	"seeking",
+	"select",
+	"selectionchange",
+	"selectstart",
+	"slotchange",
+	"stalled",
+	"storage",
+	"submit",
+	"suspend",
+	"timeupdate",
+	"toggle",
+	"touchcancel",
+	"touchend",
+	"touchmove",
+	"touchstart",
+	"transitioncancel",
+	"transitionend",
+	"transitionrun",
+	"transitionstart",
+	"unhandledrejection",
+	"unload",
+	"volumechange",
+	"waiting",
+	"wheel",
+];
+
+/// Elements that only have the global attributes
+const GLOBAL_ONLY_ELEMENTS: &[&str] = &[
+	"abbr",
+	"address",
+	"article",
+	"aside",
+	"b",
+	"bdi",
+	"bdo",
+	"body",
+	"br",
+	"caption",
+	"cite",
+	"code",
+	"datalist",
+	"dd",
+	"dfn",
+	"div",
+	"dl",
+	"dt",
+	"em",
+	"figcaption",
+	"figure",
+	"footer",
+	"h1",
+	"h2",
+	"h3",
+	"h4",
+	"h5",
+	"h6",
+	"head",
+	"header",
+	"hgroup",
+	"hr",
+	"html",
+	"i",
+	"kbd",
+	"legend",
+	"main",
+	"mark",
+	"menu",
+	"nav",
+	"noscript",
+	"p",
+	"picture",
+	"pre",
+	"rp",
+	"rt",
+	"ruby",
+	"s",
+	"samp",
+	"search",
+	"section",
+	"small",
+	"span",
+	"strong",
+	"sub",
+	"summary",
+	"sup",
+	"table",
+	"tbody",
+	"tfoot",
+	"thead",
+	"title",
+	"tr",
+	"u",
+	"ul",
+	"var",
+	"wbr",
+];
+
+/// Returns the attributes specific to element `name`, if it's a known element
+#[expect(clippy::too_many_lines, reason = "It's just a table of attributes")]
+fn element_attrs(name: &str) -> Option<&'static [&'static str]> {
+	let attrs: &[&str] = match name {
+		"a" => &[
+			"download",
+			"href",
+			"hreflang",
+			"ping",
+			"referrerpolicy",
+			"rel",
+			"target",
+			"type",
+		],
+		"area" => &[
+			"alt",
+			"coords",
+			"download",
+			"href",
+			"ping",
+			"referrerpolicy",
+			"rel",
+			"shape",
+			"target",
+		],
+		"audio" => &["autoplay", "controls", "crossorigin", "loop", "muted", "preload", "src"],
+		"base" => &["href", "target"],
+		"blockquote" | "q" => &["cite"],
+		"button" => &[
+			"command",
+			"commandfor",
+			"disabled",
+			"form",
+			"formaction",
+			"formenctype",
+			"formmethod",
+			"formnovalidate",
+			"formtarget",
+			"name",
+			"popovertarget",
+			"popovertargetaction",
+			"type",
+			"value",
+		],
+		"canvas" => &["height", "width"],
+		"col" | "colgroup" => &["span"],
+		"data" | "li" => &["value"],
+		"del" | "ins" => &["cite", "datetime"],
+		"details" => &["name", "open"],
+		"dialog" => &["closedby", "open"],
+		"embed" => &["height", "src", "type", "width"],
+		"fieldset" => &["disabled", "form", "name"],
+		"form" => &[
+			"accept-charset",
+			"action",
+			"autocomplete",
+			"enctype",
+			"method",
+			"name",
+			"novalidate",
+			"rel",
+			"target",
+		],
+		"iframe" => &[
+			"allow",
+			"allowfullscreen",
+			"height",
+			"loading",
+			"name",
+			"referrerpolicy",
+			"sandbox",
+			"src",
+			"srcdoc",
+			"width",
+		],
+		"img" => &[
+			"alt",
+			"crossorigin",
+			"decoding",
+			"fetchpriority",
+			"height",
+			"ismap",
+			"loading",
+			"referrerpolicy",
+			"sizes",
+			"src",
+			"srcset",
+			"usemap",
+			"width",
+		],
+		"input" => &[
+			"accept",
+			"alpha",
+			"alt",
+			"autocomplete",
+			"checked",
+			"colorspace",
+			"dirname",
+			"disabled",
+			"form",
+			"formaction",
+			"formenctype",
+			"formmethod",
+			"formnovalidate",
+			"formtarget",
+			"height",
+			"list",
+			"max",
+			"maxlength",
+			"min",
+			"minlength",
+			"multiple",
+			"name",
+			"pattern",
+			"placeholder",
+			"popovertarget",
+			"popovertargetaction",
+			"readonly",
+			"required",
+			"size",
+			"src",
+			"step",
+			"type",
+			"value",
+			"width",
+		],
+		"label" => &["for"],
+		"link" => &[
+			"as",
+			"blocking",
+			"color",
+			"crossorigin",
+			"disabled",
+			"fetchpriority",
+			"href",
+			"hreflang",
+			"imagesizes",
+			"imagesrcset",
+			"integrity",
+			"media",
+			"referrerpolicy",
+			"rel",
+			"sizes",
+			"type",
+		],
+		"map" | "slot" => &["name"],
+		"meta" => &["charset", "content", "http-equiv", "media", "name"],
+		"meter" => &["high", "low", "max", "min", "optimum", "value"],
+		"object" => &["data", "form", "height", "name", "type", "width"],
+		"ol" => &["reversed", "start", "type"],
+		"optgroup" => &["disabled", "label"],
+		"option" => &["disabled", "label", "selected", "value"],
+		"output" => &["for", "form", "name"],
+		"progress" => &["max", "value"],
+		"script" => &[
+			"async",
+			"blocking",
+			"crossorigin",
+			"defer",
+			"fetchpriority",
+			"integrity",
+			"nomodule",
+			"referrerpolicy",
+			"src",
+			"type",
+		],
+		"select" => &[
+			"autocomplete",
+			"disabled",
+			"form",
+			"multiple",
+			"name",
+			"required",
+			"size",
+		],
+		"source" => &["height", "media", "sizes", "src", "srcset", "type", "width"],
+		"style" => &["blocking", "media"],
+		"td" => &["colspan", "headers", "rowspan"],
+		"template" => &[
+			"shadowrootclonable",
+			"shadowrootcustomelementregistry",
+			"shadowrootdelegatesfocus",
+			"shadowrootmode",
+			"shadowrootserializable",
+		],
+		"textarea" => &[
+			"autocomplete",
+			"cols",
+			"dirname",
+			"disabled",
+			"form",
+			"maxlength",
+			"minlength",
+			"name",
+			"placeholder",
+			"readonly",
+			"required",
+			"rows",
+			"wrap",
+		],
+		"th" => &["abbr", "colspan", "headers", "rowspan", "scope"],
+		"time" => &["datetime"],
+		"track" => &["default", "kind", "label", "src", "srclang"],
+		"video" => &[
+			"autoplay",
+			"controls",
+			"crossorigin",
+			"height",
+			"loop",
+			"muted",
+			"playsinline",
+			"poster",
+			"preload",
+			"src",
+			"width",
+		],
+		_ if GLOBAL_ONLY_ELEMENTS.contains(&name) => &[],
+		_ => return None,
+	};
+
+	Some(attrs)
+}
+
+/// Returns if `attr` is a known attribute of the html element `element`.
+///
+/// Returns `None` if `element` isn't a known element, such as custom elements.
+pub fn is_known(element: &str, attr: &str) -> Option<bool> {
+	let element = element.to_ascii_lowercase();
+	let attr = attr.to_ascii_lowercase();
+	let element_attrs = self::element_attrs(&element)?;
+
+	let is_known = GLOBAL_ATTRS.contains(&&*attr) ||
+		GLOBAL_ATTR_PREFIXES.iter().any(|prefix| attr.starts_with(prefix)) ||
+		attr.strip_prefix("on")
+			.is_some_and(|event| GLOBAL_EVENTS.contains(&event)) ||
+		element_attrs.contains(&&*attr);
+	Some(is_known)
+}
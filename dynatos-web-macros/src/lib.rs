@@ -1,49 +1,112 @@
 //! Macros for `dynatos-web`
 
+// Modules
+mod attrs;
+
 // Imports
 use {
-	dynatos_web_parser::{XHtml, XHtmlNode},
+	convert_case::Casing,
+	dynatos_web_parser::{XHtml, XHtmlAttr, XHtmlElement, XHtmlNode},
 	proc_macro::TokenStream,
 	quote::ToTokens,
 	std::{
+		borrow::Cow,
 		fs,
 		path::{Path, PathBuf},
 	},
-	syn::punctuated::Punctuated,
+	syn::{
+		parse::{Parse, ParseStream, Parser},
+		punctuated::Punctuated,
+	},
 };
 
 #[proc_macro]
 pub fn html(input: TokenStream) -> TokenStream {
-	let input_lit = syn::parse_macro_input!(input as syn::LitStr);
-	let input = input_lit.value();
+	let input = syn::parse_macro_input!(input as MacroInput);
+	let html = input.lit.value();
 
-	self::parse_html(&input, input_lit.span(), None)
+	self::parse_html(&html, input.lit.span(), None, input.attr_check)
 }
 
 #[proc_macro]
 pub fn html_file(input: TokenStream) -> TokenStream {
-	let input_file_lit = syn::parse_macro_input!(input as syn::LitStr);
-	let input_file = PathBuf::from(input_file_lit.value());
+	let input = syn::parse_macro_input!(input as MacroInput);
+	let input_file = PathBuf::from(input.lit.value());
 	let input_file = input_file.canonicalize().expect("Unable to canonicalize input file");
-	let input = fs::read_to_string(&input_file).expect("Unable to read file");
+	let html = fs::read_to_string(&input_file).expect("Unable to read file");
 
-	self::parse_html(&input, input_file_lit.span(), Some(&input_file))
+	self::parse_html(&html, input.lit.span(), Some(&input_file), input.attr_check)
+}
+
+/// Macro input, `#[html(...)] "..."`
+struct MacroInput {
+	/// Literal
+	lit: syn::LitStr,
+
+	/// Attribute check
+	attr_check: AttrCheck,
+}
+
+impl Parse for MacroInput {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut attr_check = AttrCheck::Warn;
+		for attr in syn::Attribute::parse_outer(input)? {
+			if !attr.path().is_ident("html") {
+				return Err(syn::Error::new_spanned(attr, "Expected `#[html(...)]`"));
+			}
+
+			attr.parse_nested_meta(|meta| match () {
+				() if meta.path.is_ident("strict") => {
+					attr_check = AttrCheck::Strict;
+					Ok(())
+				},
+				() => Err(meta.error("Unknown option, expected `strict`")),
+			})?;
+		}
+
+		let lit = input.parse()?;
+		Ok(Self { lit, attr_check })
+	}
+}
+
+/// Check for unknown attributes
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum AttrCheck {
+	/// Warn on unknown attributes
+	Warn,
+
+	/// Error on unknown attributes
+	Strict,
+}
+
+impl AttrCheck {
+	/// Returns the statement to report the unknown attribute `attr` of the element `element`
+	fn report(self, element: &str, attr: &str) -> syn::Stmt {
+		let msg = format!("Unknown attribute `{attr}` for element `<{element}>`");
+		match self {
+			// Note: There's no stable way to emit warnings from a proc macro, so instead
+			//       we use a deprecated item, which gets reported by the `deprecated` lint.
+			Self::Warn => syn::parse_quote! {{
+				#[deprecated(note = #msg)]
+				struct UnknownAttr;
+				_ = UnknownAttr;
+			}},
+			Self::Strict => syn::parse_quote! { compile_error!(#msg); },
+		}
+	}
 }
 
 /// Parses html from `input`
-fn parse_html(input: &str, span: proc_macro2::Span, dep_file: Option<&Path>) -> TokenStream {
+fn parse_html(input: &str, span: proc_macro2::Span, dep_file: Option<&Path>, attr_check: AttrCheck) -> TokenStream {
 	// Parse the html and parse all the root nodes
 	let html = match XHtml::parse(input) {
 		Ok(html) => html,
-		Err((input, err)) =>
-			return syn::Error::new(span, format!("{err} at {input:?}"))
-				.to_compile_error()
-				.into(),
+		Err(err) => return syn::Error::new(span, err.to_string()).to_compile_error().into(),
 	};
 	let root = html
 		.children
 		.iter()
-		.filter_map(|node| Node::from_html(node))
+		.filter_map(|node| Node::from_html(node, Namespace::Html, attr_check))
 		.collect::<Vec<_>>();
 
 	// Check if all nodes have the same type.
@@ -104,8 +167,52 @@ enum NodeTy {
 
 	/// A generic expression
 	Expr,
+
+	/// A static expression, `<>static expr</>`, whose value implements `Children`
+	StaticExpr,
+}
+
+/// Element namespace
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Namespace {
+	/// Html, with elements created by `dynatos_web::html`
+	Html,
+
+	/// Svg, with elements created by `dynatos_web::svg`
+	Svg,
 }
 
+/// Svg elements with their own function in `dynatos_web::svg`
+const SVG_ELEMENTS: &[&str] = &[
+	"a",
+	"circle",
+	"clipPath",
+	"defs",
+	"desc",
+	"ellipse",
+	"foreignObject",
+	"g",
+	"image",
+	"line",
+	"linearGradient",
+	"marker",
+	"mask",
+	"path",
+	"pattern",
+	"polygon",
+	"polyline",
+	"radialGradient",
+	"rect",
+	"stop",
+	"svg",
+	"symbol",
+	"text",
+	"textPath",
+	"title",
+	"tspan",
+	"use",
+];
+
 #[derive(Clone, Debug)]
 struct Node {
 	ty:   NodeTy,
@@ -113,21 +220,44 @@ struct Node {
 }
 
 impl Node {
-	/// Parses a node `node` from an html node.
+	/// Parses a node `node` from an html node, within the namespace `ns`.
+	///
+	/// An `<svg>` element switches its descendants to the svg namespace,
+	/// while a `<foreignObject>` switches them back to the html namespace.
+	///
+	/// Any attributes of html elements that aren't known for that element are
+	/// reported according to `attr_check`.
 	///
 	/// Returns `None` is `node` is an empty text element.
 	#[expect(clippy::too_many_lines, reason = "TODO")]
-	fn from_html(node: &XHtmlNode) -> Option<Self> {
+	fn from_html(node: &XHtmlNode, ns: Namespace, attr_check: AttrCheck) -> Option<Self> {
 		let node = match node {
 			// If it's an element with an empty name, this is an expression
 			XHtmlNode::Element(element) if element.name.is_empty() => {
 				let inner = element.inner.expect("Expression cannot be self-closing");
+				let (ty, inner) = match inner.trim_start().strip_prefix("static") {
+					Some(inner) if inner.starts_with(char::is_whitespace) => (NodeTy::StaticExpr, inner),
+					_ => (NodeTy::Expr, inner),
+				};
 				let expr = syn::parse_str(inner).expect("Unable to parse placeholder");
-				Self { ty: NodeTy::Expr, expr }
+				Self { ty, expr }
 			},
 
+			// If it's a `<for>`, it can only be used as a child of an element
+			XHtmlNode::Element(element) if element.name == "for" =>
+				panic!("`<for>` may only be used inside of an element"),
+
 			// Otherwise, it's a normal element
 			XHtmlNode::Element(element) => {
+				let ns = match element.name {
+					"svg" => Namespace::Svg,
+					_ => ns,
+				};
+				let children_ns = match (ns, element.name) {
+					(Namespace::Svg, "foreignObject") => Namespace::Html,
+					_ => ns,
+				};
+
 				// If the name starts with a `:`, use an expression for the constructor
 				let constructor: syn::Expr = match element.name.strip_prefix(':') {
 					Some(expr) => {
@@ -135,27 +265,94 @@ impl Node {
 							syn::parse_str::<syn::Expr>(expr).expect("Unable to parse tag name as an expression");
 						syn::parse_quote! { #expr }
 					},
-					None => {
-						let name = syn::parse_str::<syn::Ident>(element.name)
-							.expect("Unable to parse tag name as an identifier");
-						syn::parse_quote! { dynatos_web::html::#name }
+					None => match ns {
+						Namespace::Html => {
+							let name = syn::parse_str::<syn::Ident>(element.name)
+								.expect("Unable to parse tag name as an identifier");
+							syn::parse_quote! { dynatos_web::html::#name }
+						},
+						// Note: Any other svg elements are created from their tag name.
+						Namespace::Svg if !SVG_ELEMENTS.contains(&element.name) => {
+							let name = element.name;
+							syn::parse_quote! {
+								(|ctx: &dynatos_web::DynatosWebCtx| dynatos_web::svg::element(ctx, #name))
+							}
+						},
+						// Note: Svg tags may be camel case, but the constructors are snake case.
+						Namespace::Svg => {
+							let name = element.name.to_case(convert_case::Case::Snake);
+							let name = syn::parse_str::<syn::Ident>(&name)
+								.or_else(|_| syn::parse_str::<syn::Ident>(&format!("r#{name}")))
+								.expect("Unable to parse tag name as an identifier");
+							syn::parse_quote! { dynatos_web::svg::#name }
+						},
 					},
 				};
 
+				// Reports any unknown attributes.
+				// Note: Expression (`:attr`) and event listener (`@attr`) attributes, as well as
+				//       `key`, are exempt, and we don't know the attributes of svg or custom elements.
+				let report_attrs = match ns {
+					Namespace::Html => element
+						.attrs
+						.iter()
+						.map(XHtmlAttr::name)
+						.filter(|&attr| !attr.starts_with([':', '@']) && attr != "key")
+						.filter(|attr| attrs::is_known(element.name, attr) == Some(false))
+						.map(|attr| attr_check.report(element.name, attr))
+						.collect::<Vec<_>>(),
+					Namespace::Svg => vec![],
+				};
+
 				// The element name for building it.
 				// Note: The name won't ever conflict with anything else due to it's `mixed_site` span.
 				let el = syn::Ident::new("el", proc_macro2::Span::mixed_site());
 
-				// Adds all attributes to the element
+				// Adds all attributes to the element, in source order
 				let add_attrs = element
 					.attrs
 					.iter()
-					.map(|(tag, value)| {
+					.map(|attr| {
+						let (tag, value) = match *attr {
+							XHtmlAttr::Static { name, value } => (name, value),
+							XHtmlAttr::Dyn { name, expr } => {
+								assert!(name != "key", "`key` attribute cannot be dynamic, use `:key` instead");
+								let expr = syn::parse_str::<syn::Expr>(expr)
+									.expect("Unable to parse dynamic attribute value as an expression");
+								return syn::parse_quote! {
+									dynatos_web_reactive::ElementWithDynAttr::with_dyn_attr(&#el, #name, #expr);
+								};
+							},
+						};
+
 						match tag {
+							// If the tag is `key`, attach it, instead of adding it as an attribute
+							"key" => {
+								let value = value.expect("`key` attribute needs a value");
+								let value = dynatos_web_parser::decode_entities(value);
+								syn::parse_quote! {
+									dynatos_web::ObjectAttachValue::attach_value(
+										AsRef::<dynatos_web::Object>::as_ref(&#el),
+										dynatos_web::NodeKey::new(#value)
+									);
+								}
+							},
+							":key" => {
+								let value = value.unwrap_or("key");
+								let value = syn::parse_str::<syn::Expr>(value)
+									.expect("Unable to parse `key` value as an expression");
+								syn::parse_quote! {
+									dynatos_web::ObjectAttachValue::attach_value(
+										AsRef::<dynatos_web::Object>::as_ref(&#el),
+										dynatos_web::NodeKey::new(std::string::ToString::to_string(&#value))
+									);
+								}
+							},
+
 							// If the tag name starts with a `:`, the value should be an expression
 							tag if let Some(tag) = tag.strip_prefix(":") => {
 								// Use the tag as the value if none is provided
-								let value = value.as_deref().unwrap_or(tag);
+								let value = value.unwrap_or(tag);
 								let value = syn::parse_str::<syn::Expr>(value)
 									.expect("Unable to parse attribute value as an expression");
 								syn::parse_quote! {
@@ -170,7 +367,7 @@ impl Node {
 									.expect("Unable to parse attribute name as an identifier");
 
 								// Use the value as the function handler
-								let value = value.as_deref().expect("Event listener needs a value");
+								let value = value.expect("Event listener needs a value");
 								let value =
 									syn::parse_str::<syn::Expr>(value).expect("Unable to parse event listener value");
 
@@ -183,8 +380,29 @@ impl Node {
 								}
 							},
 
+							// If the value has any arguments, interpolate them
+							_ if let Some(value) = value &&
+								value.contains("%{") =>
+							{
+								let value = InterpolatedText::new(value);
+								let add_attr: syn::Expr = match &value.text {
+									TextArg::Cons(s) => syn::parse_quote! {
+										dynatos_web::ElementWithAttr::with_attr(&#el, #tag, #s)
+									},
+									TextArg::DynArg(expr) => syn::parse_quote! {
+										dynatos_web_reactive::ElementWithDynAttr::with_dyn_attr(&#el, #tag, move || #expr)
+									},
+									TextArg::StaticArg(expr) => syn::parse_quote! {
+										dynatos_web::ElementWithAttr::with_attr(&#el, #tag, &#expr)
+									},
+								};
+
+								let add_attr = value.bind_static_args(&add_attr);
+								syn::parse_quote! { #add_attr; }
+							},
+
 							_ => {
-								let value = value.unwrap_or_default();
+								let value = dynatos_web_parser::decode_entities(value.unwrap_or_default());
 								syn::parse_quote! {
 									dynatos_web::ElementWithAttr::with_attr(&#el, #tag, #value);
 								}
@@ -199,35 +417,12 @@ impl Node {
 				//       all children to the type, as we'll be adding them separately.
 				// TODO: If we only contain text nodes, should we collect them all and
 				//       use `set_text_content` instead?
-				let add_children = element
-					.children
-					.iter()
-					.filter_map(|child| {
-						let child = Self::from_html(child)?;
-
-						let expr = match child.ty {
-							NodeTy::Element | NodeTy::Text | NodeTy::Comment => syn::parse_quote! {
-								dynatos_web::NodeAddChildren::add_children(
-									AsRef::<web_sys::Node>::as_ref(&#el),
-									#child
-								);
-							},
-							NodeTy::Expr => syn::parse_quote! {
-								dynatos_web_reactive::NodeDynChildren::add_dyn_children(
-									AsRef::<web_sys::Node>::as_ref(&#el),
-									&ctx,
-									#child
-								);
-							},
-						};
-
-						Some(expr)
-					})
-					.collect::<Vec<syn::Stmt>>();
+				let add_children = Self::add_children(&el, &element.children, children_ns, attr_check);
 
 				Self {
 					ty:   NodeTy::Element,
 					expr: syn::parse_quote! {{
+						#(#report_attrs)*
 						let #el = #constructor(&ctx);
 						#(#add_attrs)*
 						#(#add_children)*
@@ -242,45 +437,8 @@ impl Node {
 				}
 
 				// Otherwise, process the arguments.
-				// Note: If we have any dynamic arguments, we need to create a
-				//       closure to evaluate it inside, but we need to evaluate
-				//       any statics outside this closure, because they might be
-				//       temporaries that we can't capture.
-				//       To do this, we first "flatten" every non-dynamic argument,
-				//       grouping nearby constants and statics, then we evaluate
-				//       those statics outside using `ToString`, and move the evaluated
-				//       strings inside of the closure.
-				let args = self::split_text_args(text);
-				let args = self::flatten_text_args(&args);
-
-				let args_idents = args
-					.iter()
-					.enumerate()
-					.map(|(idx, _)| syn::Ident::new(&format!("_{idx}"), proc_macro2::Span::call_site()))
-					.collect::<Vec<_>>();
-
-				let (args_static, args_static_idents) = args
-					.iter()
-					.zip(&args_idents)
-					.filter_map(|(arg, ident)| {
-						let expr = arg.try_as_static_arg_ref()?;
-						let expr: syn::Expr = syn::parse_quote! { std::string::ToString::to_string(&#expr) };
-
-						Some((expr, ident))
-					})
-					.unzip::<_, _, Vec<_>, Vec<_>>();
-
-				let args = args
-					.iter()
-					.zip(&args_idents)
-					.map(|(arg, ident)| match arg {
-						TextArg::Cons(s) => TextArg::Cons(s),
-						TextArg::DynArg(expr) => TextArg::DynArg(expr.clone()),
-						TextArg::StaticArg(_) => TextArg::StaticArg(syn::parse_quote! { #ident }),
-					})
-					.collect::<Vec<_>>();
-
-				let text: syn::Expr = match self::format_text_args(&args, true) {
+				let text = InterpolatedText::new(text);
+				let expr: syn::Expr = match &text.text {
 					TextArg::Cons(s) => syn::parse_quote! {
 						dynatos_web::text(&ctx, #s)
 					},
@@ -295,11 +453,7 @@ impl Node {
 					},
 				};
 
-				let expr = syn::parse_quote! {
-					match ( #( #args_static, )* ) {
-						( #( #args_static_idents, )* ) => #text,
-					}
-				};
+				let expr = text.bind_static_args(&expr);
 				Self { ty: NodeTy::Text, expr }
 			},
 			XHtmlNode::Comment(comment) => Self {
@@ -310,6 +464,64 @@ impl Node {
 
 		Some(node)
 	}
+
+	/// Returns the statements to add all `children` to the element `el`
+	fn add_children(el: &syn::Ident, children: &[XHtmlNode], ns: Namespace, attr_check: AttrCheck) -> Vec<syn::Stmt> {
+		children
+			.iter()
+			.filter_map(|child| {
+				// If the child is a `<for>`, add all of its children for each item
+				if let XHtmlNode::Element(element) = child &&
+					element.name == "for"
+				{
+					return Some(Self::add_for_children(el, element, ns, attr_check));
+				}
+
+				let child = Self::from_html(child, ns, attr_check)?;
+				let expr = match child.ty {
+					NodeTy::Element | NodeTy::Text | NodeTy::Comment | NodeTy::StaticExpr => syn::parse_quote! {
+						dynatos_web::NodeAddChildren::add_children(
+							AsRef::<web_sys::Node>::as_ref(&#el),
+							#child
+						);
+					},
+					NodeTy::Expr => syn::parse_quote! {
+						dynatos_web_reactive::NodeDynChildren::add_dyn_children(
+							AsRef::<web_sys::Node>::as_ref(&#el),
+							&ctx,
+							#child
+						);
+					},
+				};
+
+				Some(expr)
+			})
+			.collect()
+	}
+
+	/// Returns the statement to add the children of `<for each={expr} item={pat}>` to the element `el`
+	fn add_for_children(el: &syn::Ident, element: &XHtmlElement, ns: Namespace, attr_check: AttrCheck) -> syn::Stmt {
+		let dyn_attr = |name| {
+			element.attrs.iter().find_map(|attr| match *attr {
+				XHtmlAttr::Dyn { name: attr, expr } if attr == name => Some(expr),
+				_ => None,
+			})
+		};
+
+		let each = dyn_attr("each").expect("`<for>` requires an `each={...}` attribute");
+		let each = syn::parse_str::<syn::Expr>(each).expect("Unable to parse `<for>` `each` as an expression");
+
+		let item = dyn_attr("item").expect("`<for>` requires an `item={...}` attribute");
+		let item =
+			Parser::parse_str(syn::Pat::parse_single, item).expect("Unable to parse `<for>` `item` as a pattern");
+
+		let add_children = Self::add_children(el, &element.children, ns, attr_check);
+		syn::parse_quote! {
+			for #item in #each {
+				#(#add_children)*
+			}
+		}
+	}
 }
 
 impl quote::ToTokens for Node {
@@ -318,11 +530,87 @@ impl quote::ToTokens for Node {
 	}
 }
 
+/// Text with interpolated arguments
+struct InterpolatedText<'a> {
+	/// Static arguments, which must be evaluated before the text
+	args_static: Vec<syn::Expr>,
+
+	/// Identifiers for each static argument
+	args_static_idents: Vec<syn::Ident>,
+
+	/// Formatted text
+	text: TextArg<'a>,
+}
+
+impl<'a> InterpolatedText<'a> {
+	/// Processes all arguments in `text`
+	fn new(text: &'a str) -> Self {
+		// Note: If we have any dynamic arguments, we need to create a
+		//       closure to evaluate it inside, but we need to evaluate
+		//       any statics outside this closure, because they might be
+		//       temporaries that we can't capture.
+		//       To do this, we first "flatten" every non-dynamic argument,
+		//       grouping nearby constants and statics, then we evaluate
+		//       those statics outside using `ToString`, and move the evaluated
+		//       strings inside of the closure.
+		let args = self::split_text_args(text);
+		let args = self::flatten_text_args(&args);
+
+		let args_idents = args
+			.iter()
+			.enumerate()
+			.map(|(idx, _)| syn::Ident::new(&format!("_{idx}"), proc_macro2::Span::call_site()))
+			.collect::<Vec<_>>();
+
+		let (args_static, args_static_idents) = args
+			.iter()
+			.zip(&args_idents)
+			.filter_map(|(arg, ident)| {
+				let expr = arg.try_as_static_arg_ref()?;
+				let expr: syn::Expr = syn::parse_quote! { std::string::ToString::to_string(&#expr) };
+
+				Some((expr, ident.clone()))
+			})
+			.unzip::<_, _, Vec<_>, Vec<_>>();
+
+		let args = args
+			.iter()
+			.zip(&args_idents)
+			.map(|(arg, ident)| match arg {
+				TextArg::Cons(s) => TextArg::Cons(s.clone()),
+				TextArg::DynArg(expr) => TextArg::DynArg(expr.clone()),
+				TextArg::StaticArg(_) => TextArg::StaticArg(syn::parse_quote! { #ident }),
+			})
+			.collect::<Vec<_>>();
+
+		Self {
+			args_static,
+			args_static_idents,
+			text: self::format_text_args(&args, true),
+		}
+	}
+
+	/// Binds all static arguments for use within `expr`
+	fn bind_static_args(&self, expr: &syn::Expr) -> syn::Expr {
+		let Self {
+			args_static,
+			args_static_idents,
+			..
+		} = self;
+
+		syn::parse_quote! {
+			match ( #( #args_static, )* ) {
+				( #( #args_static_idents, )* ) => #expr,
+			}
+		}
+	}
+}
+
 #[derive(Clone, Debug)]
 #[derive(strum::EnumIs, strum::EnumTryAs)]
 enum TextArg<'a> {
-	/// Constant
-	Cons(&'a str),
+	/// Constant, with any entities already decoded
+	Cons(Cow<'a, str>),
 
 	/// Dynamic argument
 	DynArg(syn::Expr),
@@ -348,7 +636,7 @@ fn split_text_args(mut text: &str) -> Vec<TextArg<'_>> {
 		#[expect(clippy::mixed_read_write_in_expression, reason = "False positive")]
 		let Some(start) = text.find("%{") else {
 			if !text.is_empty() {
-				args.push(TextArg::Cons(text));
+				args.push(TextArg::Cons(dynatos_web_parser::decode_entities(text)));
 			}
 			text = &text[text.len()..];
 			continue;
@@ -359,23 +647,28 @@ fn split_text_args(mut text: &str) -> Vec<TextArg<'_>> {
 		};
 
 		if start != 0 {
-			args.push(TextArg::Cons(&text[..start]));
+			args.push(TextArg::Cons(dynatos_web_parser::decode_entities(&text[..start])));
 		}
 
 		enum ArgKind {
 			Dyn,
 			Static,
+			Signal,
 		}
 		let arg = &text[start..][2..end];
 		let (kind, arg) = match arg {
 			arg if let Some(arg) = arg.strip_prefix("static") => (ArgKind::Static, arg),
 			arg if let Some(arg) = arg.strip_prefix("dyn") => (ArgKind::Dyn, arg),
+			arg if let Some(arg) = arg.trim_start().strip_prefix('~') => (ArgKind::Signal, arg),
 			_ => (ArgKind::Dyn, arg),
 		};
 		let arg = syn::parse_str::<syn::Expr>(arg).expect("Unable to parse argument expression");
 		let arg = match kind {
 			ArgKind::Dyn => TextArg::DynArg(arg),
 			ArgKind::Static => TextArg::StaticArg(arg),
+			ArgKind::Signal => TextArg::DynArg(syn::parse_quote! {
+				dynatos_reactive::SignalWith::with(&#arg, |value| std::string::ToString::to_string(&value))
+			}),
 		};
 		args.push(arg);
 
@@ -413,8 +706,9 @@ fn format_text_args<'a>(args: &[TextArg<'a>], to_string: bool) -> TextArg<'a> {
 	let fmt = args
 		.iter()
 		.map(|arg| match arg {
-			TextArg::Cons(text) => text,
-			TextArg::DynArg(_) | TextArg::StaticArg(_) => "{}",
+			// Note: Constants are used as part of the format string, so we need to escape any braces
+			TextArg::Cons(text) => text.replace('{', "{{").replace('}', "}}"),
+			TextArg::DynArg(_) | TextArg::StaticArg(_) => "{}".to_owned(),
 		})
 		.collect::<String>();
 
@@ -435,3 +729,385 @@ fn format_text_args<'a>(args: &[TextArg<'a>], to_string: bool) -> TextArg<'a> {
 		false => TextArg::StaticArg(fmt),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	/// Expands a single html node
+	fn expand(input: &str) -> String {
+		self::expand_with(input, AttrCheck::Warn)
+	}
+
+	/// Expands a single html node, with an attribute check
+	fn expand_with(input: &str, attr_check: AttrCheck) -> String {
+		let html = XHtml::parse(input).expect("Unable to parse html");
+		let [node] = &*html.children else {
+			panic!("Expected a single node");
+		};
+		let node = Node::from_html(node, Namespace::Html, attr_check).expect("Node was empty");
+
+		node.to_token_stream().to_string()
+	}
+
+	#[test]
+	fn dyn_attr() {
+		let expanded = expand(r#"<input type="checkbox" disabled={is_disabled.clone()} />"#);
+
+		let static_attr = quote::quote! {
+			dynatos_web::ElementWithAttr::with_attr(&el, "type", "checkbox");
+		};
+		let dyn_attr = quote::quote! {
+			dynatos_web_reactive::ElementWithDynAttr::with_dyn_attr(&el, "disabled", is_disabled.clone());
+		};
+		assert!(
+			expanded.contains(&static_attr.to_string()),
+			"Static attribute missing: {expanded}"
+		);
+		assert!(
+			expanded.contains(&dyn_attr.to_string()),
+			"Dynamic attribute missing: {expanded}"
+		);
+	}
+
+	#[test]
+	fn attrs_order() {
+		let expanded = expand(r#"<div a={x} b="1" c={y} />"#);
+
+		let attrs = [
+			quote::quote! { dynatos_web_reactive::ElementWithDynAttr::with_dyn_attr(&el, "a", x); },
+			quote::quote! { dynatos_web::ElementWithAttr::with_attr(&el, "b", "1"); },
+			quote::quote! { dynatos_web_reactive::ElementWithDynAttr::with_dyn_attr(&el, "c", y); },
+		];
+		let positions = attrs
+			.iter()
+			.map(|attr| {
+				expanded
+					.find(&attr.to_string())
+					.unwrap_or_else(|| panic!("Attribute missing: {attr}\n{expanded}"))
+			})
+			.collect::<Vec<_>>();
+		assert!(positions.is_sorted(), "Attributes weren't in source order: {expanded}");
+	}
+
+	#[test]
+	fn dyn_attr_braces() {
+		let expanded = expand(r#"<div class={ { let class = "a"; class } }></div>"#);
+
+		let dyn_attr = quote::quote! {
+			dynatos_web_reactive::ElementWithDynAttr::with_dyn_attr(&el, "class", { let class = "a"; class });
+		};
+		assert!(
+			expanded.contains(&dyn_attr.to_string()),
+			"Dynamic attribute missing: {expanded}"
+		);
+	}
+
+	#[test]
+	fn text_entities() {
+		let expanded = expand("a &lt; b &amp;&amp; c &#x3e; d");
+
+		let text = quote::quote! { dynatos_web::text(&ctx, "a < b && c > d") };
+		assert!(expanded.contains(&text.to_string()), "Decoded text missing: {expanded}");
+	}
+
+	#[test]
+	fn text_entities_args() {
+		let expanded = expand("&#123;%{static value}%&#125; &amp;");
+
+		let text = quote::quote! { format_args!("{{{}}} &", value) };
+		assert!(expanded.contains(&text.to_string()), "Decoded text missing: {expanded}");
+	}
+
+	#[test]
+	fn attr_args_static() {
+		let expanded = expand(r#"<a href="/users/%{static id}%/posts?a=1&amp;b=2"></a>"#);
+
+		let add_attr = quote::quote! {
+			match (std::string::ToString::to_string(&format_args!("/users/{}/posts?a=1&b=2", id)),) {
+				(_0,) => dynatos_web::ElementWithAttr::with_attr(&el, "href", &_0),
+			};
+		};
+		assert!(
+			expanded.contains(&add_attr.to_string()),
+			"Interpolated attribute missing: {expanded}"
+		);
+	}
+
+	#[test]
+	fn attr_args_dyn() {
+		let expanded = expand(r#"<div class="item %{static kind}% %{state.get()}%-state"></div>"#);
+
+		let add_attr = quote::quote! {
+			match (std::string::ToString::to_string(&format_args!("item {} ", kind)),) {
+				(_0,) => dynatos_web_reactive::ElementWithDynAttr::with_dyn_attr(
+					&el,
+					"class",
+					move || format!("{}{}-state", _0, state.get())
+				),
+			};
+		};
+		// Note: Closures are printed as `| |` within the expansion, so we ignore whitespace
+		let [expanded, add_attr] = [expanded, add_attr.to_string()].map(|s| s.replace(' ', ""));
+		assert!(
+			expanded.contains(&add_attr),
+			"Interpolated attribute missing: {expanded}"
+		);
+	}
+
+	#[test]
+	fn text_args_signal() {
+		let expanded = expand("<p>Count: %{~count}%</p>");
+
+		let text = quote::quote! {
+			dynatos_web_reactive::NodeWithDynText::with_dyn_text(
+				dynatos_web::text(&ctx, ""),
+				move || format!(
+					"Count: {}",
+					dynatos_reactive::SignalWith::with(&count, |value| std::string::ToString::to_string(&value))
+				)
+			)
+		};
+		// Note: Closures are printed as `| |` within the expansion, so we ignore whitespace
+		let [expanded, text] = [expanded, text.to_string()].map(|s| s.replace(' ', ""));
+		assert!(expanded.contains(&text), "Signal text missing: {expanded}");
+	}
+
+	#[test]
+	fn attr_no_args() {
+		let expanded = expand(r#"<a href="/users?a=1&amp;b=2" :title="title"></a>"#);
+
+		let add_attrs = quote::quote! {
+			dynatos_web::ElementWithAttr::with_attr(&el, "href", "/users?a=1&b=2");
+			dynatos_web::ElementWithAttr::with_attr(&el, "title", &title);
+		};
+		assert!(
+			expanded.contains(&add_attrs.to_string()),
+			"Attributes missing: {expanded}"
+		);
+	}
+
+	#[test]
+	fn static_expr_children() {
+		let expanded = expand("<div><p>Header</p><>static fragment(&ctx)</><>static_value</></div>");
+
+		let add_static = quote::quote! {
+			dynatos_web::NodeAddChildren::add_children(
+				AsRef::<web_sys::Node>::as_ref(&el),
+				fragment(&ctx)
+			);
+		};
+		let add_dyn = quote::quote! {
+			dynatos_web_reactive::NodeDynChildren::add_dyn_children(
+				AsRef::<web_sys::Node>::as_ref(&el),
+				&ctx,
+				static_value
+			);
+		};
+		assert!(
+			expanded.contains(&add_static.to_string()),
+			"Static expression missing: {expanded}"
+		);
+		assert!(
+			expanded.contains(&add_dyn.to_string()),
+			"Dynamic expression missing: {expanded}"
+		);
+	}
+
+	#[test]
+	fn for_children() {
+		let expanded = expand(
+			"<ul><for each={items.iter().enumerate()} item={(idx, item)}><li>%{static idx}%: %{static \
+			 item}%</li></for></ul>",
+		);
+
+		let for_loop = quote::quote! { for (idx, item) in items.iter().enumerate() };
+		assert!(expanded.contains(&for_loop.to_string()), "For loop missing: {expanded}");
+		assert!(
+			expanded.contains("dynatos_web :: html :: li"),
+			"For loop children missing: {expanded}"
+		);
+	}
+
+	#[test]
+	fn for_children_empty() {
+		let expanded = expand("<ul><for each={[(); 0]} item={()}></for></ul>");
+
+		let for_loop = quote::quote! { for () in [(); 0] {} };
+		assert!(expanded.contains(&for_loop.to_string()), "For loop missing: {expanded}");
+	}
+
+	#[test]
+	#[should_panic = "`<for>` may only be used inside of an element"]
+	fn for_root() {
+		_ = expand("<for each={items} item={item}><li></li></for>");
+	}
+
+	#[test]
+	fn svg_children() {
+		let expanded =
+			expand(r#"<div><svg viewBox="0 0 10 10"><g><circle r="5"/><linearGradient/><use/></g></svg><p></p></div>"#);
+
+		for constructor in [
+			quote::quote! { dynatos_web::svg::svg(&ctx) },
+			quote::quote! { dynatos_web::svg::g(&ctx) },
+			quote::quote! { dynatos_web::svg::circle(&ctx) },
+			quote::quote! { dynatos_web::svg::linear_gradient(&ctx) },
+			quote::quote! { dynatos_web::svg::r#use(&ctx) },
+			quote::quote! { dynatos_web::html::div(&ctx) },
+			quote::quote! { dynatos_web::html::p(&ctx) },
+		] {
+			assert!(
+				expanded.contains(&constructor.to_string()),
+				"Constructor {constructor} missing: {expanded}"
+			);
+		}
+	}
+
+	#[test]
+	fn svg_unknown() {
+		let expanded = expand("<svg><filter><feGaussianBlur/></filter></svg>");
+
+		for el in ["filter", "feGaussianBlur"] {
+			let constructor = quote::quote! { dynatos_web::svg::element(ctx, #el) };
+			assert!(
+				expanded.contains(&constructor.to_string()),
+				"Element {el:?} constructor missing: {expanded}"
+			);
+		}
+	}
+
+	#[test]
+	fn svg_foreign_object() {
+		let expanded = expand("<svg><a></a><foreignObject><a></a></foreignObject></svg>");
+
+		for constructor in [
+			quote::quote! { dynatos_web::svg::a(&ctx) },
+			quote::quote! { dynatos_web::svg::foreign_object(&ctx) },
+			quote::quote! { dynatos_web::html::a(&ctx) },
+		] {
+			assert!(
+				expanded.contains(&constructor.to_string()),
+				"Constructor {constructor} missing: {expanded}"
+			);
+		}
+	}
+
+	#[test]
+	fn unknown_attr_warn() {
+		let expanded =
+			expand(r#"<div><a hreff="/" href="/" data-id="1" aria-label="a" :hreff="x" @click="f"></a></div>"#);
+
+		let report = quote::quote! {
+			#[deprecated(note = "Unknown attribute `hreff` for element `<a>`")]
+		};
+		assert_eq!(
+			expanded.matches(&report.to_string()).count(),
+			1,
+			"Expected a single unknown attribute warning: {expanded}"
+		);
+		assert!(!expanded.contains("compile_error"), "Unexpected error: {expanded}");
+	}
+
+	#[test]
+	fn unknown_attr_event() {
+		let expanded = expand(r#"<div onclik="f()" onclick="g()" OnKeyDown="h()"></div>"#);
+
+		let report = quote::quote! {
+			#[deprecated(note = "Unknown attribute `onclik` for element `<div>`")]
+		};
+		assert_eq!(
+			expanded.matches(&report.to_string()).count(),
+			1,
+			"Expected a single unknown attribute warning: {expanded}"
+		);
+		assert_eq!(
+			expanded.matches("deprecated").count(),
+			1,
+			"Unexpected warning: {expanded}"
+		);
+	}
+
+	#[test]
+	fn unknown_attr_strict() {
+		let expanded = expand_with(r#"<input typ="text" value={value} />"#, AttrCheck::Strict);
+
+		let report = quote::quote! {
+			compile_error!("Unknown attribute `typ` for element `<input>`");
+		};
+		assert!(
+			expanded.contains(&report.to_string()),
+			"Unknown attribute error missing: {expanded}"
+		);
+		assert!(!expanded.contains("deprecated"), "Unexpected warning: {expanded}");
+	}
+
+	#[test]
+	fn unknown_attr_exempt() {
+		for input in [
+			r#"<custom foo="1"></custom>"#,
+			r#"<svg foo="1"><circle bar="2"/></svg>"#,
+			r#"<div id="a" class="b" onclick="c" DATA-x="d"></div>"#,
+		] {
+			let expanded = expand_with(input, AttrCheck::Strict);
+			assert!(!expanded.contains("compile_error"), "Unexpected error: {expanded}");
+		}
+	}
+
+	#[test]
+	fn macro_input_strict() {
+		let input =
+			syn::parse_str::<MacroInput>(r##"#[html(strict)] r#"<div></div>"#"##).expect("Unable to parse input");
+		assert_eq!(input.attr_check, AttrCheck::Strict);
+		assert_eq!(input.lit.value(), "<div></div>");
+
+		let input = syn::parse_str::<MacroInput>(r#""<div></div>""#).expect("Unable to parse input");
+		assert_eq!(input.attr_check, AttrCheck::Warn);
+
+		assert!(syn::parse_str::<MacroInput>(r#"#[html(lenient)] "<div></div>""#).is_err());
+	}
+
+	#[test]
+	fn key_attr() {
+		let expanded = expand(r#"<ul><li key="a&amp;b" class="item"></li><li :key="item.id"></li></ul>"#);
+
+		for attach in [
+			quote::quote! {
+				dynatos_web::ObjectAttachValue::attach_value(
+					AsRef::<dynatos_web::Object>::as_ref(&el),
+					dynatos_web::NodeKey::new("a&b")
+				);
+			},
+			quote::quote! {
+				dynatos_web::ObjectAttachValue::attach_value(
+					AsRef::<dynatos_web::Object>::as_ref(&el),
+					dynatos_web::NodeKey::new(std::string::ToString::to_string(&item.id))
+				);
+			},
+		] {
+			assert!(expanded.contains(&attach.to_string()), "Key missing: {expanded}");
+		}
+
+		assert!(
+			!expanded.contains("\"key\""),
+			"Key was added as an attribute: {expanded}"
+		);
+		assert!(
+			!expanded.contains("deprecated"),
+			"Key was reported as unknown: {expanded}"
+		);
+		assert!(
+			expanded.contains(
+				&quote::quote! { dynatos_web::ElementWithAttr::with_attr(&el, "class", "item"); }.to_string()
+			),
+			"Attribute missing: {expanded}"
+		);
+	}
+
+	#[test]
+	#[should_panic = "`key` attribute cannot be dynamic, use `:key` instead"]
+	fn key_attr_dyn() {
+		_ = expand("<li key={id}></li>");
+	}
+}
@@ -1,7 +1,7 @@
 //! `XHtml` parser for `dynatos-web`
 
 // Features
-#![feature(pattern, try_blocks, try_trait_v2)]
+#![feature(pattern, try_trait_v2)]
 
 // Imports
 use {
@@ -10,7 +10,7 @@ use {
 		ops::{ControlFlow, Try},
 		str::pattern::Pattern,
 	},
-	std::collections::HashMap,
+	std::borrow::Cow,
 	unicode_xid::UnicodeXID,
 };
 
@@ -23,12 +23,14 @@ pub struct XHtml<'a> {
 
 impl<'a> XHtml<'a> {
 	/// Parses an `XHtml` document
-	pub fn parse(mut s: &'a str) -> Result<Self, (&'a str, Error)> {
+	pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+		let mut s = input;
+
 		// Ignore the doctype if it exists
 		if s.starts_with("<!") &&
 			let Err(err) = Self::parse_doctype(&mut s)
 		{
-			return Err((s, err));
+			return Err(ParseError::new(input, s, err));
 		}
 
 		// Parse all children until `s` is empty.
@@ -40,7 +42,7 @@ impl<'a> XHtml<'a> {
 
 		match res {
 			Ok(children) => Ok(XHtml { children }),
-			Err(err) => Err((s, err)),
+			Err(err) => Err(ParseError::new(input, s, err)),
 		}
 	}
 
@@ -116,8 +118,8 @@ pub struct XHtmlElement<'a> {
 	/// Name
 	pub name: &'a str,
 
-	/// Attributes
-	pub attrs: HashMap<&'a str, Option<&'a str>>,
+	/// Attributes, in source order
+	pub attrs: Vec<XHtmlAttr<'a>>,
 
 	/// Children
 	pub children: Vec<XHtmlNode<'a>>,
@@ -136,7 +138,7 @@ impl<'a> XHtmlElement<'a> {
 
 		// Then parse the attributes if we weren't empty
 		let (attrs, is_self_closing) = match start.is_empty {
-			true => (HashMap::new(), false),
+			true => (vec![], false),
 			false => {
 				self::eat_whitespace(s);
 				let res = self::parse_element_attrs(s)?;
@@ -144,6 +146,16 @@ impl<'a> XHtmlElement<'a> {
 			},
 		};
 
+		// Note: Void elements never have any children, so they're always self-closing.
+		//       We still accept a matching closing tag, such as `<br></br>`, which we ignore.
+		let is_void = self::is_void_element(name);
+		if is_void {
+			_ = self::try_parse(s, |s| {
+				self::parse_close_element(s).filter(|close_name| close_name.eq_ignore_ascii_case(name))
+			});
+		}
+		let is_self_closing = is_self_closing || is_void;
+
 		// Then parse all children if we're not self-closing
 		let inner_span_start = *s;
 		let (children, inner_span_end) = match is_self_closing {
@@ -151,6 +163,8 @@ impl<'a> XHtmlElement<'a> {
 			false => {
 				let res = self::parse_element_children(s)?;
 				if name != res.close_name {
+					// Note: We rewind to the closing tag, so the error is reported there.
+					*s = res.inner_span_end;
 					return Err(Error::WrongClose {
 						open_name:  name.to_owned(),
 						close_name: res.close_name.to_owned(),
@@ -173,6 +187,114 @@ impl<'a> XHtmlElement<'a> {
 	}
 }
 
+/// `XHtml` attribute
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum XHtmlAttr<'a> {
+	/// Static attribute, `attr="value"` or `attr`
+	Static { name: &'a str, value: Option<&'a str> },
+
+	/// Dynamic attribute, `attr={expr}`, with the inner expression
+	Dyn { name: &'a str, expr: &'a str },
+}
+
+impl<'a> XHtmlAttr<'a> {
+	/// Returns the name of this attribute
+	#[must_use]
+	pub const fn name(&self) -> &'a str {
+		match *self {
+			Self::Static { name, .. } | Self::Dyn { name, .. } => name,
+		}
+	}
+}
+
+/// Decodes all html entities in `s`.
+///
+/// Supports the predefined entities (`&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;`),
+/// as well as numeric entities (`&#60;` and `&#x3c;`).
+///
+/// Any malformed or unknown entities are kept as-is.
+///
+/// Only allocates if `s` contains any entities.
+#[must_use]
+pub fn decode_entities(s: &str) -> Cow<'_, str> {
+	let Some(first) = s.find('&') else {
+		return Cow::Borrowed(s);
+	};
+
+	let mut output = String::with_capacity(s.len());
+	output.push_str(&s[..first]);
+
+	let mut rest = &s[first..];
+	while let Some(start) = rest.find('&') {
+		output.push_str(&rest[..start]);
+		rest = &rest[start..];
+
+		match self::decode_entity(rest) {
+			Some((ch, len)) => {
+				output.push(ch);
+				rest = &rest[len..];
+			},
+			None => {
+				output.push('&');
+				rest = &rest[1..];
+			},
+		}
+	}
+	output.push_str(rest);
+
+	Cow::Owned(output)
+}
+
+/// Decodes the entity at the start of `s`.
+///
+/// Returns the decoded character and the length of the entity
+fn decode_entity(s: &str) -> Option<(char, usize)> {
+	let end = s.find(';')?;
+	let name = &s[1..end];
+	let ch = match name {
+		"amp" => '&',
+		"lt" => '<',
+		"gt" => '>',
+		"quot" => '"',
+		"apos" => '\'',
+		_ => {
+			let num = name.strip_prefix('#')?;
+			let code = match num.strip_prefix(['x', 'X']) {
+				Some(hex) => self::parse_entity_num(hex, 16)?,
+				None => self::parse_entity_num(num, 10)?,
+			};
+
+			char::from_u32(code)?
+		},
+	};
+
+	Some((ch, end + 1))
+}
+
+/// Parses the number of a numeric entity
+fn parse_entity_num(s: &str, radix: u32) -> Option<u32> {
+	// Note: `from_str_radix` accepts a leading sign, which entities don't
+	if !s.chars().next().is_some_and(|ch| ch.is_digit(radix)) {
+		return None;
+	}
+
+	u32::from_str_radix(s, radix).ok()
+}
+
+/// Void elements.
+///
+/// See <https://html.spec.whatwg.org/multipage/syntax.html#void-elements>
+const VOID_ELEMENTS: [&str; 13] = [
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr",
+];
+
+/// Returns if `name` is a void element
+fn is_void_element(name: &str) -> bool {
+	VOID_ELEMENTS
+		.iter()
+		.any(|void_element| void_element.eq_ignore_ascii_case(name))
+}
+
 /// Eats `pat` from `s`.
 ///
 /// Returns the eaten part
@@ -225,6 +347,35 @@ fn parse_attr_value<'a>(s: &mut &'a str) -> Result<&'a str, Error> {
 	Ok(value)
 }
 
+/// Parses a dynamic attribute value, `...}`, after the opening brace.
+///
+/// Returns the value without the closing brace.
+fn parse_dyn_attr_value<'a>(s: &mut &'a str) -> Result<&'a str, Error> {
+	// Note: We need to keep track of any inner braces, so that
+	//       block expressions and struct literals work.
+	let mut depth = 0_usize;
+	let end = s
+		.find(|ch| match ch {
+			'{' => {
+				depth += 1;
+				false
+			},
+			'}' => match depth.checked_sub(1) {
+				Some(new_depth) => {
+					depth = new_depth;
+					false
+				},
+				None => true,
+			},
+			_ => false,
+		})
+		.ok_or(Error::AttrValueBraceEnd)?;
+	let value = &s[..end];
+	*s = &s[end + 1..];
+
+	Ok(value)
+}
+
 #[derive(Debug)]
 struct ParsedElementStart<'a> {
 	name:     &'a str,
@@ -251,33 +402,39 @@ fn parse_element_start<'a>(s: &mut &'a str) -> Result<ParsedElementStart<'a>, Er
 
 #[derive(Debug)]
 struct ParsedElementAttrs<'a> {
-	attrs:           HashMap<&'a str, Option<&'a str>>,
+	attrs:           Vec<XHtmlAttr<'a>>,
 	is_self_closing: bool,
 }
 
-/// Parses an element's attributes, a mix of `attr1=value1 attr2=value2` or `attr1 attr2`,
-/// followed with `>` or `/>`.
+/// Parses an element's attributes, a mix of `attr1=value1 attr2=value2`,
+/// `attr1={expr1}` or `attr1 attr2`, followed with `>` or `/>`.
 fn parse_element_attrs<'a>(s: &mut &'a str) -> Result<ParsedElementAttrs<'a>, Error> {
-	let mut is_self_closing = false;
-	let attrs = iter::from_fn(|| {
+	let mut attrs = vec![];
+	let is_self_closing = loop {
 		self::eat_whitespace(s);
-		match self::eat(s, '>') {
-			Some(_) => None,
-			None => {
-				if self::eat(s, "/>").is_some() {
-					is_self_closing = true;
-					return None;
-				}
-				let Some(attr) = self::parse_ident(s) else {
-					return Some(Err(Error::Identifier));
-				};
-				let value = self::eat(s, '=').map(|_| self::parse_attr_value(s)).transpose();
+		if self::eat(s, '>').is_some() {
+			break false;
+		}
+		if self::eat(s, "/>").is_some() {
+			break true;
+		}
 
-				Some(try { (attr, value?) })
+		let name = self::parse_ident(s).ok_or(Error::Identifier)?;
+		let attr = match self::eat(s, '=') {
+			Some(_) => match self::eat(s, '{') {
+				Some(_) => XHtmlAttr::Dyn {
+					name,
+					expr: self::parse_dyn_attr_value(s)?,
+				},
+				None => XHtmlAttr::Static {
+					name,
+					value: Some(self::parse_attr_value(s)?),
+				},
 			},
-		}
-	})
-	.collect::<Result<_, Error>>()?;
+			None => XHtmlAttr::Static { name, value: None },
+		};
+		attrs.push(attr);
+	};
 
 	Ok(ParsedElementAttrs { attrs, is_self_closing })
 }
@@ -298,6 +455,7 @@ fn parse_element_children<'a>(s: &mut &'a str) -> Result<ParsedElementChildren<'
 			Some(name) => {
 				break (name, inner_span_end);
 			},
+			None if s.is_empty() => return Err(Error::MissingClose),
 			None => children.push(XHtmlNode::parse(s)?),
 		}
 	};
@@ -356,7 +514,38 @@ fn span_from_start_end<'a>(start: &'a str, end: &'a str) -> &'a str {
 	&start[..start.len() - end.len()]
 }
 
-// TODO: This should have a span associated
+/// Parse error
+#[derive(thiserror::Error, Debug)]
+#[error("{kind} at {line}:{column}")]
+pub struct ParseError {
+	/// Byte offset of the error
+	pub offset: usize,
+
+	/// Line of the error, 1-indexed
+	pub line: usize,
+
+	/// Column of the error, in characters, 1-indexed
+	pub column: usize,
+
+	/// Error kind
+	pub kind: Error,
+}
+
+impl ParseError {
+	/// Creates a parse error from the input and the remaining input at the error
+	fn new(input: &str, rest: &str, kind: Error) -> Self {
+		let before = self::span_from_start_end(input, rest);
+		let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+		Self {
+			offset: before.len(),
+			line: before.matches('\n').count() + 1,
+			column: before[line_start..].chars().count() + 1,
+			kind,
+		}
+	}
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
 	#[error("Expected `<!doctype html>`")]
@@ -377,6 +566,176 @@ pub enum Error {
 	#[error("Expected `\"` after `attr=\"...`")]
 	AttrValueQuoteEnd,
 
+	#[error("Expected `}}` after `attr={{...`")]
+	AttrValueBraceEnd,
+
+	#[error("Expected a closing tag, found the end of the input")]
+	MissingClose,
+
 	#[error("Expected `{open_name}`, found `{close_name:?}`")]
 	WrongClose { open_name: String, close_name: String },
 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	/// Parses `input` as a single element
+	fn parse_element(input: &str) -> XHtmlElement<'_> {
+		let html = XHtml::parse(input).expect("Unable to parse html");
+		match <[_; 1]>::try_from(html.children) {
+			Ok([XHtmlNode::Element(element)]) => element,
+			res => panic!("Expected a single element, found {res:?}"),
+		}
+	}
+
+	#[test]
+	fn decode_entities_named() {
+		assert_eq!(
+			decode_entities("&lt;a href=&quot;x&quot;&gt; &amp; &apos;"),
+			"<a href=\"x\"> & '"
+		);
+	}
+
+	#[test]
+	fn decode_entities_decimal() {
+		assert_eq!(decode_entities("&#60;&#62;&#38;&#0065;"), "<>&A");
+	}
+
+	#[test]
+	fn decode_entities_hex() {
+		assert_eq!(decode_entities("&#x3c;&#X3E;&#x1F600;"), "<>\u{1f600}");
+	}
+
+	#[test]
+	fn decode_entities_malformed() {
+		for input in [
+			"&",
+			"&;",
+			"&amp",
+			"&unknown;",
+			"&#;",
+			"&#x;",
+			"&#-1;",
+			"&#+1;",
+			"&#x+1;",
+			"&#12a;",
+			"&#xD800;",
+			"&#99999999999;",
+		] {
+			assert_eq!(decode_entities(input), input);
+		}
+
+		assert_eq!(decode_entities("a & b &amp c &amp; d"), "a & b &amp c & d");
+		assert_eq!(decode_entities("&&amp;&#;&lt;"), "&&&#;<");
+	}
+
+	#[test]
+	fn decode_entities_borrowed() {
+		assert!(matches!(decode_entities("no entities"), Cow::Borrowed("no entities")));
+	}
+
+	#[test]
+	fn attrs_order() {
+		let element = parse_element(r#"<div c="3" a="1" b d={2} e="5" f={6}></div>"#);
+		assert_eq!(element.attrs, [
+			XHtmlAttr::Static {
+				name:  "c",
+				value: Some("3"),
+			},
+			XHtmlAttr::Static {
+				name:  "a",
+				value: Some("1"),
+			},
+			XHtmlAttr::Static {
+				name:  "b",
+				value: None,
+			},
+			XHtmlAttr::Dyn { name: "d", expr: "2" },
+			XHtmlAttr::Static {
+				name:  "e",
+				value: Some("5"),
+			},
+			XHtmlAttr::Dyn { name: "f", expr: "6" },
+		]);
+	}
+
+	#[test]
+	fn void_elements() {
+		for name in VOID_ELEMENTS {
+			for input in [format!("<{name}>"), format!("<{name} a=\"1\">"), format!("<{name}/>")] {
+				let element = parse_element(&input);
+				assert_eq!(element.name, name);
+				assert!(element.children.is_empty());
+				assert_eq!(element.inner, None);
+			}
+		}
+	}
+
+	#[test]
+	fn void_elements_siblings() {
+		let element = parse_element(r#"<p>a<br>b<img src="c.png">d</p>"#);
+		let names = element
+			.children
+			.iter()
+			.map(|child| match child {
+				XHtmlNode::Element(element) => element.name,
+				XHtmlNode::Text(text) => text,
+				XHtmlNode::Comment(_) => panic!("Unexpected comment"),
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(names, ["a", "br", "b", "img", "d"]);
+	}
+
+	#[test]
+	fn void_elements_close() {
+		for input in ["<br></br>", "<br/></br>", "<BR></br>", "<br></ br >"] {
+			let element = parse_element(input);
+			assert_eq!(element.name.to_ascii_lowercase(), "br");
+			assert!(element.children.is_empty());
+		}
+
+		let element = parse_element(r#"<p><input a="1"></input>b</p>"#);
+		assert!(
+			matches!(&*element.children, [XHtmlNode::Element(input), XHtmlNode::Text("b")] if input.name == "input"),
+			"Unexpected children: {:?}",
+			element.children
+		);
+
+		// Note: Closing tags for other elements are left to the parent
+		let element = parse_element("<p><br></p>");
+		assert_eq!(element.children.len(), 1);
+	}
+
+	#[test]
+	fn non_void_requires_close() {
+		let err = XHtml::parse("<div>").expect_err("Unclosed `<div>` should fail to parse");
+		assert!(matches!(err.kind, Error::MissingClose), "Unexpected error: {err:?}");
+
+		let element = parse_element("<div><br></div>");
+		assert_eq!(element.name, "div");
+		assert_eq!(element.children.len(), 1);
+	}
+
+	#[test]
+	fn wrong_close_position() {
+		let err = XHtml::parse("<div>\n\t<p>a</span>\n</div>").expect_err("Mismatched close should fail to parse");
+		assert!(
+			matches!(&err.kind, Error::WrongClose { open_name, close_name } if open_name == "p" && close_name == "span"),
+			"Unexpected error: {err:?}"
+		);
+		assert_eq!((err.offset, err.line, err.column), (11, 2, 6));
+		assert_eq!(err.to_string(), "Expected `p`, found `\"span\"` at 2:6");
+	}
+
+	#[test]
+	fn wrong_close_position_unicode() {
+		let err = XHtml::parse("<a>\u{e9}\u{e9}</b>").expect_err("Mismatched close should fail to parse");
+		assert!(
+			matches!(err.kind, Error::WrongClose { .. }),
+			"Unexpected error: {err:?}"
+		);
+		assert_eq!((err.offset, err.line, err.column), (7, 1, 6));
+	}
+}
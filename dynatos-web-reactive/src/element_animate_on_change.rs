@@ -0,0 +1,132 @@
+//! Element animation on change
+
+// Imports
+use {
+	crate::ObjectAttachEffect,
+	core::mem,
+	dynatos_reactive::{Effect, SignalWith},
+	dynatos_sync_types::{IMut, RcPtr, SyncBounds},
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, ElementAddListener, ElementRemoveClass, ElementToggleClass, cfg_ssr_expr, ev},
+	js_sys::WeakRef,
+	web_sys::{Element, Event},
+};
+
+/// Extension trait to animate an element whenever a signal changes
+#[extend::ext(name = ElementAnimateOnChange)]
+pub impl Element {
+	/// Animates this element whenever `signal` changes.
+	///
+	/// Whenever `signal` changes (but not initially), `class` is added to this element,
+	/// and it's then removed on the next `animationend` or `transitionend` event of
+	/// this element (events bubbling from its children are ignored).
+	///
+	/// This allows `class` to define a css animation or transition that plays
+	/// each time the value changes.
+	///
+	/// If `signal` changes while `class` is still present, the animation isn't restarted.
+	#[track_caller]
+	fn animate_on_change<S>(&self, ctx: &DynatosWebCtx, signal: S, class: impl Into<RcPtr<str>>)
+	where
+		S: SyncBounds + SignalWith + 'static,
+	{
+		let class = class.into();
+
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let element = WeakRef::new(self);
+		let effect_class = RcPtr::clone(&class);
+		let has_run = IMut::new(false);
+		let animate_effect = Effect::try_new(move || {
+			// Try to get the element
+			let element = element.deref().or_return()?;
+
+			// Note: We only add the class on changes, not when first created
+			signal.with(|_| ());
+			if !mem::replace(&mut *has_run.lock(), true) {
+				return;
+			}
+
+			// Note: If the class is still present, we don't want to add it twice
+			element.toggle_class(&*effect_class, true);
+		})
+		.or_return()?;
+		self.attach_effect(animate_effect);
+
+		let animation_class = RcPtr::clone(&class);
+		self.add_event_listener_el::<ev!(animationend)>(ctx, move |element, ev| {
+			if self::is_target(&ev, &element) {
+				element.remove_class(&*animation_class);
+			}
+		});
+		self.add_event_listener_el::<ev!(transitionend)>(ctx, move |element, ev| {
+			if self::is_target(&ev, &element) {
+				element.remove_class(&*class);
+			}
+		});
+	}
+}
+
+/// Extension trait to animate an element whenever a signal changes
+#[extend::ext(name = ElementWithAnimateOnChange)]
+pub impl<E> E
+where
+	E: AsRef<Element>,
+{
+	/// Animates this element whenever `signal` changes.
+	///
+	/// See [`animate_on_change`](ElementAnimateOnChange::animate_on_change) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_animate_on_change<S>(self, ctx: &DynatosWebCtx, signal: S, class: impl Into<RcPtr<str>>) -> Self
+	where
+		S: SyncBounds + SignalWith + 'static,
+	{
+		self.as_ref().animate_on_change(ctx, signal, class);
+		self
+	}
+}
+
+/// Returns if `element` is the target of `ev`
+#[allow(
+	clippy::allow_attributes,
+	clippy::missing_const_for_fn,
+	reason = "This only applies in some branches"
+)]
+fn is_target(ev: &Event, element: &Element) -> bool {
+	cfg_ssr_expr!(
+		ssr = {
+			// Note: There are no events during server-side rendering
+			_ = (ev, element);
+			false
+		},
+		csr = ev.target().as_ref() == Some(element.as_ref()),
+	)
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::test_util,
+		dynatos_reactive::{Signal, SignalSet},
+		dynatos_web::html,
+	};
+
+	#[test]
+	fn animate_on_change() {
+		let ctx = test_util::ctx();
+		let value = Signal::new(0);
+		let div = html::div(&ctx).with_animate_on_change(&ctx, value.clone(), "changed");
+		assert_eq!(div.class_name(), "", "Class was added initially");
+
+		value.set(1);
+		assert_eq!(div.class_name(), "changed", "Class wasn't added on change");
+
+		value.set(2);
+		assert_eq!(div.class_name(), "changed", "Class was added twice");
+	}
+}
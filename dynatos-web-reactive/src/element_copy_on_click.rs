@@ -0,0 +1,122 @@
+//! Element copy on click
+
+// Imports
+use {
+	crate::WithDynText,
+	core::time::Duration,
+	dynatos_reactive::{Signal, SignalSet},
+	dynatos_sync_types::{IMut, RcPtr, SyncBounds},
+	dynatos_web::{DynatosWebCtx, ElementAddListener, clipboard, ev, timer},
+	web_sys::Element,
+};
+
+/// Extension trait to copy some text whenever an element is clicked
+#[extend::ext(name = ElementCopyOnClick)]
+pub impl Element {
+	/// Copies `text` to the clipboard whenever this element is clicked.
+	///
+	/// Once the text has been copied, `copied` is set to `true` for `copied_duration`,
+	/// after which it's set back to `false`. Clicking again before then
+	/// restarts the duration.
+	///
+	/// `text` is only read when clicked, so it doesn't need to be a signal.
+	/// If it has no text, nothing is copied.
+	#[track_caller]
+	fn copy_on_click<T>(&self, ctx: &DynatosWebCtx, text: T, copied: Signal<bool>, copied_duration: Duration)
+	where
+		T: SyncBounds + WithDynText + 'static,
+	{
+		// Note: Each copy increments this, so that older copies
+		//       don't reset `copied` while a newer one is active.
+		let generation = RcPtr::new(IMut::new(0_usize));
+		let listener_ctx = ctx.clone();
+		self.add_event_listener_el::<ev!(click)>(ctx, move |_element, _ev| {
+			let Some(copy) = text.with_text(|text| text.map(|text| clipboard::copy_to_clipboard(&listener_ctx, text)))
+			else {
+				return;
+			};
+
+			let ctx = listener_ctx.clone();
+			let copied = copied.clone();
+			let generation = RcPtr::clone(&generation);
+			dynatos_reactive::spawn_task(async move {
+				if let Err(err) = copy.await {
+					tracing::warn!(?err, "Unable to copy text to clipboard");
+					return;
+				}
+
+				let cur_generation = {
+					let mut generation = generation.lock();
+					*generation += 1;
+					*generation
+				};
+				copied.set(true);
+
+				timer::sleep(&ctx, copied_duration).await;
+				if *generation.lock() == cur_generation {
+					copied.set(false);
+				}
+			});
+		});
+	}
+}
+
+/// Extension trait to copy some text whenever an element is clicked
+#[extend::ext(name = ElementWithCopyOnClick)]
+pub impl<E> E
+where
+	E: AsRef<Element>,
+{
+	/// Copies `text` to the clipboard whenever this element is clicked.
+	///
+	/// See [`copy_on_click`](ElementCopyOnClick::copy_on_click) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_copy_on_click<T>(
+		self,
+		ctx: &DynatosWebCtx,
+		text: T,
+		copied: Signal<bool>,
+		copied_duration: Duration,
+	) -> Self
+	where
+		T: SyncBounds + WithDynText + 'static,
+	{
+		self.as_ref().copy_on_click(ctx, text, copied, copied_duration);
+		self
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {super::*, crate::test_util, dynatos_reactive::SignalGet, dynatos_util::Counter, dynatos_web::html};
+
+	#[test]
+	fn copy_on_click() {
+		static READS: Counter = Counter::new();
+
+		test_util::run(async {
+			let ctx = test_util::ctx();
+			let copied = Signal::new(false);
+			let button = html::button(&ctx).with_copy_on_click(
+				&ctx,
+				|| {
+					READS.bump();
+					"text"
+				},
+				copied.clone(),
+				Duration::from_secs(1),
+			);
+			assert_eq!(READS.get(), 0, "Text was read before clicking");
+
+			test_util::click(&ctx, &button);
+			assert_eq!(READS.get(), 1, "Text wasn't read when clicking");
+
+			// Note: There's no clipboard during server-side rendering, so the copy fails.
+			tokio::task::yield_now().await;
+			assert!(!copied.get(), "Failed copy set `copied`");
+		});
+	}
+}
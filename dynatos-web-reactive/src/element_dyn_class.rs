@@ -0,0 +1,93 @@
+//! Element reactive class
+
+// Imports
+use {
+	crate::{ObjectAttachEffect, WithDynPred},
+	dynatos_reactive::Effect,
+	dynatos_sync_types::SyncBounds,
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::ElementToggleClass,
+	js_sys::WeakRef,
+};
+
+/// Extension trait to add a reactive class to an element
+#[extend::ext(name = ElementDynClass)]
+pub impl web_sys::Element {
+	/// Adds a dynamic class to this element.
+	///
+	/// Whenever `pred` is true, `class` is added to this element, and otherwise it's removed.
+	#[track_caller]
+	fn set_dyn_class<C, P>(&self, class: C, pred: P)
+	where
+		C: SyncBounds + AsRef<str> + 'static,
+		P: SyncBounds + WithDynPred + 'static,
+	{
+		// Create the value to attach
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let element = WeakRef::new(self);
+		let class_effect = Effect::try_new(move || {
+			// Try to get the element
+			let element = element.deref().or_return()?;
+
+			// And add or remove the class
+			element.toggle_class(&class, pred.eval());
+		})
+		.or_return()?;
+
+		// Then set it
+		self.attach_effect(class_effect);
+	}
+}
+
+/// Extension trait to add a reactive class to an element
+#[extend::ext(name = ElementWithDynClass)]
+pub impl<E> E
+where
+	E: AsRef<web_sys::Element>,
+{
+	/// Adds a dynamic class to this element.
+	///
+	/// See [`set_dyn_class`](ElementDynClass::set_dyn_class) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_dyn_class<C, P>(self, class: C, pred: P) -> Self
+	where
+		C: SyncBounds + AsRef<str> + 'static,
+		P: SyncBounds + WithDynPred + 'static,
+	{
+		self.as_ref().set_dyn_class(class, pred);
+		self
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::test_util,
+		dynatos_reactive::{Signal, SignalGet, SignalSet},
+		dynatos_web::{ElementWithClass, html},
+	};
+
+	#[test]
+	fn set_dyn_class() {
+		let ctx = test_util::ctx();
+		let active = Signal::new(true);
+		let div = html::div(&ctx).with_class("item").with_dyn_class("active", {
+			let active = active.clone();
+			move || active.get()
+		});
+		assert_eq!(div.class_name(), "item active");
+
+		active.set(false);
+		assert_eq!(div.class_name(), "item");
+
+		active.set(true);
+		active.set(true);
+		assert_eq!(div.class_name(), "item active");
+	}
+}
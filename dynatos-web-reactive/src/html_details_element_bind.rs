@@ -0,0 +1,122 @@
+//! Html details element binding
+
+// Imports
+use {
+	crate::ObjectAttachEffect,
+	core::ops::Deref,
+	dynatos_reactive::{Effect, SignalBorrow, SignalSet},
+	dynatos_sync_types::SyncBounds,
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, ElementAddListener, ev},
+	js_sys::WeakRef,
+	web_sys::HtmlDetailsElement,
+};
+
+/// Extension trait to bind a details element to a signal
+#[extend::ext(name = HtmlDetailsElementBind)]
+pub impl HtmlDetailsElement {
+	/// Binds whether this element is open to `signal`.
+	///
+	/// Whenever `signal` changes, the element is opened or closed, and
+	/// whenever the user opens or closes the element, `signal` is set.
+	///
+	/// The element is observed through the `toggle` event, which the browser
+	/// fires asynchronously after the element is opened or closed, so `signal`
+	/// will only be updated after the current task finishes.
+	/// The event is also fired when `signal` changes the element, but since
+	/// the signal will already have the same value by then, it isn't set again.
+	#[track_caller]
+	fn bind_open<S>(&self, ctx: &DynatosWebCtx, signal: S)
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = bool>> + SignalSet<bool> + 'static,
+	{
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let details = WeakRef::new(self);
+		let details_signal = signal.clone();
+		let details_effect = Effect::try_new(move || {
+			// Try to get the element
+			let details = details.deref().or_return()?;
+
+			details.set_open(*details_signal.borrow());
+		})
+		.or_return()?;
+		self.attach_effect(details_effect);
+
+		self.add_event_listener_el::<ev!(toggle)>(ctx, move |details, _ev| {
+			let is_open = details.open();
+			if *signal.borrow() != is_open {
+				signal.set(is_open);
+			}
+		});
+	}
+}
+
+/// Extension trait to bind a details element to a signal
+#[extend::ext(name = HtmlDetailsElementWithBind)]
+pub impl<E> E
+where
+	E: AsRef<HtmlDetailsElement>,
+{
+	/// Binds whether this element is open to `signal`.
+	///
+	/// See [`bind_open`](HtmlDetailsElementBind::bind_open) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_bind_open<S>(self, ctx: &DynatosWebCtx, signal: S) -> Self
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = bool>> + SignalSet<bool> + 'static,
+	{
+		self.as_ref().bind_open(ctx, signal);
+		self
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::test_util,
+		dynatos_reactive::{Signal, SignalGet},
+		dynatos_util::Counter,
+		dynatos_web::html,
+		web_sys::ToggleEvent,
+	};
+
+	#[test]
+	fn bind_open() {
+		static UPDATES: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let open = Signal::new(false);
+		let details = html::details(&ctx).with_bind_open(&ctx, open.clone());
+		let _effect = Effect::new({
+			let open = open.clone();
+			move || {
+				_ = open.get();
+				UPDATES.bump();
+			}
+		});
+		assert!(!details.open());
+
+		open.set(true);
+		assert!(details.open());
+		assert_eq!(UPDATES.get(), 2);
+
+		// Note: The browser also fires `toggle` for changes made by the signal,
+		//       but since the signal already has the value, it isn't set again.
+		test_util::dispatch(&details, "toggle", ToggleEvent::default());
+		assert_eq!(UPDATES.get(), 2);
+
+		details.set_open(false);
+		test_util::dispatch(&details, "toggle", ToggleEvent::default());
+		assert!(!open.get());
+
+		details.set_open(true);
+		test_util::dispatch(&details, "toggle", ToggleEvent::default());
+		assert!(open.get());
+	}
+}
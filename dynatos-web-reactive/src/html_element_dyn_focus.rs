@@ -0,0 +1,199 @@
+//! Html element reactive focus
+
+// Imports
+use {
+	crate::{ObjectAttachEffect, WithDynPred},
+	core::mem,
+	dynatos_reactive::Effect,
+	dynatos_sync_types::{IMut, SyncBounds},
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, cfg_ssr_expr},
+	js_sys::WeakRef,
+	web_sys::HtmlElement,
+};
+
+/// Extension trait to reactively focus an html element
+#[extend::ext(name = HtmlElementDynFocus)]
+pub impl HtmlElement {
+	/// Focuses this element whenever `pred` becomes true.
+	#[track_caller]
+	fn focus_when<P>(&self, ctx: &DynatosWebCtx, pred: P)
+	where
+		P: SyncBounds + WithDynPred + 'static,
+	{
+		self::focus_when_inner(self, ctx, pred, false);
+	}
+
+	/// Focuses this element whenever `pred` becomes true, and
+	/// restores focus to the previously focused element once
+	/// it becomes false.
+	#[track_caller]
+	fn focus_when_restore<P>(&self, ctx: &DynatosWebCtx, pred: P)
+	where
+		P: SyncBounds + WithDynPred + 'static,
+	{
+		self::focus_when_inner(self, ctx, pred, true);
+	}
+}
+
+/// Extension trait to reactively focus an html element
+#[extend::ext(name = HtmlElementWithDynFocus)]
+pub impl<E> E
+where
+	E: AsRef<HtmlElement>,
+{
+	/// Focuses this element whenever `pred` becomes true.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_focus_when<P>(self, ctx: &DynatosWebCtx, pred: P) -> Self
+	where
+		P: SyncBounds + WithDynPred + 'static,
+	{
+		self.as_ref().focus_when(ctx, pred);
+		self
+	}
+
+	/// Focuses this element whenever `pred` becomes true, and
+	/// restores focus to the previously focused element once
+	/// it becomes false.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_focus_when_restore<P>(self, ctx: &DynatosWebCtx, pred: P) -> Self
+	where
+		P: SyncBounds + WithDynPred + 'static,
+	{
+		self.as_ref().focus_when_restore(ctx, pred);
+		self
+	}
+}
+
+/// Inner function for [`HtmlElementDynFocus::focus_when`] and [`HtmlElementDynFocus::focus_when_restore`]
+#[track_caller]
+fn focus_when_inner<P>(element: &HtmlElement, ctx: &DynatosWebCtx, pred: P, restore_focus: bool)
+where
+	P: SyncBounds + WithDynPred + 'static,
+{
+	// Note: It's important that we only keep a `WeakRef` to both elements.
+	//       Otherwise, the element will be keeping us alive, while we keep
+	//       the element alive, causing a leak.
+	let element_weak = WeakRef::new(element);
+	let ctx = ctx.clone();
+
+	/// Focus state
+	enum State {
+		/// Not focused by us
+		Unfocused,
+
+		/// Focused by us, with the previously focused element, if any
+		Focused(Option<WeakRef<HtmlElement>>),
+	}
+
+	let state = IMut::new(State::Unfocused);
+	let focus_effect = Effect::try_new(move || {
+		// Try to get the element
+		let element = element_weak.deref().or_return()?;
+
+		let should_focus = pred.eval();
+		let mut state = state.lock();
+		match mem::replace(&mut *state, State::Unfocused) {
+			// If we should be focused and aren't yet, save the current focus and focus the element
+			State::Unfocused if should_focus => {
+				let prev_focus = self::active_html_element(&ctx).map(|prev_focus| WeakRef::new(&prev_focus));
+				element
+					.focus()
+					.unwrap_or_else(|err| panic!("Unable to focus element {element:?}: {err:?}"));
+				*state = State::Focused(prev_focus);
+			},
+
+			// If we shouldn't be focused anymore, restore the previous focus, if requested
+			State::Focused(prev_focus) if !should_focus =>
+				if restore_focus && let Some(prev_focus) = prev_focus.and_then(|prev_focus| prev_focus.deref()) {
+					prev_focus
+						.focus()
+						.unwrap_or_else(|err| panic!("Unable to restore focus to element {prev_focus:?}: {err:?}"));
+				},
+
+			// Otherwise, keep the same state
+			prev_state => *state = prev_state,
+		}
+	})
+	.or_return()?;
+
+	// Then attach it
+	element.attach_effect(focus_effect);
+}
+
+/// Returns the currently focused element, if it's an html element
+fn active_html_element(ctx: &DynatosWebCtx) -> Option<HtmlElement> {
+	let element = ctx.document().active_element()?;
+	cfg_ssr_expr!(
+		ssr = {
+			use zutil_inheritance::Downcast;
+			element.downcast().ok()
+		},
+		csr = {
+			use wasm_bindgen::JsCast;
+			element.dyn_into().ok()
+		},
+	)
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::test_util,
+		dynatos_reactive::{Signal, SignalGet, SignalSet},
+		dynatos_util::Counter,
+		dynatos_web::html,
+	};
+
+	#[test]
+	fn focus_when() {
+		static EVALS: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let focus = Signal::new(false);
+		let input = html::input(&ctx).with_focus_when(&ctx, {
+			let focus = focus.clone();
+			move || {
+				EVALS.bump();
+				focus.get()
+			}
+		});
+		assert_eq!(EVALS.get(), 1);
+
+		focus.set(true);
+		assert_eq!(EVALS.get(), 2);
+		focus.set(false);
+		assert_eq!(EVALS.get(), 3);
+
+		// Note: The effect is owned by the element, so it stops once it's dropped
+		drop(input);
+		focus.set(true);
+		assert_eq!(EVALS.get(), 3);
+	}
+
+	#[test]
+	fn focus_when_restore() {
+		static EVALS: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let focus = Signal::new(true);
+		let _input = html::input(&ctx).with_focus_when_restore(&ctx, {
+			let focus = focus.clone();
+			move || {
+				EVALS.bump();
+				focus.get()
+			}
+		});
+		assert_eq!(EVALS.get(), 1);
+
+		focus.set(false);
+		focus.set(true);
+		assert_eq!(EVALS.get(), 3);
+	}
+}
@@ -0,0 +1,176 @@
+//! Html input element binding
+
+// Imports
+use {
+	crate::ObjectAttachEffect,
+	core::ops::Deref,
+	dynatos_reactive::{Effect, SignalBorrow, SignalSet},
+	dynatos_sync_types::SyncBounds,
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, ElementAddListener, ev},
+	js_sys::WeakRef,
+	web_sys::HtmlInputElement,
+};
+
+/// Extension trait to bind an input element to a signal
+#[extend::ext(name = HtmlInputElementBind)]
+pub impl HtmlInputElement {
+	/// Binds the value of this element to `signal`.
+	///
+	/// Whenever `signal` changes, the element's value is set, and whenever
+	/// the user changes the value (on each `input` event), `signal` is set.
+	///
+	/// The value is only written to the element if it's different, so setting `signal`
+	/// from the element doesn't write it back (which would reset the cursor).
+	#[track_caller]
+	fn bind_value<S>(&self, ctx: &DynatosWebCtx, signal: S)
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = String>> + SignalSet<String> + 'static,
+	{
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let input = WeakRef::new(self);
+		let input_signal = signal.clone();
+		let input_effect = Effect::try_new(move || {
+			// Try to get the element
+			let input = input.deref().or_return()?;
+
+			let value = input_signal.borrow();
+			if input.value() != *value {
+				input.set_value(&value);
+			}
+		})
+		.or_return()?;
+		self.attach_effect(input_effect);
+
+		self.add_event_listener_el::<ev!(input)>(ctx, move |input, _ev| signal.set(input.value()));
+	}
+
+	/// Binds whether this element is checked to `signal`.
+	///
+	/// Whenever `signal` changes, the element is checked or unchecked, and
+	/// whenever the user checks or unchecks the element (on each `change` event),
+	/// `signal` is set.
+	#[track_caller]
+	fn bind_checked<S>(&self, ctx: &DynatosWebCtx, signal: S)
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = bool>> + SignalSet<bool> + 'static,
+	{
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let input = WeakRef::new(self);
+		let input_signal = signal.clone();
+		let input_effect = Effect::try_new(move || {
+			// Try to get the element
+			let input = input.deref().or_return()?;
+
+			let checked = *input_signal.borrow();
+			if input.checked() != checked {
+				input.set_checked(checked);
+			}
+		})
+		.or_return()?;
+		self.attach_effect(input_effect);
+
+		self.add_event_listener_el::<ev!(change)>(ctx, move |input, _ev| signal.set(input.checked()));
+	}
+}
+
+/// Extension trait to bind an input element to a signal
+#[extend::ext(name = HtmlInputElementWithBind)]
+pub impl<E> E
+where
+	E: AsRef<HtmlInputElement>,
+{
+	/// Binds the value of this element to `signal`.
+	///
+	/// See [`bind_value`](HtmlInputElementBind::bind_value) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_bind_value<S>(self, ctx: &DynatosWebCtx, signal: S) -> Self
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = String>> + SignalSet<String> + 'static,
+	{
+		self.as_ref().bind_value(ctx, signal);
+		self
+	}
+
+	/// Binds whether this element is checked to `signal`.
+	///
+	/// See [`bind_checked`](HtmlInputElementBind::bind_checked) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_bind_checked<S>(self, ctx: &DynatosWebCtx, signal: S) -> Self
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = bool>> + SignalSet<bool> + 'static,
+	{
+		self.as_ref().bind_checked(ctx, signal);
+		self
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::test_util,
+		dynatos_reactive::{Signal, SignalGet, run_queue},
+		dynatos_web::html,
+		web_sys::{Event, InputEvent},
+	};
+
+	#[test]
+	fn bind_value() {
+		let ctx = test_util::ctx();
+		let value = Signal::new("a".to_owned());
+		let input = html::input(&ctx).with_bind_value(&ctx, value.clone());
+		assert_eq!(input.value(), "a");
+
+		value.set("b".to_owned());
+		assert_eq!(input.value(), "b");
+
+		input.set_value("c");
+		test_util::dispatch(&input, "input", InputEvent::default());
+		assert_eq!(*value.borrow(), "c");
+		assert_eq!(input.value(), "c");
+	}
+
+	#[test]
+	fn bind_value_deferred() {
+		let ctx = test_util::ctx();
+		let value = Signal::new("a".to_owned());
+		let input = html::input(&ctx).with_bind_value(&ctx, value.clone());
+
+		{
+			let _defer = run_queue::defer_effects();
+			input.set_value("b");
+			test_util::dispatch(&input, "input", InputEvent::default());
+			assert_eq!(*value.borrow(), "b");
+		}
+		assert_eq!(input.value(), "b");
+
+		value.set("c".to_owned());
+		assert_eq!(input.value(), "c");
+	}
+
+	#[test]
+	fn bind_checked() {
+		let ctx = test_util::ctx();
+		let checked = Signal::new(false);
+		let input = html::input(&ctx).with_bind_checked(&ctx, checked.clone());
+		assert!(!input.checked());
+
+		checked.set(true);
+		assert!(input.checked());
+
+		input.set_checked(false);
+		test_util::dispatch(&input, "change", Event::default());
+		assert!(!checked.get());
+		assert!(!input.checked());
+	}
+}
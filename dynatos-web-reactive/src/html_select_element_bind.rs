@@ -0,0 +1,204 @@
+//! Html select element binding
+
+// Imports
+use {
+	crate::ObjectAttachEffect,
+	core::{fmt::Display, ops::Deref, str::FromStr},
+	dynatos_reactive::{Effect, SignalBorrow, SignalSet},
+	dynatos_sync_types::SyncBounds,
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, ElementAddListener, cfg_ssr_expr, ev},
+	js_sys::WeakRef,
+	web_sys::{HtmlOptionElement, HtmlSelectElement},
+};
+
+/// Extension trait to bind a select element to a signal
+#[extend::ext(name = HtmlSelectElementBind)]
+pub impl HtmlSelectElement {
+	/// Binds the selected option of this element to `signal`.
+	///
+	/// Whenever `signal` changes, the option with the same value (as given by [`Display`])
+	/// is selected, and whenever the user selects an option, its value is parsed
+	/// (using [`FromStr`]) and written to `signal`.
+	///
+	/// If the selected value cannot be parsed, the previous selection is restored.
+	#[track_caller]
+	fn bind_select<S, T>(&self, ctx: &DynatosWebCtx, signal: S)
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = T>> + SignalSet<T> + 'static,
+		T: FromStr + Display + 'static,
+	{
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let select = WeakRef::new(self);
+		let select_signal = signal.clone();
+		let select_effect = Effect::try_new(move || {
+			// Try to get the element
+			let select = select.deref().or_return()?;
+
+			let value = select_signal.borrow().to_string();
+			select.set_value(&value);
+		})
+		.or_return()?;
+		self.attach_effect(select_effect);
+
+		self.add_event_listener_el::<ev!(change)>(ctx, move |select, _ev| match select.value().parse() {
+			Ok(value) => signal.set(value),
+			Err(_) => select.set_value(&signal.borrow().to_string()),
+		});
+	}
+
+	/// Binds the selected options of this element to `signal`.
+	///
+	/// This is the same as [`bind_select`](HtmlSelectElementBind::bind_select), but for
+	/// `<select multiple>` elements. Any selected values that cannot be parsed are ignored.
+	#[track_caller]
+	fn bind_select_multiple<S, T>(&self, ctx: &DynatosWebCtx, signal: S)
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = Vec<T>>> + SignalSet<Vec<T>> + 'static,
+		T: FromStr + Display + 'static,
+	{
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let select = WeakRef::new(self);
+		let select_signal = signal.clone();
+		let select_effect = Effect::try_new(move || {
+			// Try to get the element
+			let select = select.deref().or_return()?;
+
+			let values = select_signal.borrow().iter().map(T::to_string).collect::<Vec<_>>();
+			for option in self::options(&select) {
+				option.set_selected(values.contains(&option.value()));
+			}
+		})
+		.or_return()?;
+		self.attach_effect(select_effect);
+
+		self.add_event_listener_el::<ev!(change)>(ctx, move |select, _ev| {
+			let values = self::options(&select)
+				.filter(HtmlOptionElement::selected)
+				.filter_map(|option| option.value().parse().ok())
+				.collect();
+			signal.set(values);
+		});
+	}
+}
+
+/// Extension trait to bind a select element to a signal
+#[extend::ext(name = HtmlSelectElementWithBind)]
+pub impl<E> E
+where
+	E: AsRef<HtmlSelectElement>,
+{
+	/// Binds the selected option of this element to `signal`.
+	///
+	/// See [`bind_select`](HtmlSelectElementBind::bind_select) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_bind_select<S, T>(self, ctx: &DynatosWebCtx, signal: S) -> Self
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = T>> + SignalSet<T> + 'static,
+		T: FromStr + Display + 'static,
+	{
+		self.as_ref().bind_select(ctx, signal);
+		self
+	}
+
+	/// Binds the selected options of this element to `signal`.
+	///
+	/// See [`bind_select_multiple`](HtmlSelectElementBind::bind_select_multiple) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_bind_select_multiple<S, T>(self, ctx: &DynatosWebCtx, signal: S) -> Self
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = Vec<T>>> + SignalSet<Vec<T>> + 'static,
+		T: FromStr + Display + 'static,
+	{
+		self.as_ref().bind_select_multiple(ctx, signal);
+		self
+	}
+}
+
+/// Returns all options of `select`
+fn options(select: &HtmlSelectElement) -> impl Iterator<Item = HtmlOptionElement> {
+	(0..select.length()).filter_map(|idx| {
+		let option = select.item(idx)?;
+		cfg_ssr_expr!(
+			ssr = {
+				use zutil_inheritance::Downcast;
+				option.downcast().ok()
+			},
+			csr = {
+				use wasm_bindgen::JsCast;
+				option.dyn_into().ok()
+			},
+		)
+	})
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::test_util,
+		dynatos_reactive::{Signal, SignalGet},
+		dynatos_web::{ElementWithAttr, NodeWithChildren, html},
+		web_sys::Event,
+	};
+
+	/// Creates a select with the options `1`, `2`, `3` and `x`
+	fn select(ctx: &DynatosWebCtx) -> HtmlSelectElement {
+		html::select(ctx)
+			.with_children_iter(["1", "2", "3", "x"].map(|value| html::option(ctx).with_attr("value", value)))
+	}
+
+	#[test]
+	fn bind_select() {
+		let ctx = test_util::ctx();
+		let value = Signal::new(2_u32);
+		let select = self::select(&ctx).with_bind_select(&ctx, value.clone());
+		assert_eq!(select.value(), "2");
+
+		value.set(3);
+		assert_eq!(select.value(), "3");
+
+		select.set_value("1");
+		test_util::dispatch(&select, "change", Event::default());
+		assert_eq!(value.get(), 1);
+
+		// Note: Values that can't be parsed restore the previous selection
+		select.set_value("x");
+		test_util::dispatch(&select, "change", Event::default());
+		assert_eq!(value.get(), 1);
+		assert_eq!(select.value(), "1");
+	}
+
+	#[test]
+	fn bind_select_multiple() {
+		let ctx = test_util::ctx();
+		let values = Signal::new(vec![1_u32, 3]);
+		let select = self::select(&ctx).with_bind_select_multiple(&ctx, values.clone());
+		let selected = || {
+			super::options(&select)
+				.filter(HtmlOptionElement::selected)
+				.map(|option| option.value())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(selected(), ["1", "3"]);
+
+		values.set(vec![2]);
+		assert_eq!(selected(), ["2"]);
+
+		// Note: Values that can't be parsed are ignored
+		for option in super::options(&select) {
+			option.set_selected(option.value() != "2");
+		}
+		test_util::dispatch(&select, "change", Event::default());
+		assert_eq!(*values.borrow(), [1, 3]);
+	}
+}
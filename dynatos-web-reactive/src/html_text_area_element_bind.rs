@@ -0,0 +1,91 @@
+//! Html text area element binding
+
+// Imports
+use {
+	crate::ObjectAttachEffect,
+	core::ops::Deref,
+	dynatos_reactive::{Effect, SignalBorrow, SignalSet},
+	dynatos_sync_types::SyncBounds,
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, ElementAddListener, ev},
+	js_sys::WeakRef,
+	web_sys::HtmlTextAreaElement,
+};
+
+/// Extension trait to bind a text area element to a signal
+#[extend::ext(name = HtmlTextAreaElementBind)]
+pub impl HtmlTextAreaElement {
+	/// Binds the value of this element to `signal`.
+	///
+	/// See [`HtmlInputElementBind::bind_value`](crate::HtmlInputElementBind::bind_value)
+	/// for details, since this behaves the same.
+	#[track_caller]
+	fn bind_value<S>(&self, ctx: &DynatosWebCtx, signal: S)
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = String>> + SignalSet<String> + 'static,
+	{
+		// Note: It's important that we only keep a `WeakRef` to the element.
+		//       Otherwise, the element will be keeping us alive, while we keep
+		//       the element alive, causing a leak.
+		let text_area = WeakRef::new(self);
+		let text_area_signal = signal.clone();
+		let text_area_effect = Effect::try_new(move || {
+			// Try to get the element
+			let text_area = text_area.deref().or_return()?;
+
+			// Note: We only write the value if it's different, so the
+			//       values we get from the element aren't written back.
+			let value = text_area_signal.borrow();
+			if text_area.value() != *value {
+				text_area.set_value(&value);
+			}
+		})
+		.or_return()?;
+		self.attach_effect(text_area_effect);
+
+		self.add_event_listener_el::<ev!(input)>(ctx, move |text_area, _ev| signal.set(text_area.value()));
+	}
+}
+
+/// Extension trait to bind a text area element to a signal
+#[extend::ext(name = HtmlTextAreaElementWithBind)]
+pub impl<E> E
+where
+	E: AsRef<HtmlTextAreaElement>,
+{
+	/// Binds the value of this element to `signal`.
+	///
+	/// See [`bind_value`](HtmlTextAreaElementBind::bind_value) for details.
+	///
+	/// Returns the element, for chaining
+	#[track_caller]
+	fn with_bind_value<S>(self, ctx: &DynatosWebCtx, signal: S) -> Self
+	where
+		S: SyncBounds + Clone + for<'a> SignalBorrow<Ref<'a>: Deref<Target = String>> + SignalSet<String> + 'static,
+	{
+		self.as_ref().bind_value(ctx, signal);
+		self
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {super::*, crate::test_util, dynatos_reactive::Signal, dynatos_web::html, web_sys::InputEvent};
+
+	#[test]
+	fn bind_value() {
+		let ctx = test_util::ctx();
+		let value = Signal::new("a".to_owned());
+		let text_area = html::textarea(&ctx).with_bind_value(&ctx, value.clone());
+		assert_eq!(text_area.value(), "a");
+
+		value.set("b".to_owned());
+		assert_eq!(text_area.value(), "b");
+
+		text_area.set_value("c");
+		test_util::dispatch(&text_area, "input", InputEvent::default());
+		assert_eq!(*value.borrow(), "c");
+		assert_eq!(text_area.value(), "c");
+	}
+}
@@ -6,13 +6,26 @@
 
 // Modules
 mod dyn_element;
+mod element_animate_on_change;
+mod element_copy_on_click;
 mod element_dyn_attr;
+mod element_dyn_class;
+mod html_details_element_bind;
 mod html_element_dyn_css_prop;
+mod html_element_dyn_focus;
+mod html_input_element_bind;
+mod html_select_element_bind;
+mod html_text_area_element_bind;
 mod node_dyn_child;
 mod node_dyn_children;
+mod node_dyn_for;
+mod node_dyn_loadable_for;
+mod node_dyn_show;
 mod node_dyn_text;
 mod object_attach_effect;
 mod object_dyn_prop;
+#[cfg(all(test, feature = "ssr"))]
+mod test_util;
 mod to_dyn_prop;
 mod with_dyn_pred;
 mod with_dyn_text;
@@ -21,16 +34,32 @@ mod with_dyn_text;
 pub use {
 	self::{
 		dyn_element::DynElement,
+		element_animate_on_change::{ElementAnimateOnChange, ElementWithAnimateOnChange},
+		element_copy_on_click::{ElementCopyOnClick, ElementWithCopyOnClick},
 		element_dyn_attr::{ElementDynAttr, ElementWithDynAttr},
+		element_dyn_class::{ElementDynClass, ElementWithDynClass},
+		html_details_element_bind::{HtmlDetailsElementBind, HtmlDetailsElementWithBind},
 		html_element_dyn_css_prop::{HtmlElementDynCssProp, HtmlElementWithDynCssProp},
-		node_dyn_child::{NodeDynChild, NodeWithDynChild, WithDynNode},
+		html_element_dyn_focus::{HtmlElementDynFocus, HtmlElementWithDynFocus},
+		html_input_element_bind::{HtmlInputElementBind, HtmlInputElementWithBind},
+		html_select_element_bind::{HtmlSelectElementBind, HtmlSelectElementWithBind},
+		html_text_area_element_bind::{HtmlTextAreaElementBind, HtmlTextAreaElementWithBind},
+		node_dyn_child::{ErrView, NodeDynChild, NodeWithDynChild, WithDynNode},
 		node_dyn_children::{NodeDynChildren, NodeWithDynChildren, WithDynNodes},
+		node_dyn_for::{NodeDynFor, NodeWithDynFor},
+		node_dyn_loadable_for::{NodeDynLoadableFor, NodeWithDynLoadableFor},
+		node_dyn_show::{NodeDynShow, NodeWithDynShow},
 		node_dyn_text::{NodeDynText, NodeWithDynText},
-		object_attach_effect::{ObjectAttachEffect, ObjectWithEffect},
+		object_attach_effect::{ObjectAttachEffect, ObjectDetachEffect, ObjectWithEffect},
 		object_dyn_prop::{ObjectDynProp, ObjectWithDynProp},
 		to_dyn_prop::ToDynProp,
 		with_dyn_pred::WithDynPred,
-		with_dyn_text::WithDynText,
+		with_dyn_text::{DisplayText, WithDynText},
 	},
 	dynatos_web_reactive_macros::*,
 };
+
+// Imports
+// Note: `tokio` is only used by the ssr tests
+#[cfg(all(test, not(feature = "ssr")))]
+use tokio as _;
@@ -3,10 +3,10 @@
 // Imports
 use {
 	crate::{NodeDynChildren, WithDynNodes},
-	core::ops::Deref,
+	core::{fmt, ops::Deref},
 	dynatos_reactive::{Derived, Memo, Signal, SignalWith, WithDefault, derived::DerivedRun},
 	dynatos_sync_types::SyncBounds,
-	dynatos_web::DynatosWebCtx,
+	dynatos_web::{DynatosWebCtx, Fragment, cfg_ssr_expr},
 };
 
 /// Extension trait to add a reactive child to an node
@@ -55,9 +55,12 @@ where
 /// - `impl Fn() -> N`
 /// - `{Node, Element, HtmlElement}`
 /// - `Option<N>`
+/// - `Result<N, E>` (where `Err` is displayed as text), for any `E: Display`
+/// - [`ErrView`]
 /// - `Vec<N>`, `[N; _]`, `[N]`
 /// - [`Signal`], [`Derived`], [`Memo`], [`WithDefault`]
 /// - `LazyCell<N, impl Fn() -> N>`
+/// - [`Fragment`]
 /// - `!`
 ///
 /// Where `N` is any of the types above.
@@ -94,6 +97,12 @@ impl WithDynNode for Ty {
 	}
 }
 
+impl WithDynNode for Fragment {
+	fn with_node(&self, f: impl FnMut(web_sys::Node)) {
+		self.nodes().iter().cloned().for_each(f);
+	}
+}
+
 impl<N> WithDynNode for Option<N>
 where
 	N: WithDynNode,
@@ -105,6 +114,55 @@ where
 	}
 }
 
+impl<N, E> WithDynNode for Result<N, E>
+where
+	N: WithDynNode,
+	E: SyncBounds + fmt::Display,
+{
+	fn with_node(&self, mut f: impl FnMut(web_sys::Node)) {
+		match self {
+			Ok(node) => node.with_node(f),
+			Err(err) => f(self::text_node(&err.to_string())),
+		}
+	}
+}
+
+/// Dynamic node from a `Result`, with a custom error view.
+///
+/// On `Ok`, the node is used, as with `Result<N, E>`. On `Err`, the
+/// node returned by calling the function with the error is used instead,
+/// e.g. `move || ErrView(self::view(), |err| self::error_view(err))`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ErrView<N, E, F>(pub Result<N, E>, pub F);
+
+impl<N, E, F, V> WithDynNode for ErrView<N, E, F>
+where
+	N: WithDynNode,
+	E: SyncBounds,
+	F: SyncBounds + Fn(&E) -> V,
+	V: WithDynNode,
+{
+	fn with_node(&self, f: impl FnMut(web_sys::Node)) {
+		match &self.0 {
+			Ok(node) => node.with_node(f),
+			Err(err) => (self.1)(err).with_node(f),
+		}
+	}
+}
+
+/// Creates a text node with `data`
+fn text_node(data: &str) -> web_sys::Node {
+	cfg_ssr_expr!(
+		ssr = {
+			let text = web_sys::Text::new(Some(data.to_owned()));
+			AsRef::<web_sys::Node>::as_ref(&text).clone()
+		},
+		csr = web_sys::Text::new_with_data(data)
+			.expect("Unable to create text node")
+			.into()
+	)
+}
+
 // TODO: Allow impl for `impl SignalWith<Value: WithDynNodes>`
 #[duplicate::duplicate_item(
 	Generics Ty;
@@ -151,3 +209,123 @@ where
 impl WithDynNode for ! {
 	fn with_node(&self, _f: impl FnMut(web_sys::Node)) {}
 }
+
+// Note: We need `wasm-bindgen` to create dummy nodes
+#[cfg(all(test, feature = "csr"))]
+mod tests {
+	// Imports
+	use {super::*, dynatos_util::Counter, wasm_bindgen::JsCast};
+
+	/// Node that counts how many times it was used
+	struct CountNode(&'static Counter);
+
+	impl WithDynNode for CountNode {
+		fn with_node(&self, mut f: impl FnMut(web_sys::Node)) {
+			self.0.bump();
+
+			// Note: We can't create actual nodes outside of wasm
+			f(wasm_bindgen::JsValue::NULL.unchecked_into());
+		}
+	}
+
+	/// Returns the number of nodes of `node`
+	fn nodes_len<N: WithDynNode>(node: &N) -> usize {
+		let mut len = 0;
+		node.with_node(|_| len += 1);
+		len
+	}
+
+	#[test]
+	fn result_ok() {
+		/// Counts the number of times the node was used
+		static USED: Counter = Counter::new();
+
+		let node = Ok::<_, &str>(CountNode(&USED));
+		assert_eq!(nodes_len(&node), 1);
+		assert_eq!(USED.get(), 1);
+	}
+
+	#[test]
+	fn err_view() {
+		/// Counts the number of times the node was used
+		static USED: Counter = Counter::new();
+
+		/// Counts the number of times the error view was used
+		static ERR_USED: Counter = Counter::new();
+
+		let node = ErrView(Ok(CountNode(&USED)), |_: &&str| CountNode(&ERR_USED));
+		assert_eq!(nodes_len(&node), 1);
+		assert_eq!((USED.get(), ERR_USED.get()), (1, 0));
+
+		let node = ErrView(Err("error"), |err: &&str| {
+			assert_eq!(*err, "error");
+			CountNode(&ERR_USED)
+		});
+		assert_eq!(nodes_len::<ErrView<CountNode, _, _>>(&node), 1);
+		assert_eq!((USED.get(), ERR_USED.get()), (1, 1));
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod ssr_tests {
+	// Imports
+	use {
+		super::*,
+		crate::{NodeWithDynChild, test_util},
+		core::iter,
+		dynatos_reactive::{SignalGetCloned, SignalSet},
+		dynatos_web::{ElementWithAttr, NodeWithChildren, html},
+		zutil_inheritance::Downcast,
+	};
+
+	#[test]
+	fn fragment() {
+		let ctx = test_util::ctx();
+		let span = |name: &str| web_sys::Node::from(html::span(&ctx).with_attr("id", name));
+		let fragment = Signal::new(None::<Fragment>);
+		let div = html::div(&ctx)
+			.with_child(span("a"))
+			.with_dyn_child(&ctx, {
+				let fragment = fragment.clone();
+				move || fragment.get_cloned()
+			})
+			.with_child(span("z"));
+		let ids = || {
+			iter::successors(div.first_child(), web_sys::Node::next_sibling)
+				.filter_map(|child| child.downcast::<web_sys::Element>().ok())
+				.filter_map(|child| child.get_attribute("id").ok())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(ids(), ["a", "z"]);
+
+		fragment.set(Some(Fragment::new([span("b"), span("c")])));
+		assert_eq!(ids(), ["a", "b", "c", "z"]);
+
+		fragment.set(Some(Fragment::new([span("d"), span("e"), span("f")])));
+		assert_eq!(ids(), ["a", "d", "e", "f", "z"]);
+
+		fragment.set(None);
+		assert_eq!(ids(), ["a", "z"]);
+
+		fragment.set(Some(Fragment::new([span("g"), span("h")])));
+		assert_eq!(ids(), ["a", "g", "h", "z"]);
+	}
+
+	#[test]
+	fn result_err() {
+		let ctx = test_util::ctx();
+		let span = web_sys::Node::from(html::span(&ctx).with_attr("id", "a"));
+		let result = Signal::new(Err::<web_sys::Node, _>("error"));
+		let div = html::div(&ctx).with_dyn_child(&ctx, {
+			let result = result.clone();
+			move || result.get_cloned()
+		});
+		assert_eq!(div.outer_html(), "<div>error</div>");
+
+		result.set(Ok(span));
+		assert_eq!(div.outer_html(), r#"<div><span id="a"></span></div>"#);
+
+		result.set(Err("other error"));
+		assert_eq!(div.outer_html(), "<div>other error</div>");
+	}
+}
@@ -7,7 +7,7 @@ use {
 	dynatos_reactive::{Derived, Effect, Memo, Signal, SignalWith, WithDefault, derived::DerivedRun},
 	dynatos_sync_types::{IMut, SyncBounds},
 	dynatos_util::TryOrReturnExt,
-	dynatos_web::{DynatosWebCtx, cfg_ssr_expr, html},
+	dynatos_web::{DynatosWebCtx, Fragment, cfg_ssr_expr, html},
 	js_sys::WeakRef,
 };
 
@@ -25,7 +25,7 @@ pub impl web_sys::Node {
 		//       Otherwise, the node will be keeping us alive, while we keep
 		//       the node alive, causing a leak.
 		// Note: We have an empty `<template>` so that we can track the position
-		//       of the node, in case of `f` returning `None`.
+		//       of the node, whenever `f` returns no children.
 		// TODO: Find a better solution than using an empty `<template>` element?
 		let node = WeakRef::new(self);
 		let prev_children = IMut::new(vec![]);
@@ -41,13 +41,15 @@ pub impl web_sys::Node {
 
 			let mut prev_children = prev_children.lock();
 
-			// If we hadn't initialized yet (no previous children) and we have no new children,
-			// add our empty child to keep our position
-			if prev_children.is_empty() && new_children.is_empty() {
-				node.append_child(&empty_child).expect("Unable to add reactive child");
+			// If we have no new children, use our empty child to keep our position
+			if new_children.is_empty() {
 				new_children.push(empty_child.clone());
 			}
 
+			// Get the node after all our previous children, to insert any remaining new children before.
+			// Note: We never touch any nodes outside of our children, so this stays valid.
+			let end_node = prev_children.last().and_then(web_sys::Node::next_sibling);
+
 			// Take all previous nodes we have and start trying to match them against the new ones
 			let mut new_nodes = new_children.into_iter();
 			for cur_prev_node in mem::take(&mut *prev_children) {
@@ -83,11 +85,9 @@ pub impl web_sys::Node {
 			}
 
 			for new_node in new_nodes {
-				let last_prev_child = prev_children.last().and_then(web_sys::Node::next_sibling);
-
-				node.insert_before(&new_node, last_prev_child.as_ref())
+				node.insert_before(&new_node, end_node.as_ref())
 					.expect("Unable to add reactive child");
-				self::trace_add_node(&new_node, last_prev_child.as_ref());
+				self::trace_add_node(&new_node, end_node.as_ref());
 				prev_children.push(new_node);
 			}
 		})
@@ -126,6 +126,7 @@ where
 /// - `Vec<N>`, `[N; _]`, `[N]`
 /// - [`Signal`], [`Derived`], [`Memo`], [`WithDefault`]
 /// - `LazyCell<N, impl Fn() -> N>`
+/// - [`Fragment`]
 /// - `!`
 ///
 /// Where `N` is any of the types above.
@@ -162,6 +163,12 @@ impl WithDynNodes for Ty {
 	}
 }
 
+impl WithDynNodes for Fragment {
+	fn with_nodes(&self, f: impl FnMut(web_sys::Node)) {
+		self.nodes().iter().cloned().for_each(f);
+	}
+}
+
 impl<N> WithDynNodes for Option<N>
 where
 	N: WithDynNodes,
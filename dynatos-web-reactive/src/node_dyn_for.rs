@@ -0,0 +1,495 @@
+//! Node reactive keyed list
+
+// Imports
+use {
+	crate::ObjectAttachEffect,
+	core::{hash::Hash, ops::Deref},
+	dynatos_reactive::{Effect, SignalWith},
+	dynatos_sync_types::{IMut, RcPtr, SyncBounds},
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, html},
+	js_sys::WeakRef,
+	std::collections::{HashMap, HashSet},
+};
+
+/// Extension trait to add a reactive keyed list to a node
+#[extend::ext(name = NodeDynFor)]
+pub impl web_sys::Node {
+	/// Adds a keyed list of children to this node.
+	///
+	/// Each item of `items` is identified by the key returned by `key`, and
+	/// its row is created by calling `view` the first time its key appears.
+	///
+	/// Whenever `items` changes, the old and new keys are compared, and only
+	/// the nodes of the keys that were added, removed or moved are touched.
+	/// The rows of all other keys are kept as-is, so any state within them
+	/// (such as focus, or their own effects) survives the update.
+	///
+	/// If multiple items have the same key, only the first is used.
+	///
+	/// Each row owns an effect that calls `view`, so if `view` uses any signals,
+	/// only that row's node is re-created when they change. Keep in mind that
+	/// the row keeps the item it was created with, even if a later item has the
+	/// same key, so any changing data should be within a signal in the item.
+	#[track_caller]
+	fn add_dyn_for<S, T, K, KF, N, VF>(&self, ctx: &DynatosWebCtx, items: S, key: KF, view: VF)
+	where
+		S: SyncBounds + for<'a> SignalWith<Value<'a>: Deref<Target = Vec<T>>> + 'static,
+		T: SyncBounds + Clone + 'static,
+		K: SyncBounds + Eq + Hash + Clone + 'static,
+		KF: SyncBounds + Fn(&T) -> K + 'static,
+		N: AsRef<web_sys::Node>,
+		VF: SyncBounds + Fn(&T) -> N + 'static,
+	{
+		// Note: We have an empty `<template>` to mark the end of the list,
+		//       so that we can keep our position if there are other children.
+		let end_node = web_sys::Node::from(html::template(ctx));
+		self.append_child(&end_node).expect("Unable to add reactive list");
+
+		// Note: It's important that we only keep a `WeakRef` to the node.
+		//       Otherwise, the node will be keeping us alive, while we keep
+		//       the node alive, causing a leak.
+		let node = WeakRef::new(self);
+		let view = RcPtr::new(view);
+		let rows = IMut::new(Rows::<K> {
+			keys: vec![],
+			rows: HashMap::new(),
+		});
+		let list_effect = Effect::try_new(move || {
+			// Try to get the node
+			let node = node.deref().or_return()?;
+
+			items.with(|items| {
+				let mut rows = rows.lock();
+
+				// Get all the new keys, creating the rows of any new ones
+				let mut new_keys = Vec::with_capacity(items.len());
+				let mut seen_keys = HashSet::with_capacity(items.len());
+				for item in &**items {
+					let key = key(item);
+					if !seen_keys.insert(key.clone()) {
+						tracing::warn!("Found duplicate key in reactive list, ignoring item");
+						continue;
+					}
+
+					_ = rows
+						.rows
+						.entry(key.clone())
+						.or_insert_with(|| Row::new(item.clone(), RcPtr::clone(&view)));
+					new_keys.push(key);
+				}
+
+				// Then update the nodes
+				for op in self::diff_keys(&rows.keys, &new_keys) {
+					match op {
+						KeyedOp::Remove(key) => {
+							let row = rows.rows.remove(&key).expect("Removed key should exist");
+
+							// Note: If the node was already removed by someone else, there's nothing to do
+							_ = node.remove_child(&row.node());
+						},
+						KeyedOp::Insert { key, before } => {
+							let before = match before {
+								Some(before) => rows.rows[&before].node(),
+								None => end_node.clone(),
+							};
+							node.insert_before(&rows.rows[&key].node(), Some(&before))
+								.expect("Unable to add reactive list item");
+						},
+					}
+				}
+
+				rows.keys = new_keys;
+			});
+		})
+		.or_return()?;
+
+		// Then set it
+		self.attach_effect(list_effect);
+	}
+}
+
+/// Extension trait to add a reactive keyed list to a node
+#[extend::ext(name = NodeWithDynFor)]
+pub impl<E> E
+where
+	E: AsRef<web_sys::Node>,
+{
+	/// Adds a keyed list of children to this node.
+	///
+	/// See [`add_dyn_for`](NodeDynFor::add_dyn_for) for details.
+	///
+	/// Returns the node, for chaining
+	#[track_caller]
+	fn with_dyn_for<S, T, K, KF, N, VF>(self, ctx: &DynatosWebCtx, items: S, key: KF, view: VF) -> Self
+	where
+		S: SyncBounds + for<'a> SignalWith<Value<'a>: Deref<Target = Vec<T>>> + 'static,
+		T: SyncBounds + Clone + 'static,
+		K: SyncBounds + Eq + Hash + Clone + 'static,
+		KF: SyncBounds + Fn(&T) -> K + 'static,
+		N: AsRef<web_sys::Node>,
+		VF: SyncBounds + Fn(&T) -> N + 'static,
+	{
+		self.as_ref().add_dyn_for(ctx, items, key, view);
+		self
+	}
+}
+
+/// Live rows of a keyed list
+struct Rows<K> {
+	/// Keys, in order
+	keys: Vec<K>,
+
+	/// Row of each key
+	rows: HashMap<K, Row>,
+}
+
+/// Live row of a keyed list
+struct Row {
+	/// Current node, shared with the effect
+	node: RcPtr<IMut<Option<web_sys::Node>>>,
+
+	/// Effect that renders the node.
+	///
+	/// If `None`, the view had no dependencies, so it won't ever be re-created.
+	_effect: Option<Effect>,
+}
+
+impl Row {
+	/// Creates a new row for `item`.
+	///
+	/// Whenever the view changes after being created, the current
+	/// node is replaced with the new one in its parent.
+	#[track_caller]
+	fn new<T, N, VF>(item: T, view: RcPtr<VF>) -> Self
+	where
+		T: SyncBounds + 'static,
+		N: AsRef<web_sys::Node>,
+		VF: SyncBounds + Fn(&T) -> N + 'static,
+	{
+		let node = RcPtr::new(IMut::new(None::<web_sys::Node>));
+		let effect = Effect::try_new({
+			let node = RcPtr::clone(&node);
+			move || {
+				let new_node = view(&item).as_ref().clone();
+				let old_node = node.lock().replace(new_node.clone());
+
+				// Note: If we haven't been inserted yet, the list will insert the new node
+				if let Some(old_node) = old_node &&
+					let Some(parent) = old_node.parent_node()
+				{
+					parent
+						.replace_child(&new_node, &old_node)
+						.expect("Unable to replace reactive list item");
+				}
+			}
+		});
+
+		Self {
+			node,
+			_effect: effect.map(Effect::unsize),
+		}
+	}
+
+	/// Returns the current node
+	fn node(&self) -> web_sys::Node {
+		self.node.lock().clone().expect("Row should have been rendered")
+	}
+}
+
+/// Operation to update a keyed list
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum KeyedOp<K> {
+	/// Removes the row with a key
+	Remove(K),
+
+	/// Inserts, or moves, the row with `key` before the row with `before`,
+	/// or at the end, if `None`.
+	Insert { key: K, before: Option<K> },
+}
+
+/// Diffs the keys of a keyed list.
+///
+/// Returns the operations to apply, in order, to get from `prev` to `new`.
+/// All removals are returned first. `new` must not contain any duplicate keys.
+///
+/// Only the keys outside of the longest increasing subsequence of the previous
+/// positions are moved, so the number of moves is minimal.
+fn diff_keys<K>(prev: &[K], new: &[K]) -> Vec<KeyedOp<K>>
+where
+	K: Eq + Hash + Clone,
+{
+	let new_keys = new.iter().collect::<HashSet<_>>();
+
+	// Remove all keys that no longer exist
+	let mut ops = vec![];
+	let mut prev_idxs = HashMap::with_capacity(prev.len());
+	for key in prev {
+		match new_keys.contains(key) {
+			true => _ = prev_idxs.insert(key, prev_idxs.len()),
+			false => ops.push(KeyedOp::Remove(key.clone())),
+		}
+	}
+
+	// Then find which of the kept keys may stay in place.
+	// Note: Any keys already in increasing order relative to each other don't
+	//       need to be moved, so we keep the longest such sequence.
+	let new_prev_idxs = new.iter().map(|key| prev_idxs.get(key).copied()).collect::<Vec<_>>();
+	let stays = self::longest_increasing_subsequence(&new_prev_idxs);
+
+	// Finally insert or move all other keys, from the back, so the key
+	// we're inserting before is always already in its final place.
+	for (idx, key) in new.iter().enumerate().rev() {
+		if stays[idx] {
+			continue;
+		}
+
+		ops.push(KeyedOp::Insert {
+			key:    key.clone(),
+			before: new.get(idx + 1).cloned(),
+		});
+	}
+
+	ops
+}
+
+/// Returns which values are part of the longest strictly increasing subsequence of `values`.
+///
+/// `None`s are never part of the subsequence.
+fn longest_increasing_subsequence(values: &[Option<usize>]) -> Vec<bool> {
+	// Note: `tails[len]` is the index of the smallest value that ends
+	//       an increasing subsequence of length `len + 1`.
+	let mut tails = Vec::<usize>::new();
+	let mut prev_idxs = vec![None; values.len()];
+	for (idx, value) in values.iter().enumerate() {
+		let Some(value) = *value else { continue };
+
+		let len = tails.partition_point(|&tail_idx| values[tail_idx] < Some(value));
+		prev_idxs[idx] = len.checked_sub(1).map(|prev_len| tails[prev_len]);
+		match tails.get_mut(len) {
+			Some(tail_idx) => *tail_idx = idx,
+			None => tails.push(idx),
+		}
+	}
+
+	let mut in_subsequence = vec![false; values.len()];
+	let mut cur_idx = tails.last().copied();
+	while let Some(idx) = cur_idx {
+		in_subsequence[idx] = true;
+		cur_idx = prev_idxs[idx];
+	}
+
+	in_subsequence
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	#[cfg(feature = "ssr")]
+	use {
+		crate::test_util,
+		core::iter,
+		dynatos_reactive::{Signal, SignalGet, SignalSet},
+	};
+
+	/// Applies `ops` to `keys`
+	fn apply(keys: &mut Vec<char>, ops: &[KeyedOp<char>]) {
+		for op in ops {
+			match *op {
+				KeyedOp::Remove(key) => keys.retain(|&cur_key| cur_key != key),
+				KeyedOp::Insert { key, before } => {
+					keys.retain(|&cur_key| cur_key != key);
+					let idx = match before {
+						Some(before) => keys.iter().position(|&cur_key| cur_key == before).expect("Missing key"),
+						None => keys.len(),
+					};
+					keys.insert(idx, key);
+				},
+			}
+		}
+	}
+
+	/// Diffs `prev` and `new`, checks that the diff results in `new`, and returns the operations
+	fn diff(prev: &str, new: &str) -> Vec<KeyedOp<char>> {
+		let prev = prev.chars().collect::<Vec<_>>();
+		let new = new.chars().collect::<Vec<_>>();
+
+		let ops = diff_keys(&prev, &new);
+		let mut keys = prev;
+		apply(&mut keys, &ops);
+		assert_eq!(keys, new, "Diff didn't result in the new keys: {ops:?}");
+
+		ops
+	}
+
+	#[test]
+	fn insert() {
+		assert_eq!(diff("", "abc"), [
+			KeyedOp::Insert {
+				key:    'c',
+				before: None,
+			},
+			KeyedOp::Insert {
+				key:    'b',
+				before: Some('c'),
+			},
+			KeyedOp::Insert {
+				key:    'a',
+				before: Some('b'),
+			},
+		]);
+		assert_eq!(diff("ac", "abc"), [KeyedOp::Insert {
+			key:    'b',
+			before: Some('c'),
+		}]);
+		assert_eq!(diff("bc", "abc"), [KeyedOp::Insert {
+			key:    'a',
+			before: Some('b'),
+		}]);
+		assert_eq!(diff("ab", "abc"), [KeyedOp::Insert {
+			key:    'c',
+			before: None,
+		}]);
+	}
+
+	#[test]
+	fn remove() {
+		assert_eq!(diff("abc", "ac"), [KeyedOp::Remove('b')]);
+		assert_eq!(diff("abc", ""), [
+			KeyedOp::Remove('a'),
+			KeyedOp::Remove('b'),
+			KeyedOp::Remove('c')
+		]);
+		assert_eq!(diff("abcd", "bd"), [KeyedOp::Remove('a'), KeyedOp::Remove('c')]);
+	}
+
+	#[test]
+	fn reorder() {
+		assert_eq!(diff("abc", "cab"), [KeyedOp::Insert {
+			key:    'c',
+			before: Some('a'),
+		}]);
+		assert_eq!(diff("abcd", "adcb"), [
+			KeyedOp::Insert {
+				key:    'c',
+				before: Some('b'),
+			},
+			KeyedOp::Insert {
+				key:    'd',
+				before: Some('c'),
+			},
+		]);
+		assert_eq!(diff("abcde", "bcdea"), [KeyedOp::Insert {
+			key:    'a',
+			before: None,
+		}]);
+		assert_eq!(diff("abcde", "eabcd"), [KeyedOp::Insert {
+			key:    'e',
+			before: Some('a'),
+		}]);
+		assert_eq!(diff("abcde", "edcba").len(), 4);
+	}
+
+	#[test]
+	fn mixed() {
+		_ = diff("abcde", "fdbg");
+		_ = diff("abc", "xyz");
+		_ = diff("abcdef", "fabxc");
+	}
+
+	#[test]
+	fn longest_increasing_subsequence() {
+		assert_eq!(super::longest_increasing_subsequence(&[]), Vec::<bool>::new());
+		assert_eq!(
+			super::longest_increasing_subsequence(&[Some(1), Some(2), Some(3), Some(4), Some(0)]),
+			[true, true, true, true, false]
+		);
+		assert_eq!(
+			super::longest_increasing_subsequence(&[Some(2), None, Some(0), Some(1)]),
+			[false, false, true, true]
+		);
+		assert_eq!(super::longest_increasing_subsequence(&[None, None]), [false, false]);
+	}
+
+	/// Returns all children of `node`
+	#[cfg(feature = "ssr")]
+	fn children(node: &web_sys::Node) -> Vec<web_sys::Node> {
+		iter::successors(node.first_child(), web_sys::Node::next_sibling).collect()
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	fn keeps_nodes() {
+		let ctx = test_util::ctx();
+		let items = Signal::new(vec!['a', 'b', 'c', 'd', 'e']);
+		let list = html::ul(&ctx).with_dyn_for(&ctx, items.clone(), |&item| item, {
+			let ctx = ctx.clone();
+			move |_| html::li(&ctx)
+		});
+
+		let mut nodes = self::children(&list);
+		let end_node = nodes.pop().expect("Missing end node");
+		let nodes = iter::zip(['a', 'b', 'c', 'd', 'e'], nodes).collect::<HashMap<_, _>>();
+		let expected = |keys: &str| {
+			keys.chars()
+				.map(|key| nodes[&key].clone())
+				.chain([end_node.clone()])
+				.collect::<Vec<_>>()
+		};
+
+		for keys in ["bcdea", "edcba", "ace", "eca", "abcde"] {
+			items.set(keys.chars().collect::<Vec<_>>());
+			let children = self::children(&list);
+			match keys {
+				// Note: Removed keys are re-created when added back
+				"abcde" => {
+					assert_eq!(children[0], nodes[&'a']);
+					assert_ne!(children[1], nodes[&'b']);
+					assert_eq!(children[2], nodes[&'c']);
+					assert_ne!(children[3], nodes[&'d']);
+					assert_eq!(children[4], nodes[&'e']);
+				},
+				_ => assert_eq!(children, expected(keys), "Nodes weren't kept for {keys:?}"),
+			}
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	fn row_effect() {
+		let ctx = test_util::ctx();
+		let items = Signal::new(vec!['a', 'b', 'c']);
+		let highlight = Signal::new(false);
+		let list = html::ul(&ctx).with_dyn_for(&ctx, items.clone(), |&item| item, {
+			let ctx = ctx.clone();
+			let highlight = highlight.clone();
+			move |&item| {
+				let li = html::li(&ctx);
+				if item == 'b' && highlight.get() {
+					li.set_class_name("highlight");
+				}
+				li
+			}
+		});
+
+		let prev_children = self::children(&list);
+		highlight.set(true);
+		let children = self::children(&list);
+		assert_eq!(children.len(), prev_children.len());
+		assert_eq!(children[0], prev_children[0]);
+		assert_ne!(children[1], prev_children[1]);
+		assert_eq!(children[2], prev_children[2]);
+
+		// Note: The row's effect is dropped with the row
+		items.set(vec!['a', 'c']);
+		highlight.set(false);
+		assert_eq!(self::children(&list)[..2], [children[0].clone(), children[2].clone()]);
+	}
+
+	#[test]
+	fn no_op() {
+		assert_eq!(diff("", ""), []);
+		assert_eq!(diff("abc", "abc"), []);
+	}
+}
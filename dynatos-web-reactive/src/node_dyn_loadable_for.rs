@@ -0,0 +1,137 @@
+//! Node reactive keyed list of loadable items
+
+// Imports
+use {
+	crate::NodeDynFor,
+	core::{hash::Hash, ops::Deref},
+	dynatos_loadable::{Loadable, LoadableSignal},
+	dynatos_reactive::{SignalWith, async_signal::Loader},
+	dynatos_sync_types::SyncBounds,
+	dynatos_web::DynatosWebCtx,
+};
+
+/// Extension trait to add a reactive keyed list of loadable items to a node
+#[extend::ext(name = NodeDynLoadableFor)]
+pub impl web_sys::Node {
+	/// Adds a keyed list of loadable items to this node.
+	///
+	/// Each item loads independently, and is rendered by calling `view` with its
+	/// current state, so it may show its own loading, loaded or error view.
+	/// Whenever an item's state changes, only its own node is replaced.
+	///
+	/// See [`add_dyn_for`](NodeDynFor::add_dyn_for) for details on how changes
+	/// to `items` itself are handled.
+	#[track_caller]
+	fn add_dyn_loadable_for<S, F, T, E, K, KF, N, VF>(&self, ctx: &DynatosWebCtx, items: S, key: KF, view: VF)
+	where
+		S: SyncBounds + for<'a> SignalWith<Value<'a>: Deref<Target = Vec<LoadableSignal<F>>>> + 'static,
+		F: Loader<Output = Result<T, E>>,
+		T: 'static,
+		E: Clone + 'static,
+		LoadableSignal<F>: SyncBounds,
+		K: SyncBounds + Eq + Hash + Clone + 'static,
+		KF: SyncBounds + Fn(&LoadableSignal<F>) -> K + 'static,
+		N: AsRef<web_sys::Node>,
+		VF: SyncBounds + Fn(Loadable<&T, E>) -> N + 'static,
+	{
+		// Note: Each row has its own effect, so whenever the item's state changes,
+		//       only that row is re-rendered.
+		self.add_dyn_for(ctx, items, key, move |item| item.with(&view));
+	}
+}
+
+/// Extension trait to add a reactive keyed list of loadable items to a node
+#[extend::ext(name = NodeWithDynLoadableFor)]
+pub impl<E> E
+where
+	E: AsRef<web_sys::Node>,
+{
+	/// Adds a keyed list of loadable items to this node.
+	///
+	/// See [`add_dyn_loadable_for`](NodeDynLoadableFor::add_dyn_loadable_for) for details.
+	///
+	/// Returns the node, for chaining
+	#[track_caller]
+	fn with_dyn_loadable_for<S, F, T, Err, K, KF, N, VF>(self, ctx: &DynatosWebCtx, items: S, key: KF, view: VF) -> Self
+	where
+		S: SyncBounds + for<'a> SignalWith<Value<'a>: Deref<Target = Vec<LoadableSignal<F>>>> + 'static,
+		F: Loader<Output = Result<T, Err>>,
+		T: 'static,
+		Err: Clone + 'static,
+		LoadableSignal<F>: SyncBounds,
+		K: SyncBounds + Eq + Hash + Clone + 'static,
+		KF: SyncBounds + Fn(&LoadableSignal<F>) -> K + 'static,
+		N: AsRef<web_sys::Node>,
+		VF: SyncBounds + Fn(Loadable<&T, Err>) -> N + 'static,
+	{
+		self.as_ref().add_dyn_loadable_for(ctx, items, key, view);
+		self
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::test_util,
+		core::{iter, ptr},
+		dynatos_reactive::Signal,
+		dynatos_web::{ElementWithAttr, html},
+		web_sys::Element,
+		zutil_inheritance::Downcast,
+	};
+
+	/// Creates an item that loads `name` after yielding `yields` times
+	fn item(name: char, yields: usize) -> LoadableSignal<impl Loader<Output = Result<char, ()>> + Clone> {
+		LoadableSignal::new(move || async move {
+			for _ in 0..yields {
+				tokio::task::yield_now().await;
+			}
+			Ok(name)
+		})
+	}
+
+	#[test]
+	fn out_of_order() {
+		test_util::run(async {
+			let ctx = test_util::ctx();
+			let items = Signal::new(vec![item('a', 4), item('b', 0), item('c', 2)]);
+			// Note: The items are never changed, so we can key them by their address
+			let key = |item: &LoadableSignal<_>| ptr::from_ref(item).addr();
+			let list = html::ul(&ctx).with_dyn_loadable_for(&ctx, items, key, {
+				let ctx = ctx.clone();
+				move |item| {
+					let state = match item {
+						Loadable::Empty => "empty".to_owned(),
+						Loadable::Loading => "loading".to_owned(),
+						Loadable::Err(()) => "error".to_owned(),
+						Loadable::Loaded(&name) => name.to_string(),
+					};
+					html::li(&ctx).with_attr("data-state", state)
+				}
+			});
+
+			let states = || {
+				iter::successors(list.first_child(), web_sys::Node::next_sibling)
+					.filter_map(|child| child.downcast::<Element>().ok())
+					.filter_map(|child| child.get_attribute("data-state").ok())
+					.collect::<Vec<_>>()
+			};
+			assert_eq!(states(), ["loading", "loading", "loading"]);
+
+			tokio::task::yield_now().await;
+			assert_eq!(states(), ["loading", "b", "loading"]);
+
+			for _ in 0..2 {
+				tokio::task::yield_now().await;
+			}
+			assert_eq!(states(), ["loading", "b", "c"]);
+
+			for _ in 0..2 {
+				tokio::task::yield_now().await;
+			}
+			assert_eq!(states(), ["a", "b", "c"]);
+		});
+	}
+}
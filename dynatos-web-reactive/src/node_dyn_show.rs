@@ -0,0 +1,258 @@
+//! Node reactive conditional children
+
+// Imports
+use {
+	crate::{NodeDynChild, ObjectDetachEffect, WithDynPred},
+	dynatos_reactive::effect,
+	dynatos_sync_types::{IMut, SyncBounds},
+	dynatos_web::DynatosWebCtx,
+};
+
+/// Extension trait to add a reactive conditional child to a node
+#[extend::ext(name = NodeDynShow)]
+pub impl web_sys::Node {
+	/// Adds a child to this node that's only shown while `pred` is true.
+	///
+	/// The child is only created by `then` the first time `pred` is true,
+	/// and afterwards it's kept and re-used whenever `pred` becomes true again,
+	/// instead of being re-created.
+	///
+	/// `then` is called without gathering any dependencies.
+	///
+	/// While the child is hidden, any effects attached to it, or to any of its
+	/// descendants, are suppressed. Once it's shown again, the ones that missed
+	/// any changes while hidden are re-run, to catch up on them.
+	#[track_caller]
+	fn add_dyn_show<P, N, TF>(&self, ctx: &DynatosWebCtx, pred: P, then: TF)
+	where
+		P: WithDynPred + 'static,
+		N: AsRef<web_sys::Node>,
+		TF: SyncBounds + Fn() -> N + 'static,
+	{
+		self::add_dyn_show_inner(self, ctx, pred, then, None::<fn() -> web_sys::Node>);
+	}
+
+	/// Adds a child to this node that's shown while `pred` is true,
+	/// and a fallback child that's shown while it's false.
+	///
+	/// Both children are only created the first time they're shown, and
+	/// afterwards are kept and re-used. See [`add_dyn_show`](NodeDynShow::add_dyn_show)
+	/// for details.
+	#[track_caller]
+	fn add_dyn_show_else<P, N, TF, FN, FF>(&self, ctx: &DynatosWebCtx, pred: P, then: TF, fallback: FF)
+	where
+		P: WithDynPred + 'static,
+		N: AsRef<web_sys::Node>,
+		TF: SyncBounds + Fn() -> N + 'static,
+		FN: AsRef<web_sys::Node>,
+		FF: SyncBounds + Fn() -> FN + 'static,
+	{
+		self::add_dyn_show_inner(self, ctx, pred, then, Some(fallback));
+	}
+}
+
+/// Extension trait to add a reactive conditional child to a node
+#[extend::ext(name = NodeWithDynShow)]
+pub impl<E> E
+where
+	E: AsRef<web_sys::Node>,
+{
+	/// Adds a child to this node that's only shown while `pred` is true.
+	///
+	/// See [`add_dyn_show`](NodeDynShow::add_dyn_show) for details.
+	///
+	/// Returns the node, for chaining
+	#[track_caller]
+	fn with_dyn_show<P, N, TF>(self, ctx: &DynatosWebCtx, pred: P, then: TF) -> Self
+	where
+		P: WithDynPred + 'static,
+		N: AsRef<web_sys::Node>,
+		TF: SyncBounds + Fn() -> N + 'static,
+	{
+		self.as_ref().add_dyn_show(ctx, pred, then);
+		self
+	}
+
+	/// Adds a child to this node that's shown while `pred` is true,
+	/// and a fallback child that's shown while it's false.
+	///
+	/// See [`add_dyn_show_else`](NodeDynShow::add_dyn_show_else) for details.
+	///
+	/// Returns the node, for chaining
+	#[track_caller]
+	fn with_dyn_show_else<P, N, TF, FN, FF>(self, ctx: &DynatosWebCtx, pred: P, then: TF, fallback: FF) -> Self
+	where
+		P: WithDynPred + 'static,
+		N: AsRef<web_sys::Node>,
+		TF: SyncBounds + Fn() -> N + 'static,
+		FN: AsRef<web_sys::Node>,
+		FF: SyncBounds + Fn() -> FN + 'static,
+	{
+		self.as_ref().add_dyn_show_else(ctx, pred, then, fallback);
+		self
+	}
+}
+
+/// Inner function for [`NodeDynShow::add_dyn_show`] and [`NodeDynShow::add_dyn_show_else`].
+#[track_caller]
+fn add_dyn_show_inner<P, N, TF, FN, FF>(
+	node: &web_sys::Node,
+	ctx: &DynatosWebCtx,
+	pred: P,
+	then: TF,
+	fallback: Option<FF>,
+) where
+	P: WithDynPred + 'static,
+	N: AsRef<web_sys::Node>,
+	TF: SyncBounds + Fn() -> N + 'static,
+	FN: AsRef<web_sys::Node>,
+	FF: SyncBounds + Fn() -> FN + 'static,
+{
+	let then_node = IMut::new(None);
+	let fallback_node = IMut::new(None);
+	let prev_shown = IMut::new(None);
+	node.add_dyn_child(ctx, move || {
+		let shown = pred.eval();
+
+		// If we switched branches, suppress the hidden one and resume the shown one
+		if prev_shown.lock().replace(shown) != Some(shown) {
+			let (shown_node, hidden_node) = match shown {
+				true => (&then_node, &fallback_node),
+				false => (&fallback_node, &then_node),
+			};
+
+			if let Some(node) = &*hidden_node.lock() {
+				self::set_effects_suppressed(node, true);
+			}
+			if let Some(node) = &*shown_node.lock() {
+				self::set_effects_suppressed(node, false);
+			}
+		}
+
+		match shown {
+			true => Some(self::get_or_create(&then_node, &then)),
+			false => fallback
+				.as_ref()
+				.map(|fallback| self::get_or_create(&fallback_node, fallback)),
+		}
+	});
+}
+
+/// Sets whether the effects attached to `node` and its descendants are suppressed.
+///
+/// When un-suppressing, only the effects that missed a run while suppressed are re-run.
+fn set_effects_suppressed(node: &web_sys::Node, suppressed: bool) {
+	for effect in node.effects() {
+		effect.set_suppressed(suppressed);
+		match suppressed {
+			// Note: We clear any runs missed before, since those were meant to be skipped.
+			true => _ = effect.take_missed_run(),
+			false =>
+				if effect.take_missed_run() {
+					effect.force_run();
+				},
+		}
+	}
+
+	let mut child = node.first_child();
+	while let Some(cur_child) = child {
+		self::set_effects_suppressed(&cur_child, suppressed);
+		child = cur_child.next_sibling();
+	}
+}
+
+/// Gets the node in `cache`, or creates it with `f`
+fn get_or_create<N, F>(cache: &IMut<Option<web_sys::Node>>, f: F) -> web_sys::Node
+where
+	N: AsRef<web_sys::Node>,
+	F: FnOnce() -> N,
+{
+	cache
+		.lock()
+		.get_or_insert_with(|| effect::with_no_dep(f).as_ref().clone())
+		.clone()
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::{ObjectWithEffect, test_util},
+		dynatos_reactive::{Effect, Signal, SignalGet, SignalSet},
+		dynatos_util::Counter,
+		dynatos_web::{NodeWithChildren, html},
+	};
+
+	#[test]
+	fn reuses_node() {
+		static CREATES: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let shown = Signal::new(true);
+		let div = html::div(&ctx).with_dyn_show(
+			&ctx,
+			{
+				let shown = shown.clone();
+				move || shown.get()
+			},
+			{
+				let ctx = ctx.clone();
+				move || {
+					CREATES.bump();
+					html::span(&ctx)
+				}
+			},
+		);
+
+		let node = div.first_child().expect("Missing child");
+		shown.set(false);
+		assert_ne!(div.first_child().as_ref(), Some(&node));
+		shown.set(true);
+		assert_eq!(div.first_child().as_ref(), Some(&node));
+		assert_eq!(CREATES.get(), 1);
+	}
+
+	#[test]
+	fn suppresses_hidden() {
+		static RUNS: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let shown = Signal::new(true);
+		let value = Signal::new(0);
+		let _div = html::div(&ctx).with_dyn_show(
+			&ctx,
+			{
+				let shown = shown.clone();
+				move || shown.get()
+			},
+			{
+				let ctx = ctx.clone();
+				let value = value.clone();
+				move || {
+					let value = value.clone();
+					html::div(&ctx).with_child(html::span(&ctx).with_effect(Effect::new(move || {
+						_ = value.get();
+						RUNS.bump();
+					})))
+				}
+			},
+		);
+		assert_eq!(RUNS.get(), 1);
+
+		shown.set(false);
+		value.set(1);
+		assert_eq!(RUNS.get(), 1, "Hidden effect was run");
+
+		// Note: Once shown, the effect catches up on the changes it missed
+		shown.set(true);
+		assert_eq!(RUNS.get(), 2);
+		value.set(2);
+		assert_eq!(RUNS.get(), 3);
+
+		// Note: If nothing changed while hidden, the effect isn't re-run
+		shown.set(false);
+		shown.set(true);
+		assert_eq!(RUNS.get(), 3, "Effect was re-run without any changes");
+	}
+}
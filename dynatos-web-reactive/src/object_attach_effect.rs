@@ -3,12 +3,20 @@
 // Imports
 use {
 	dynatos_reactive::{Effect, EffectRun},
-	dynatos_web::{ObjectGet, ObjectSetProp, cfg_ssr_expr},
+	dynatos_web::{ObjectGet, ObjectRemoveProp, ObjectSetProp, cfg_ssr_expr},
 	js_sys::Object,
 };
 
+/// Property name for the attached effects
+// TODO: Use an static anonymous symbol?
+const PROP_NAME: &str = "__dynatos_effects";
+
+/// A wasm `Effect` type.
+#[cfg(feature = "csr")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+struct WasmEffect(Effect);
+
 /// Extension trait to add an effect to an object
-// TODO: Allow removing effects?
 #[extend::ext(name = ObjectAttachEffect)]
 pub impl Object {
 	/// Attaches an effect to this object
@@ -16,9 +24,6 @@ pub impl Object {
 	where
 		F: ?Sized + EffectRun,
 	{
-		// TODO: Use an static anonymous symbol?
-		let prop_name = "__dynatos_effects";
-
 		cfg_ssr_expr!(
 			ssr = {
 				use {
@@ -27,17 +32,17 @@ pub impl Object {
 					zutil_inheritance::{FromFields, Value},
 				};
 
-				let effects = match self.get::<ObjectAttachEffectEffects>(prop_name) {
+				let effects = match self.get::<ObjectAttachEffectEffects>(PROP_NAME) {
 					Ok(effects) => effects,
 					Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
-					Err(dynatos_web::GetError::Missing) => {
+					Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => {
 						let effects = ObjectAttachEffectEffects::from_fields((
 							ObjectAttachEffectEffectsFields {
 								effects: Mutex::new(HashMap::new()),
 							},
 							<Object as Value>::Fields::default(),
 						));
-						self.set_prop(prop_name, effects.clone());
+						self.set_prop(PROP_NAME, effects.clone());
 
 						effects
 					},
@@ -46,18 +51,13 @@ pub impl Object {
 				effects.fields().effects.lock().insert(effect.id(), effect.unsize());
 			},
 			csr = {
-				/// A wasm `Effect` type.
-				#[wasm_bindgen::prelude::wasm_bindgen]
-				#[expect(dead_code, reason = "We just want to keep the field alive, not use it")]
-				struct WasmEffect(Effect);
-
 				// Get the effects map, or create it, if it doesn't exist
-				let effects = match self.get::<js_sys::Map>(prop_name) {
+				let effects = match self.get::<js_sys::Map>(PROP_NAME) {
 					Ok(effects) => effects,
 					Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
-					Err(dynatos_web::GetError::Missing) => {
+					Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => {
 						let effects = js_sys::Map::new();
-						self.set_prop(prop_name, &effects);
+						self.set_prop(PROP_NAME, &effects);
 						effects
 					},
 				};
@@ -71,6 +71,115 @@ pub impl Object {
 	}
 }
 
+/// Extension trait to query and remove the effects attached to an object
+#[extend::ext(name = ObjectDetachEffect)]
+pub impl Object {
+	/// Detaches all effects attached to this object.
+	///
+	/// The effects are dropped immediately, so unless they're kept
+	/// alive elsewhere, they will no longer run.
+	fn detach_effects(&self) {
+		cfg_ssr_expr!(
+			ssr = {
+				use {core::mem, dynatos_web_ssr::ObjectAttachEffectEffects, zutil_inheritance::Value};
+
+				let effects = match self.get::<ObjectAttachEffectEffects>(PROP_NAME) {
+					Ok(effects) => effects,
+					Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
+					Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => return,
+				};
+				self.remove_prop(PROP_NAME);
+
+				// Note: We take the effects before dropping them, since any effects
+				//       that are dropped could try to access the map.
+				let effects = mem::take(&mut *effects.fields().effects.lock());
+				drop(effects);
+			},
+			csr = {
+				use wasm_bindgen::convert::TryFromJsValue;
+
+				let effects = match self.get::<js_sys::Map>(PROP_NAME) {
+					Ok(effects) => effects,
+					Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
+					Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => return,
+				};
+				self.remove_prop(PROP_NAME);
+
+				// Note: Just removing the effects from javascript would only drop them once
+				//       they're garbage collected, so we take them back to drop them now.
+				for effect in effects.values() {
+					let effect = effect.expect("Unable to iterate effects map");
+					let effect = WasmEffect::try_from_js_value(effect)
+						.unwrap_or_else(|effect| panic!("Effect was the wrong type: {effect:?}"));
+					drop(effect);
+				}
+				effects.clear();
+			}
+		);
+	}
+
+	/// Returns all effects attached to this object
+	fn effects(&self) -> Vec<Effect> {
+		cfg_ssr_expr!(
+			ssr = {
+				use {dynatos_web_ssr::ObjectAttachEffectEffects, zutil_inheritance::Value};
+
+				match self.get::<ObjectAttachEffectEffects>(PROP_NAME) {
+					Ok(effects) => effects.fields().effects.lock().values().cloned().collect(),
+					Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
+					Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => vec![],
+				}
+			},
+			csr = {
+				use wasm_bindgen::convert::TryFromJsValue;
+
+				let effects = match self.get::<js_sys::Map>(PROP_NAME) {
+					Ok(effects) => effects,
+					Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
+					Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => return vec![],
+				};
+
+				// Note: Getting an effect out of javascript takes ownership of it,
+				//       so we put back a clone of each one.
+				effects
+					.keys()
+					.into_iter()
+					.map(|id| {
+						let id = id.expect("Unable to iterate effects map");
+						let effect = WasmEffect::try_from_js_value(effects.get(&id))
+							.unwrap_or_else(|effect| panic!("Effect was the wrong type: {effect:?}"));
+						effects.set(&id, &WasmEffect(effect.0.clone()).into());
+						effect.0
+					})
+					.collect()
+			}
+		)
+	}
+
+	/// Returns if `effect` is attached to this object
+	fn has_effect<F>(&self, effect: &Effect<F>) -> bool
+	where
+		F: ?Sized,
+	{
+		cfg_ssr_expr!(
+			ssr = {
+				use {dynatos_web_ssr::ObjectAttachEffectEffects, zutil_inheritance::Value};
+
+				match self.get::<ObjectAttachEffectEffects>(PROP_NAME) {
+					Ok(effects) => effects.fields().effects.lock().contains_key(&effect.id()),
+					Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
+					Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => false,
+				}
+			},
+			csr = match self.get::<js_sys::Map>(PROP_NAME) {
+				Ok(effects) => effects.has(&effect.id().into()),
+				Err(dynatos_web::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
+				Err(dynatos_web::GetError::Missing | dynatos_web::GetError::Null) => false,
+			}
+		)
+	}
+}
+
 /// Extension trait to add an effect to an object
 #[extend::ext(name = ObjectWithEffect)]
 pub impl<O> O
@@ -88,3 +197,24 @@ where
 		self
 	}
 }
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {super::*, crate::test_util, dynatos_web::html};
+
+	#[test]
+	fn attach_detach() {
+		let ctx = test_util::ctx();
+		let div = html::div(&ctx);
+
+		let effect = Effect::new(|| ());
+		div.attach_effect(effect.clone());
+		assert!(div.has_effect(&effect));
+		assert!(!effect.is_inert());
+
+		div.detach_effects();
+		assert!(!div.has_effect(&effect));
+		assert!(effect.is_inert());
+	}
+}
@@ -0,0 +1,46 @@
+//! Test utilities
+
+// Imports
+use {
+	dynatos_web::DynatosWebCtx,
+	web_sys::{Element, Event, EventTarget, PointerEvent},
+};
+
+/// Creates a new server-side rendering context
+pub fn ctx() -> DynatosWebCtx {
+	let state = dynatos_web_ssr::State::new("http://localhost/".to_owned());
+	DynatosWebCtx::new(state).expect("Unable to create web context")
+}
+
+/// Runs `f` within a single-threaded tokio runtime
+pub fn run<F: Future>(f: F) -> F::Output {
+	tokio::runtime::Builder::new_current_thread()
+		.build()
+		.expect("Unable to create runtime")
+		.block_on(f)
+}
+
+/// Dispatches a click event to `el`.
+///
+/// In ssr, click listeners are dispatched through a form, so we find the
+/// handler through the form it's associated with.
+pub fn click(ctx: &DynatosWebCtx, el: &Element) {
+	let form_id = el.get_attribute("form").expect("Element has no click listener");
+	let handler_idx = form_id
+		.strip_prefix("dynatos-form-")
+		.and_then(|idx| idx.parse().ok())
+		.unwrap_or_else(|| panic!("Invalid form id: {form_id:?}"));
+
+	EventTarget::handle(ctx.ssr_state(), handler_idx, PointerEvent::default().into())
+		.expect("Unable to handle click event");
+}
+
+/// Dispatches the event `name` to `target`.
+///
+/// In ssr, events without a server-side equivalent are never fired, so
+/// they must be dispatched manually.
+pub fn dispatch(target: &EventTarget, name: &str, event: impl Into<Event>) {
+	target
+		.dispatch_event_with_name(name, &event.into())
+		.expect("Unable to dispatch event");
+}
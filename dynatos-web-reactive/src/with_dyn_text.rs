@@ -2,7 +2,7 @@
 
 // Imports
 use {
-	core::ops::Deref,
+	core::{fmt, ops::Deref},
 	dynatos_reactive::{Derived, Memo, Signal, SignalWith, WithDefault, derived::DerivedRun},
 	dynatos_sync_types::SyncBounds,
 	dynatos_web_router::{QuerySignal, query_signal::QueryParse},
@@ -13,7 +13,9 @@ use {
 /// This allows it to work with the following types:
 /// - `impl Fn() -> N`
 /// - `{str, &str, String}`
-/// - `Option<N>`
+/// - `{i8, i16, i32, i64, i128, isize}`, `{u8, u16, u32, u64, u128, usize}`, `{f32, f64}`, `{bool, char}`
+/// - [`DisplayText<T>`], for any `T: Display`
+/// - `Option<N>` (where `None` clears the text)
 /// - [`Signal`], [`Derived`], [`Memo`], [`WithDefault`]
 /// - `LazyCell<N, impl Fn() -> N>`
 ///
@@ -56,6 +58,42 @@ impl WithDynText for Ty {
 	}
 }
 
+#[duplicate::duplicate_item(
+	Ty;
+	[i8]; [i16]; [i32]; [i64]; [i128]; [isize];
+	[u8]; [u16]; [u32]; [u64]; [u128]; [usize];
+	[f32]; [f64];
+	[bool]; [char];
+)]
+impl WithDynText for Ty {
+	fn with_text<F, O>(&self, f: F) -> O
+	where
+		F: FnOnce(Option<&str>) -> O,
+	{
+		f(Some(&self.to_string()))
+	}
+}
+
+/// Dynamic text from any [`Display`](fmt::Display) type.
+///
+/// Since `WithDynText` can't be implemented for all `Display` types without
+/// conflicting with the other implementations, this wrapper allows using
+/// types that don't implement it directly, e.g. `move || DisplayText(path.get())`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DisplayText<T>(pub T);
+
+impl<T> WithDynText for DisplayText<T>
+where
+	T: SyncBounds + fmt::Display,
+{
+	fn with_text<F, O>(&self, f: F) -> O
+	where
+		F: FnOnce(Option<&str>) -> O,
+	{
+		f(Some(&self.0.to_string()))
+	}
+}
+
 impl<T> WithDynText for Option<T>
 where
 	T: WithDynText,
@@ -88,3 +126,57 @@ impl<Generics> WithDynText for Ty {
 		self.with(|text| text.with_text(f))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		core::net::Ipv4Addr,
+		dynatos_reactive::{SignalGet, SignalSet},
+	};
+
+	/// Returns the text of `text`, as an owned string
+	fn text_of<T: WithDynText>(text: &T) -> Option<String> {
+		text.with_text(|text| text.map(str::to_owned))
+	}
+
+	#[test]
+	fn signal_i32() {
+		let signal = Signal::new(5_i32);
+		assert_eq!(text_of(&signal).as_deref(), Some("5"));
+
+		signal.set(-12);
+		assert_eq!(text_of(&signal).as_deref(), Some("-12"));
+	}
+
+	#[test]
+	fn signal_f64() {
+		let signal = Signal::new(1.5_f64);
+		assert_eq!(text_of(&signal).as_deref(), Some("1.5"));
+
+		signal.set(-0.25);
+		assert_eq!(text_of(&signal).as_deref(), Some("-0.25"));
+	}
+
+	#[test]
+	fn signal_option_i32() {
+		let signal = Signal::new(Some(3_i32));
+		assert_eq!(text_of(&signal).as_deref(), Some("3"));
+
+		signal.set(None);
+		assert_eq!(text_of(&signal), None);
+	}
+
+	#[test]
+	fn fn_display() {
+		let signal = Signal::new(7_u8);
+		assert_eq!(text_of(&move || signal.get()).as_deref(), Some("7"));
+
+		let signal = Signal::new(Ipv4Addr::LOCALHOST);
+		assert_eq!(
+			text_of(&move || DisplayText(signal.get())).as_deref(),
+			Some("127.0.0.1")
+		);
+	}
+}
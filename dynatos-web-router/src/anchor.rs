@@ -2,8 +2,13 @@
 
 // Imports
 use {
+	crate::LocationSignal,
+	dynatos_reactive::{Effect, Memo, SignalBorrow, SignalGet},
 	dynatos_sync_types::SyncBounds,
-	dynatos_web::{DynatosWebCtx, ElementWithAttr, cfg_ssr_expr, html},
+	dynatos_util::TryOrReturnExt,
+	dynatos_web::{DynatosWebCtx, ElementToggleClass, ElementWithAttr, ObjectAttachValue, cfg_ssr_expr, html},
+	js_sys::WeakRef,
+	url::Url,
 };
 
 /// Creates a reactive anchor element.
@@ -23,8 +28,7 @@ where
 		},
 		csr = {
 			use {
-				crate::LocationSignal,
-				dynatos_reactive::{SignalBorrow, SignalSet},
+				dynatos_reactive::SignalSet,
 				dynatos_web::{EventTargetWithListener, ev},
 			};
 
@@ -43,3 +47,125 @@ where
 		}
 	)
 }
+
+/// Creates a reactive anchor element that has class `active_class`
+/// whenever the current location matches it.
+///
+/// See [`ActiveMatch`] for how the location is matched.
+///
+/// Expects a value of type [`LocationSignal`](crate::LocationSignal) in the context store.
+pub fn anchor_active<U, C>(
+	ctx: &DynatosWebCtx,
+	new_location: U,
+	active_class: C,
+	active_match: ActiveMatch,
+) -> web_sys::HtmlElement
+where
+	U: SyncBounds + AsRef<str> + 'static,
+	C: SyncBounds + AsRef<str> + 'static,
+{
+	let href = new_location.as_ref().to_owned();
+	let link = self::anchor(ctx, new_location);
+
+	let location = ctx.store().get::<LocationSignal>();
+	let is_active = Memo::new(move || active_match.is_active(&location.borrow(), &href));
+
+	// Note: It's important that we only keep a `WeakRef` to the link.
+	//       Otherwise, the link will be keeping us alive, while we keep
+	//       the link alive, causing a leak.
+	let link_weak = WeakRef::new(&link);
+	let class_effect = Effect::try_new(move || {
+		let link = link_weak.deref().or_return()?;
+		link.toggle_class(&active_class, is_active.get());
+	});
+	if let Some(class_effect) = class_effect {
+		link.attach_value(class_effect);
+	}
+
+	link
+}
+
+/// How to match the location for [`anchor_active`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ActiveMatch {
+	/// The location path must be the same as the anchor's.
+	Exact,
+
+	/// The location path must start with the anchor's.
+	///
+	/// Only entire segments are matched, so `/users` matches `/users/5`,
+	/// but not `/users5`.
+	Prefix,
+}
+
+impl ActiveMatch {
+	/// Returns if `href` is active for `location`
+	fn is_active(self, location: &Url, href: &str) -> bool {
+		let Ok(href) = location.join(href) else {
+			return false;
+		};
+		let href_path = href.path().trim_end_matches('/');
+		let location_path = location.path().trim_end_matches('/');
+
+		match self {
+			Self::Exact => location_path == href_path,
+			Self::Prefix => location_path
+				.strip_prefix(href_path)
+				.is_some_and(|rest| rest.is_empty() || rest.starts_with('/')),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	#[cfg(feature = "ssr")]
+	use {crate::test_util, dynatos_reactive::SignalBorrowMut};
+
+	/// Returns if `href` is active with `active_match` when at `location`
+	fn is_active(active_match: ActiveMatch, location: &str, href: &str) -> bool {
+		let location = Url::parse(&format!("http://localhost{location}")).expect("Invalid url");
+		active_match.is_active(&location, href)
+	}
+
+	#[test]
+	fn exact() {
+		assert!(is_active(ActiveMatch::Exact, "/test", "/test"));
+		assert!(is_active(ActiveMatch::Exact, "/test/", "/test"));
+		assert!(is_active(ActiveMatch::Exact, "/test?a=5", "/test"));
+		assert!(!is_active(ActiveMatch::Exact, "/test/5", "/test"));
+		assert!(!is_active(ActiveMatch::Exact, "/other", "/test"));
+	}
+
+	#[test]
+	fn prefix() {
+		assert!(is_active(ActiveMatch::Prefix, "/users", "/users"));
+		assert!(is_active(ActiveMatch::Prefix, "/users/5", "/users"));
+		assert!(!is_active(ActiveMatch::Prefix, "/users5", "/users"));
+		assert!(!is_active(ActiveMatch::Prefix, "/other", "/users"));
+	}
+
+	#[test]
+	fn relative() {
+		assert!(is_active(ActiveMatch::Exact, "/users/5", "5"));
+		assert!(!is_active(ActiveMatch::Exact, "/users/5", "6"));
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	fn active_class() {
+		let (ctx, location) = test_util::ctx("http://localhost/users");
+		let link = anchor_active(&ctx, "/users", "active", ActiveMatch::Prefix);
+		assert_eq!(link.class_name(), "active");
+
+		*location.borrow_mut() = Url::parse("http://localhost/users/5").expect("Invalid url");
+		assert_eq!(link.class_name(), "active");
+
+		*location.borrow_mut() = Url::parse("http://localhost/other").expect("Invalid url");
+		assert_eq!(link.class_name(), "");
+
+		*location.borrow_mut() = Url::parse("http://localhost/users").expect("Invalid url");
+		assert_eq!(link.class_name(), "active");
+	}
+}
@@ -13,13 +13,24 @@
 mod anchor;
 pub mod location;
 pub mod query_signal;
+pub mod route;
+mod scroll;
+#[cfg(all(test, feature = "ssr"))]
+mod test_util;
 
 // Exports
+#[cfg(feature = "serde")]
+pub use self::query_signal::SerdeQuery;
 pub use self::{
-	anchor::anchor,
-	location::LocationSignal,
+	anchor::{ActiveMatch, anchor, anchor_active},
+	location::{LocationSignal, RouterMode},
 	query_signal::{MultiQuery, QuerySignal, SingleQuery},
+	route::{Params, Route},
+	scroll::ScrollBehavior,
 };
 
 // Imports
 use js_sys as _;
+// Note: `dynatos-web-ssr` is only used by the ssr tests
+#[cfg(feature = "ssr")]
+use dynatos_web_ssr as _;
@@ -2,20 +2,96 @@
 
 // Imports
 use {
-	core::ops::{Deref, DerefMut},
+	crate::{
+		QuerySignal,
+		ScrollBehavior,
+		SingleQuery,
+		query_signal,
+		scroll::{ScrollHistory, ScrollPos, StateId},
+	},
+	core::{
+		ops::{Deref, DerefMut},
+		str::FromStr,
+	},
 	dynatos_reactive::{Signal, SignalBorrow, SignalBorrowMut, signal},
+	dynatos_sync_types::{RcPtr, SyncBounds},
 	dynatos_web::{DynatosWebCtx, EventTargetAddListener, ev},
 	url::Url,
 	wasm_bindgen::JsValue,
+	web_sys::{HashChangeEvent, PopStateEvent},
 	zutil_cloned::cloned,
 };
 
+/// Router mode
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum RouterMode {
+	/// Uses the path of the url, with the history api.
+	#[default]
+	History,
+
+	/// Uses the fragment of the url, `#/path?query`.
+	///
+	/// Useful when hosting static files, since the server only ever sees
+	/// the path of the page itself.
+	///
+	/// The location then has the path and query of the fragment, so routes and
+	/// queries work as-is. Keep in mind that the `href` attributes of anchors are
+	/// still the location, so they only work when clicked.
+	Hash,
+}
+
+impl RouterMode {
+	/// Returns the location for the page url `page_url`
+	fn location_url(self, page_url: Url) -> Url {
+		match self {
+			Self::History => page_url,
+			Self::Hash => {
+				let fragment = page_url.fragment().unwrap_or_default();
+				let fragment = fragment.trim_start_matches('/');
+				page_url
+					.join(&format!("/{fragment}"))
+					.expect("Joining an absolute path should be valid")
+			},
+		}
+	}
+
+	/// Returns the page url for the location `location`, given the current page url `page_url`
+	fn page_url(self, mut page_url: Url, location: &Url) -> Url {
+		match self {
+			Self::History => location.clone(),
+			Self::Hash => {
+				let mut fragment = location.path().to_owned();
+				if let Some(query) = location.query() {
+					fragment.push('?');
+					fragment.push_str(query);
+				}
+				if let Some(location_fragment) = location.fragment() {
+					fragment.push('#');
+					fragment.push_str(location_fragment);
+				}
+
+				page_url.set_fragment(Some(&fragment));
+				page_url
+			},
+		}
+	}
+}
+
 /// Inner
 #[derive(Debug)]
 struct Inner {
 	/// Location
 	location: Url,
 
+	/// Router mode
+	mode: RouterMode,
+
+	/// Scroll behavior
+	scroll_behavior: ScrollBehavior,
+
+	/// Scroll history
+	scroll_history: ScrollHistory,
+
 	ctx: DynatosWebCtx,
 }
 
@@ -29,23 +105,121 @@ impl LocationSignal {
 	#[must_use]
 	#[track_caller]
 	pub fn new(ctx: &DynatosWebCtx) -> Self {
-		let location = self::parse_location_url(ctx);
+		Self::new_with_scroll_behavior(ctx, ScrollBehavior::default())
+	}
+
+	/// Creates a new location using the fragment of the url.
+	///
+	/// See [`RouterMode::Hash`] for details.
+	#[must_use]
+	#[track_caller]
+	pub fn new_hash(ctx: &DynatosWebCtx) -> Self {
+		Self::new_with(ctx, RouterMode::Hash, ScrollBehavior::default())
+	}
+
+	/// Creates a new location with a scroll behavior
+	#[must_use]
+	#[track_caller]
+	pub fn new_with_scroll_behavior(ctx: &DynatosWebCtx, scroll_behavior: ScrollBehavior) -> Self {
+		Self::new_with(ctx, RouterMode::default(), scroll_behavior)
+	}
+
+	/// Creates a new location with a router mode and scroll behavior
+	#[must_use]
+	#[track_caller]
+	pub fn new_with(ctx: &DynatosWebCtx, mode: RouterMode, scroll_behavior: ScrollBehavior) -> Self {
+		let location = mode.location_url(self::parse_page_url(ctx));
 		let inner = Inner {
 			location,
+			mode,
+			scroll_behavior,
+			scroll_history: ScrollHistory::new(),
 			ctx: ctx.clone(),
 		};
 		let inner = Signal::new(inner);
 
 		// Add an event listener on the document for when the user navigates manually
 		#[cloned(ctx, inner)]
-		let update = move |_ev| {
-			let new_location = self::parse_location_url(&ctx);
-			inner.borrow_mut().location = new_location;
+		let update = move |state: JsValue| {
+			let new_location = mode.location_url(self::parse_page_url(&ctx));
+			let mut inner = inner.borrow_mut();
+			let path_changed = inner.location.path() != new_location.path();
+			inner.location = new_location;
+
+			let scroll_pos = match inner.scroll_behavior {
+				ScrollBehavior::Auto => {
+					let cur_pos = ScrollPos::current(&ctx);
+					let id = StateId::from_state(&state);
+					Some(inner.scroll_history.pop(id, cur_pos).unwrap_or_default())
+				},
+				ScrollBehavior::Manual => None,
+				ScrollBehavior::Top => Some(ScrollPos::default()),
+			};
+
+			// Note: We only scroll after updating everyone, so the page is already rendered.
+			//       We also don't scroll if only the query or fragment changed, since we're
+			//       still on the same page.
+			drop(inner);
+			if let Some(scroll_pos) = scroll_pos &&
+				path_changed
+			{
+				scroll_pos.scroll_to(&ctx);
+			}
 		};
-		ctx.window().add_event_listener::<ev!(popstate)>(ctx, update);
+		match mode {
+			RouterMode::History => {
+				ctx.window()
+					.add_event_listener::<ev!(popstate)>(ctx, move |ev: PopStateEvent| update(ev.state()));
+			},
+			// Note: Changing the fragment fires both `popstate` and `hashchange`, so we
+			//       only listen to the latter, to avoid updating twice.
+			RouterMode::Hash => {
+				#[cloned(ctx)]
+				let update = move |_: HashChangeEvent| {
+					let state = ctx.history().state().expect("Unable to get history state");
+					update(state);
+				};
+				ctx.window().add_event_listener::<ev!(hashchange)>(ctx, update);
+			},
+		}
 
 		Self(inner)
 	}
+
+	/// Creates a query signal for a single value with key `key`.
+	///
+	/// This is a shorthand for `QuerySignal::new(ctx, SingleQuery::new(ctx, key))`.
+	///
+	/// # Panics
+	/// The query signal accesses the location through the context store, so
+	/// this panics if there's no [`LocationSignal`] in it.
+	#[track_caller]
+	pub fn query<T>(&self, key: impl Into<RcPtr<str>>) -> QuerySignal<SingleQuery<T>>
+	where
+		T: SyncBounds + FromStr + 'static,
+		T::Err: SyncBounds + 'static,
+	{
+		let ctx = self.0.borrow_no_dep().ctx.clone();
+		QuerySignal::new(&ctx, SingleQuery::new(&ctx, key))
+	}
+
+	/// Returns the current location, with the query `key` set to `value`.
+	///
+	/// Any existing values of `key` are replaced, and all other queries are kept.
+	/// The returned url may be used with [`anchor`](crate::anchor).
+	#[must_use]
+	pub fn with_query(&self, key: &str, value: &str) -> String {
+		self::url_with_query(&self.borrow(), key, value).into()
+	}
+
+	/// Returns the current location, without the query `key`.
+	///
+	/// All other queries are kept.
+	/// The returned url may be used with [`anchor`](crate::anchor).
+	#[must_use]
+	pub fn without_query(&self, key: &str) -> String {
+		self::url_without_query(&self.borrow(), key).into()
+	}
 }
 
 /// Reference type for [`SignalBorrow`] impl
@@ -94,16 +268,39 @@ impl Drop for BorrowRefMut<'_> {
 		// Note: We need to drop the borrow *before* pushing the url because with
 		//       SSR, changing the url immediately calls any events, which can try
 		//       to borrow us and deadlock.
-		let borrow = self.0.take().expect("Should exist");
-		let history = borrow.ctx.history().clone();
-		let location = borrow.location.clone();
-		let _exec_guard = borrow.into_trigger_exec();
+		let mut borrow = self.0.take().expect("Should exist");
+		let ctx = borrow.ctx.clone();
+		let page_url = self::parse_page_url(&ctx);
+		let path_changed = borrow.mode.location_url(page_url.clone()).path() != borrow.location.path();
+		let location = borrow.mode.page_url(page_url, &borrow.location);
+		let scroll_behavior = borrow.scroll_behavior;
+
+		// Note: If only the query or fragment changed, we're still on the same page, so
+		//       we keep the current history entry's id and don't scroll.
+		let state = match scroll_behavior {
+			ScrollBehavior::Auto => match path_changed {
+				true => {
+					let cur_pos = ScrollPos::current(&ctx);
+					borrow.scroll_history.push(cur_pos).to_state()
+				},
+				false => borrow.scroll_history.cur_id().to_state(),
+			},
+			ScrollBehavior::Manual | ScrollBehavior::Top => JsValue::UNDEFINED,
+		};
+		let exec_guard = borrow.into_trigger_exec();
 
 		// Push the new location into history
-		match history.push_state_with_url(&JsValue::UNDEFINED, "", Some(location.as_str())) {
+		match ctx.history().push_state_with_url(&state, "", Some(location.as_str())) {
 			Ok(()) => tracing::debug!("Pushed history: {:?}", location.as_str()),
 			Err(err) => tracing::error!("Unable to push history {:?}: {err:?}", location.as_str()),
 		}
+
+		// Then scroll to the top of the new page, after updating everyone
+		drop(exec_guard);
+		match scroll_behavior {
+			ScrollBehavior::Auto | ScrollBehavior::Top if path_changed => ScrollPos::default().scroll_to(&ctx),
+			ScrollBehavior::Auto | ScrollBehavior::Top | ScrollBehavior::Manual => (),
+		}
 	}
 }
 
@@ -119,8 +316,178 @@ impl SignalBorrowMut for LocationSignal {
 	}
 }
 
-/// Parses the location as url
-fn parse_location_url(ctx: &DynatosWebCtx) -> Url {
+/// Parses the page location as url
+fn parse_page_url(ctx: &DynatosWebCtx) -> Url {
 	let location = ctx.location().href().expect("Unable to get location href");
 	location.parse::<Url>().expect("Location href was an invalid url")
 }
+
+/// Returns `url` with the query `key` set to `value`
+fn url_with_query(url: &Url, key: &str, value: &str) -> Url {
+	let mut url = url.clone();
+	query_signal::write_query_values(&mut url, key, [value.to_owned()]);
+	url
+}
+
+/// Returns `url` without the query `key`
+fn url_without_query(url: &Url, key: &str) -> Url {
+	let mut url = url.clone();
+	query_signal::write_query_values(&mut url, key, []);
+	url
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	#[cfg(feature = "ssr")]
+	use {crate::test_util, dynatos_loadable::Loadable};
+
+	/// Base url, with multiple queries
+	const BASE_URL: &str = "http://localhost/items?page=2&tag=a&sort=name&tag=b#top";
+
+	/// Parses the base url
+	fn base_url() -> Url {
+		Url::parse(BASE_URL).expect("Invalid url")
+	}
+
+	#[test]
+	fn hash_location() {
+		let page_url =
+			Url::parse("http://localhost/app/index.html?v=1#/users/5?tab=posts&page=2").expect("Invalid url");
+		let location = RouterMode::Hash.location_url(page_url);
+		assert_eq!(location.path(), "/users/5");
+		assert_eq!(location.query(), Some("tab=posts&page=2"));
+		assert_eq!(query_signal::query_values(&location, "page"), ["2"]);
+
+		for page_url in [
+			"http://localhost/app/",
+			"http://localhost/app/#",
+			"http://localhost/app/#/",
+		] {
+			let location = RouterMode::Hash.location_url(Url::parse(page_url).expect("Invalid url"));
+			assert_eq!(location.path(), "/");
+			assert_eq!(location.query(), None);
+		}
+	}
+
+	#[test]
+	fn hash_location_change() {
+		let mut page_url = Url::parse("http://localhost/app/#/users").expect("Invalid url");
+		assert_eq!(RouterMode::Hash.location_url(page_url.clone()).path(), "/users");
+
+		page_url.set_fragment(Some("/posts/3?sort=new"));
+		let location = RouterMode::Hash.location_url(page_url);
+		assert_eq!(location.path(), "/posts/3");
+		assert_eq!(query_signal::query_values(&location, "sort"), ["new"]);
+	}
+
+	#[test]
+	fn hash_page_url() {
+		let page_url = Url::parse("http://localhost/app/index.html?v=1#/users").expect("Invalid url");
+		let location = RouterMode::Hash.location_url(page_url.clone());
+		let location = url_with_query(&location.join("/posts/3").expect("Invalid url"), "sort", "new");
+
+		let page_url = RouterMode::Hash.page_url(page_url, &location);
+		assert_eq!(
+			page_url.as_str(),
+			"http://localhost/app/index.html?v=1#/posts/3?sort=new"
+		);
+		assert_eq!(RouterMode::Hash.location_url(page_url), location);
+	}
+
+	#[test]
+	fn history_page_url() {
+		let page_url = Url::parse("http://localhost/users?a=1").expect("Invalid url");
+		let location = RouterMode::History.location_url(page_url.clone());
+		assert_eq!(location, page_url);
+
+		let location = location.join("/posts").expect("Invalid url");
+		assert_eq!(RouterMode::History.page_url(page_url, &location), location);
+	}
+
+	#[test]
+	fn with_query_add() {
+		let url = url_with_query(&base_url(), "filter", "new items");
+		assert_eq!(
+			url.as_str(),
+			"http://localhost/items?page=2&tag=a&sort=name&tag=b&filter=new+items#top"
+		);
+	}
+
+	#[test]
+	fn with_query_overwrite() {
+		let url = url_with_query(&base_url(), "page", "3");
+		assert_eq!(url.as_str(), "http://localhost/items?page=3&tag=a&sort=name&tag=b#top");
+
+		let url = url_with_query(&base_url(), "tag", "c");
+		assert_eq!(url.as_str(), "http://localhost/items?page=2&tag=c&sort=name#top");
+	}
+
+	#[test]
+	fn without_query() {
+		let url = url_without_query(&base_url(), "tag");
+		assert_eq!(url.as_str(), "http://localhost/items?page=2&sort=name#top");
+
+		let url = url_without_query(&base_url(), "missing");
+		assert_eq!(url.as_str(), BASE_URL);
+
+		let url = url_without_query(
+			&Url::parse("http://localhost/items?page=2").expect("Invalid url"),
+			"page",
+		);
+		assert_eq!(url.as_str(), "http://localhost/items");
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	#[expect(clippy::float_cmp, reason = "We only compare against positions we scrolled to")]
+	fn scroll_query_only() {
+		for scroll_behavior in [ScrollBehavior::Auto, ScrollBehavior::Top] {
+			let (ctx, _) = test_util::ctx("http://localhost/items?page=1");
+			let location = LocationSignal::new_with_scroll_behavior(&ctx, scroll_behavior);
+			let window = ctx.window();
+
+			// Changing only the query keeps the scroll position
+			window.scroll_to_with_x_and_y(0.0, 100.0);
+			let url = location.with_query("page", "2");
+			*location.borrow_mut() = Url::parse(&url).expect("Invalid url");
+			assert_eq!(window.scroll_y().expect("Unable to get scroll"), 100.0);
+
+			// But changing the path scrolls to the top
+			*location.borrow_mut() = Url::parse("http://localhost/users").expect("Invalid url");
+			assert_eq!(window.scroll_y().expect("Unable to get scroll"), 0.0);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	fn hash_change() {
+		let (ctx, _) = test_util::ctx("http://localhost/app/#/users");
+		let location = LocationSignal::new_hash(&ctx);
+		assert_eq!(location.borrow().path(), "/users");
+
+		ctx.location()
+			.assign("http://localhost/app/#/posts/3?sort=new".to_owned())
+			.expect("Unable to assign location");
+		ctx.history().update_hash_listeners();
+		assert_eq!(location.borrow().path(), "/posts/3");
+		assert_eq!(location.borrow().query(), Some("sort=new"));
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	fn query() {
+		let (_ctx, location) = test_util::ctx("http://localhost/items?page=2&tag=a");
+		let page = location.query::<u32>("page");
+		let tag = location.query::<u32>("tag");
+		let missing = location.query::<u32>("missing");
+		assert_eq!(*page.borrow(), Loadable::Loaded(2));
+		assert!(matches!(*tag.borrow(), Loadable::Err(_)));
+		assert_eq!(*missing.borrow(), Loadable::Empty);
+
+		let url = location.with_query("page", "3");
+		*location.borrow_mut() = Url::parse(&url).expect("Invalid url");
+		assert_eq!(*page.borrow(), Loadable::Loaded(3));
+	}
+}
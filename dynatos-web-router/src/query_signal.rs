@@ -2,9 +2,13 @@
 
 // Modules
 pub mod multi_query;
+#[cfg(feature = "serde")]
+pub mod serde_query;
 pub mod single_query;
 
 // Exports
+#[cfg(feature = "serde")]
+pub use self::serde_query::SerdeQuery;
 pub use self::{multi_query::MultiQuery, single_query::SingleQuery};
 
 // Imports
@@ -29,23 +33,25 @@ use {
 	},
 	dynatos_sync_types::{RcPtr, SyncBounds},
 	dynatos_web::DynatosWebCtx,
+	url::Url,
 	zutil_cloned::cloned,
 };
 
 /// Query signal.
 ///
 /// # Bind
-/// When creating a query signal, it will be bound to the current
-/// url's path, and if the path changes, the query signal won't attempt
-/// to update the value.
+/// When creating a query signal with [`QuerySignal::new`], it will be bound
+/// to the current url's path, and if the path changes, the query signal won't
+/// attempt to update the value.
+///
+/// If the query should keep tracking the url across paths, use
+/// [`QuerySignal::new_unbound`] instead.
 pub struct QuerySignal<T: QueryParse + 'static> {
 	/// Query
 	query: RcPtr<T>,
 
 	/// Bound url path for this query.
-	// TODO: Should this be optional so the user can opt out of
-	//       binding a unique path against the query signal?
-	location_path: RcPtr<str>,
+	location_path: Option<RcPtr<str>>,
 
 	/// Inner value
 	inner: Signal<Option<T::Value>>,
@@ -55,31 +61,63 @@ pub struct QuerySignal<T: QueryParse + 'static> {
 }
 
 impl<T: QueryParse> QuerySignal<T> {
-	/// Creates a new query signal with `query`.
+	/// Creates a new query signal with `query`, bound to the current path.
 	///
 	/// Requires a value of type [`LocationSignal`](crate::LocationSignal) in the context store.
 	#[track_caller]
-	#[define_opaque(UpdateEffect)]
 	pub fn new(ctx: &DynatosWebCtx, query: T) -> Self
 	where
 		T: 'static,
 		T::Value: 'static,
 	{
-		let query = RcPtr::new(query);
-
 		// Note: This access must skip dependencies to ensure that the query signal itself
 		//       doesn't change whenever the location changes, and only it's value does.
 		let location = ctx.store().get::<LocationSignal>();
 		let location_path = RcPtr::<str>::from(location.borrow_no_dep().path());
 
+		Self::new_inner(ctx, query, Some(location_path))
+	}
+
+	/// Creates a new query signal with `query`, that isn't bound to any path.
+	///
+	/// Unlike [`QuerySignal::new`], this will keep re-parsing the query
+	/// whenever the location changes, even if the path changes.
+	///
+	/// This is useful for queries shared across routes, but keep in mind
+	/// that the value will change when navigating to a path that doesn't
+	/// use the query.
+	///
+	/// Requires a value of type [`LocationSignal`](crate::LocationSignal) in the context store.
+	#[track_caller]
+	pub fn new_unbound(ctx: &DynatosWebCtx, query: T) -> Self
+	where
+		T: 'static,
+		T::Value: 'static,
+	{
+		Self::new_inner(ctx, query, None)
+	}
+
+	/// Inner function for [`QuerySignal::new`] and [`QuerySignal::new_unbound`]
+	#[track_caller]
+	#[define_opaque(UpdateEffect)]
+	fn new_inner(ctx: &DynatosWebCtx, query: T, location_path: Option<RcPtr<str>>) -> Self
+	where
+		T: 'static,
+		T::Value: 'static,
+	{
+		let query = RcPtr::new(query);
+		let location = ctx.store().get::<LocationSignal>();
+
 		let inner = Signal::new(None);
 		#[cloned(query, location_path, inner)]
 		let update = Effect::new(move || {
-			// If the location changes, don't update.
+			// If we're bound and the location changes, don't update.
 			// Note: If this is the correct location, we don't want to add the location
 			//       as a dependency, because otherwise we'll be re-parsing all queries
 			//       each time the location changes and not just when `T::parse` changes.
-			if *location.borrow_no_dep().path() != *location_path {
+			if let Some(location_path) = &location_path &&
+				*location.borrow_no_dep().path() != **location_path
+			{
 				_ = location.borrow();
 				return;
 			}
@@ -109,7 +147,7 @@ impl<T: QueryParse> Clone for QuerySignal<T> {
 	fn clone(&self) -> Self {
 		Self {
 			query:         RcPtr::clone(&self.query),
-			location_path: RcPtr::clone(&self.location_path),
+			location_path: self.location_path.clone(),
 			inner:         self.inner.clone(),
 			update_effect: self.update_effect.clone(),
 		}
@@ -312,11 +350,136 @@ type QueriesFn = impl Fn() -> Vec<String>;
 #[define_opaque(QueriesFn)]
 fn queries_memo(ctx: &DynatosWebCtx, key: RcPtr<str>) -> Memo<Vec<String>, QueriesFn> {
 	let location = ctx.store().get::<LocationSignal>();
-	Memo::new(move || {
-		location
-			.borrow()
-			.query_pairs()
-			.filter_map(|(query, value)| (query == *key).then_some(value.into_owned()))
-			.collect::<Vec<_>>()
-	})
+	Memo::new(move || self::query_values(&location.borrow(), &key))
+}
+
+/// Returns all values of the query `key` in `url`
+pub(crate) fn query_values(url: &Url, key: &str) -> Vec<String> {
+	url.query_pairs()
+		.filter_map(|(query, value)| (query == key).then_some(value.into_owned()))
+		.collect()
+}
+
+/// Writes `values` as the values of the query `key` in `url`.
+///
+/// The values are written where the first query `key` was, and any
+/// other queries `key` are removed. If there were none, the values are
+/// added at the end. The other queries are kept as-is.
+pub(crate) fn write_query_values<I>(url: &mut Url, key: &str, values: I)
+where
+	I: IntoIterator<Item = String>,
+{
+	self::write_query_pairs(
+		url,
+		|query| query == key,
+		values.into_iter().map(|value| (key.to_owned(), value)),
+	);
+}
+
+/// Writes `pairs` in place of all queries in `url` for which `is_ours` returns true.
+///
+/// The pairs are written where the first of our queries was, and any
+/// other ones are removed. If there were none, the pairs are added at
+/// the end. The other queries are kept as-is.
+pub(crate) fn write_query_pairs<I>(url: &mut Url, is_ours: impl Fn(&str) -> bool, pairs: I)
+where
+	I: IntoIterator<Item = (String, String)>,
+{
+	let mut pairs = Some(pairs);
+	let mut queries = vec![];
+	for (query, value) in url.query_pairs().into_owned() {
+		// If it's another query, keep it
+		if !is_ours(&query) {
+			queries.push((query, value));
+			continue;
+		}
+
+		// If it's ours, add all pairs, if we haven't already.
+		// Note: Otherwise, this is a duplicate, so skip it
+		if let Some(pairs) = pairs.take() {
+			queries.extend(pairs);
+		}
+	}
+
+	// If we haven't added ours yet by now, add them at the end
+	if let Some(pairs) = pairs {
+		queries.extend(pairs);
+	}
+
+	// Note: If there are no queries left, remove the query entirely, instead of leaving a `?`.
+	match queries.is_empty() {
+		true => url.set_query(None),
+		false => _ = url.query_pairs_mut().clear().extend_pairs(queries),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	#[cfg(feature = "ssr")]
+	use {crate::test_util, dynatos_loadable::Loadable};
+
+	/// Parses `url` relative to a base url
+	fn url(url: &str) -> Url {
+		Url::parse(&format!("http://localhost{url}")).expect("Invalid url")
+	}
+
+	#[test]
+	fn multi_read() {
+		let url = self::url("/items?k=1&a=x&k=2&k=3");
+		assert_eq!(query_values(&url, "k"), ["1", "2", "3"]);
+		assert_eq!(query_values(&url, "a"), ["x"]);
+		assert!(query_values(&url, "b").is_empty());
+	}
+
+	#[test]
+	fn multi_write_back() {
+		let mut url = self::url("/items?a=x&k=1&b=y&k=2&k=3");
+
+		let mut values = query_values(&url, "k");
+		values.retain(|value| value != "2");
+		values.push("4".to_owned());
+		write_query_values(&mut url, "k", values);
+
+		assert_eq!(url.query(), Some("a=x&k=1&k=3&k=4&b=y"));
+		assert_eq!(query_values(&url, "k"), ["1", "3", "4"]);
+	}
+
+	#[test]
+	fn multi_write_new_key() {
+		let mut url = self::url("/items?a=x");
+		write_query_values(&mut url, "k", ["1", "2"].map(str::to_owned));
+		assert_eq!(url.query(), Some("a=x&k=1&k=2"));
+	}
+
+	#[test]
+	fn multi_write_empty() {
+		let mut url = self::url("/items?k=1&a=x&k=2");
+		write_query_values(&mut url, "k", []);
+		assert_eq!(url.query(), Some("a=x"));
+
+		let mut url = self::url("/items?k=1&k=2");
+		write_query_values(&mut url, "k", []);
+		assert_eq!(url.query(), None);
+		assert_eq!(url.as_str(), "http://localhost/items");
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	fn unbound_across_paths() {
+		let (ctx, location) = test_util::ctx("http://localhost/a?page=1");
+		let bound = QuerySignal::new(&ctx, SingleQuery::<u32>::new(&ctx, "page"));
+		let unbound = QuerySignal::new_unbound(&ctx, SingleQuery::<u32>::new(&ctx, "page"));
+		assert_eq!(*bound.borrow(), Loadable::Loaded(1));
+		assert_eq!(*unbound.borrow(), Loadable::Loaded(1));
+
+		*location.borrow_mut() = self::url("/b?page=2");
+		assert_eq!(*bound.borrow(), Loadable::Loaded(1));
+		assert_eq!(*unbound.borrow(), Loadable::Loaded(2));
+
+		*location.borrow_mut() = self::url("/a?page=3");
+		assert_eq!(*bound.borrow(), Loadable::Loaded(3));
+		assert_eq!(*unbound.borrow(), Loadable::Loaded(3));
+	}
 }
@@ -103,43 +103,10 @@ impl<T: FromStr<Err: StdError> + ToString> QueryWrite<&[T]> for MultiQuery<T> {
 		let _suppress_queries = self.queries.suppress();
 		self.queries.update_no_run(new_value.iter().map(T::to_string).collect());
 
+		// Note: If `new_value` is empty, this removes all of our queries.
 		let location = self.ctx.store().get::<LocationSignal>();
 		let mut location = location.borrow_mut();
-		let mut added_query = false;
-		let mut queries = vec![];
-		for (key, value) in location.query_pairs().into_owned() {
-			// If it's another key, keep it
-			if key != *self.key {
-				queries.push((key, value));
-				continue;
-			}
-
-			// If we already added our query, this is a duplicate, so skip it
-			if added_query {
-				continue;
-			}
-
-			// If it's our key, add all values
-			added_query = true;
-			queries.extend(
-				new_value
-					.iter()
-					.map(T::to_string)
-					.map(|value| (self.key.to_string(), value)),
-			);
-		}
-
-		// If we haven't added ours yet by now, add it at the end
-		if !added_query {
-			queries.extend(
-				new_value
-					.iter()
-					.map(T::to_string)
-					.map(|value| (self.key.to_string(), value)),
-			);
-		}
-
-		location.query_pairs_mut().clear().extend_pairs(queries);
+		super::write_query_values(&mut location, &self.key, new_value.iter().map(T::to_string));
 	}
 }
 
@@ -0,0 +1,261 @@
+//! Serde query
+
+// Imports
+use {
+	super::{QueryIntoValue, QueryParse, QueryWrite},
+	crate::LocationSignal,
+	core::marker::PhantomData,
+	dynatos_loadable::Loadable,
+	dynatos_reactive::{Memo, SignalBorrow, SignalBorrowMut},
+	dynatos_sync_types::{RcPtr, SyncBounds},
+	dynatos_web::DynatosWebCtx,
+	serde::{Serialize, de::DeserializeOwned},
+	serde_urlencoded::{de::Error as DeError, ser::Error as SerError},
+	url::{Url, form_urlencoded},
+};
+
+/// Parses a value from the query using `serde`.
+///
+/// Each field of the value is stored in the query as `key[field]=value`.
+///
+/// Requires a value of type [`LocationSignal`](crate::LocationSignal) in the context store.
+pub struct SerdeQuery<T> {
+	/// The key to this query
+	key: RcPtr<str>,
+
+	/// Fields with our key
+	fields: Memo<Vec<(String, String)>, FieldsFn>,
+
+	ctx:      DynatosWebCtx,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> SerdeQuery<T> {
+	/// Creates a new query
+	pub fn new(ctx: &DynatosWebCtx, key: impl Into<RcPtr<str>>) -> Self {
+		let key = key.into();
+		Self {
+			key:      RcPtr::clone(&key),
+			fields:   self::fields_memo(ctx, key),
+			ctx:      ctx.clone(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Returns the key to this query
+	#[must_use]
+	pub fn key(&self) -> &str {
+		&self.key
+	}
+}
+
+impl<T> Clone for SerdeQuery<T> {
+	fn clone(&self) -> Self {
+		Self {
+			key:      RcPtr::clone(&self.key),
+			fields:   self.fields.clone(),
+			ctx:      self.ctx.clone(),
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<T> QueryParse for SerdeQuery<T>
+where
+	T: SyncBounds + DeserializeOwned,
+{
+	type Value = Loadable<T, DeError>;
+
+	fn parse(&self) -> Self::Value {
+		let fields = self.fields.borrow();
+		if fields.is_empty() {
+			return Loadable::Empty;
+		}
+
+		self::parse_fields(&fields).into()
+	}
+}
+
+impl<T> QueryIntoValue<T> for SerdeQuery<T>
+where
+	T: SyncBounds + DeserializeOwned,
+{
+	fn into_query_value(value: T) -> Self::Value {
+		Loadable::Loaded(value)
+	}
+}
+
+impl<T> QueryWrite<&'_ Loadable<T, DeError>> for SerdeQuery<T>
+where
+	T: SyncBounds + DeserializeOwned + Serialize,
+{
+	fn write(&self, new_value: &Loadable<T, DeError>) {
+		match new_value {
+			Loadable::Empty | Loadable::Loading => self.write(None),
+			Loadable::Err(err) => tracing::warn!(?self.key, ?err, "Cannot assign an error to a query value"),
+			Loadable::Loaded(new_value) => self.write(Some(new_value)),
+		}
+	}
+}
+
+impl<T: Serialize> QueryWrite<Option<&'_ T>> for SerdeQuery<T> {
+	fn write(&self, new_value: Option<&T>) {
+		let new_fields = match new_value.map(self::serialize_fields).transpose() {
+			Ok(new_fields) => new_fields.unwrap_or_default(),
+			Err(err) => {
+				tracing::warn!(?self.key, ?err, "Unable to serialize query value");
+				return;
+			},
+		};
+
+		// Update our fields memo manually and prevent it from being added
+		let _suppress_fields = self.fields.suppress();
+		self.fields.update_no_run(new_fields.clone());
+
+		let location = self.ctx.store().get::<LocationSignal>();
+		self::write_fields(&mut location.borrow_mut(), &self.key, new_fields);
+	}
+}
+
+/// Parses a value from its fields
+fn parse_fields<T: DeserializeOwned>(fields: &[(String, String)]) -> Result<T, DeError> {
+	let fields = form_urlencoded::Serializer::new(String::new())
+		.extend_pairs(fields)
+		.finish();
+	serde_urlencoded::from_str(&fields)
+}
+
+/// Serializes a value into its fields
+fn serialize_fields<T: Serialize>(value: &T) -> Result<Vec<(String, String)>, SerError> {
+	let fields = serde_urlencoded::to_string(value)?;
+	let fields = form_urlencoded::parse(fields.as_bytes()).into_owned().collect();
+	Ok(fields)
+}
+
+/// Writes the fields of `key` into `url`, replacing any existing ones
+fn write_fields(url: &mut Url, key: &str, new_fields: Vec<(String, String)>) {
+	super::write_query_pairs(
+		url,
+		|query| self::field_name(key, query).is_some(),
+		new_fields
+			.into_iter()
+			.map(|(field, value)| (format!("{key}[{field}]"), value)),
+	);
+}
+
+/// Returns the field name of `query`, if it's a field of `key`.
+fn field_name<'a>(key: &str, query: &'a str) -> Option<&'a str> {
+	query.strip_prefix(key)?.strip_prefix('[')?.strip_suffix(']')
+}
+
+type FieldsFn = impl Fn() -> Vec<(String, String)>;
+
+#[define_opaque(FieldsFn)]
+fn fields_memo(ctx: &DynatosWebCtx, key: RcPtr<str>) -> Memo<Vec<(String, String)>, FieldsFn> {
+	let location = ctx.store().get::<LocationSignal>();
+	Memo::new(move || {
+		location
+			.borrow()
+			.query_pairs()
+			.filter_map(|(query, value)| {
+				let field = self::field_name(&key, &query)?;
+				Some((field.to_owned(), value.into_owned()))
+			})
+			.collect::<Vec<_>>()
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	#[cfg(feature = "ssr")]
+	use {
+		crate::{QuerySignal, test_util},
+		dynatos_reactive::SignalSet,
+	};
+
+	#[derive(PartialEq, Debug)]
+	#[derive(serde::Serialize, serde::Deserialize)]
+	struct Filters {
+		page:   usize,
+		search: String,
+	}
+
+	/// Returns all fields of `key` in `url`
+	fn fields(url: &Url, key: &str) -> Vec<(String, String)> {
+		url.query_pairs()
+			.filter_map(|(query, value)| Some((field_name(key, &query)?.to_owned(), value.into_owned())))
+			.collect()
+	}
+
+	#[test]
+	fn parse() {
+		let url = Url::parse("http://localhost/?f[page]=2&other=1&f[search]=a+b").expect("Invalid url");
+
+		let filters = parse_fields::<Filters>(&fields(&url, "f")).expect("Unable to parse filters");
+		assert_eq!(filters, Filters {
+			page:   2,
+			search: "a b".to_owned(),
+		});
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut url = Url::parse("http://localhost/?a=1&f[page]=2&f[search]=abc&b=2").expect("Invalid url");
+
+		let mut filters = parse_fields::<Filters>(&fields(&url, "f")).expect("Unable to parse filters");
+		filters.page = 3;
+		filters.search = "a&b".to_owned();
+
+		let new_fields = serialize_fields(&filters).expect("Unable to serialize filters");
+		write_fields(&mut url, "f", new_fields);
+
+		let pairs = url.query_pairs().into_owned().collect::<Vec<_>>();
+		assert_eq!(pairs, [
+			("a".to_owned(), "1".to_owned()),
+			("f[page]".to_owned(), "3".to_owned()),
+			("f[search]".to_owned(), "a&b".to_owned()),
+			("b".to_owned(), "2".to_owned()),
+		]);
+
+		let new_filters = parse_fields::<Filters>(&fields(&url, "f")).expect("Unable to parse filters");
+		assert_eq!(new_filters, filters);
+	}
+
+	#[test]
+	#[cfg(feature = "ssr")]
+	fn query_signal_round_trip() {
+		let (ctx, location) = test_util::ctx("http://localhost/?f[page]=2&f[search]=abc");
+		let signal = QuerySignal::new(&ctx, SerdeQuery::<Filters>::new(&ctx, "f"));
+		assert_eq!(
+			signal.borrow().as_ref().loaded(),
+			Some(&Filters {
+				page:   2,
+				search: "abc".to_owned(),
+			})
+		);
+
+		signal.set(Filters {
+			page:   3,
+			search: "a&b".to_owned(),
+		});
+		assert_eq!(fields(&location.borrow(), "f"), [
+			("page".to_owned(), "3".to_owned()),
+			("search".to_owned(), "a&b".to_owned()),
+		]);
+		assert_eq!(
+			signal.borrow().as_ref().loaded(),
+			Some(&Filters {
+				page:   3,
+				search: "a&b".to_owned(),
+			})
+		);
+
+		// Note: Once no fields are left, no `?` should be left in the url either
+		*signal.borrow_mut() = Loadable::Empty;
+		assert_eq!(location.borrow().query(), None);
+		assert_eq!(location.borrow().as_str(), "http://localhost/");
+		assert!(signal.borrow().is_empty());
+	}
+}
@@ -107,7 +107,7 @@ where
 {
 	fn write(&self, new_value: &Loadable<T, T::Err>) {
 		match new_value {
-			Loadable::Empty => self.write(None),
+			Loadable::Empty | Loadable::Loading => self.write(None),
 			Loadable::Err(err) => tracing::warn!(?self.key, ?err, "Cannot assign an error to a query value"),
 			Loadable::Loaded(new_value) => self.write(Some(new_value)),
 		}
@@ -125,32 +125,6 @@ impl<T: FromStr<Err: StdError> + ToString> QueryWrite<Option<&'_ T>> for SingleQ
 
 		let location = self.ctx.store().get::<LocationSignal>();
 		let mut location = location.borrow_mut();
-		let mut added_query = false;
-		let mut queries = vec![];
-		for (key, value) in location.query_pairs().into_owned() {
-			// If it's another key, keep it
-			if key != *self.key {
-				queries.push((key, value));
-				continue;
-			}
-
-			// If we already added our query, this is a duplicate, so skip it
-			if added_query {
-				continue;
-			}
-
-			// If it's our key, check what we should do
-			if let Some(new_value) = new_value {
-				queries.push((self.key.to_string(), new_value.to_string()));
-				added_query = true;
-			}
-		}
-
-		// If we haven't added ours yet by now, add it at the end
-		if !added_query && let Some(new_value) = new_value {
-			queries.push((self.key.to_string(), new_value.to_string()));
-		}
-
-		location.query_pairs_mut().clear().extend_pairs(queries);
+		super::write_query_values(&mut location, &self.key, new_value.map(T::to_string));
 	}
 }
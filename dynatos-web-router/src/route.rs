@@ -0,0 +1,259 @@
+//! Route
+
+// Imports
+use {
+	crate::LocationSignal,
+	core::{fmt, str::FromStr},
+	dynatos_reactive::{Memo, MemoRun, SignalBorrow},
+	dynatos_sync_types::RcPtr,
+	dynatos_web::DynatosWebCtx,
+};
+
+/// Route matcher.
+///
+/// A route is made up of `/`-separated segments, each of which may be:
+/// - A static segment (e.g. `users`), which must match exactly.
+/// - A parameter (e.g. `:id`), which matches any single segment.
+/// - A trailing parameter (e.g. `*rest`), which matches the remaining
+///   segments, if any. It may only appear at the end of the route.
+///
+/// Empty segments are ignored, so `/users/` and `users` are the same route.
+#[derive(Clone, Debug)]
+pub struct Route {
+	/// Segments
+	segments: RcPtr<Vec<Segment>>,
+}
+
+impl Route {
+	/// Creates a new route from a pattern, such as `/users/:id/posts/:slug`.
+	///
+	/// # Panics
+	/// Panics if a trailing parameter isn't the last segment.
+	#[must_use]
+	#[track_caller]
+	pub fn new(pattern: &str) -> Self {
+		let segments = self::segments(pattern).map(Segment::new).collect::<Vec<_>>();
+		if let Some(idx) = segments.iter().position(|segment| matches!(segment, Segment::Rest(_))) {
+			assert!(
+				idx == segments.len() - 1,
+				"Trailing parameter must be the last segment in route {pattern:?}"
+			);
+		}
+
+		Self {
+			segments: RcPtr::new(segments),
+		}
+	}
+
+	/// Matches this route against `path`.
+	///
+	/// Returns the parameters if it matched.
+	#[must_use]
+	pub fn matches(&self, path: &str) -> Option<Params> {
+		let mut params = vec![];
+		let mut path_segments = self::segments(path);
+		for segment in &**self.segments {
+			match segment {
+				Segment::Static(name) =>
+					if path_segments.next()? != &**name {
+						return None;
+					},
+				Segment::Param(name) => params.push((name.to_string(), path_segments.next()?.to_owned())),
+				Segment::Rest(name) => {
+					let rest = path_segments.by_ref().collect::<Vec<_>>().join("/");
+					params.push((name.to_string(), rest));
+				},
+			}
+		}
+
+		// If there's any path left, we didn't match it all
+		if path_segments.next().is_some() {
+			return None;
+		}
+
+		Some(Params { params })
+	}
+
+	/// Creates a memo that matches this route against the current location.
+	///
+	/// The memo re-runs whenever the location changes, but only updates
+	/// dependents when the match itself changes.
+	///
+	/// Requires a value of type [`LocationSignal`](crate::LocationSignal) in the context store.
+	#[must_use]
+	#[track_caller]
+	pub fn memo(&self, ctx: &DynatosWebCtx) -> Memo<Option<Params>, impl MemoRun<Option<Params>>> {
+		let route = self.clone();
+		let location = ctx.store().get::<LocationSignal>();
+		Memo::new(move || route.matches(location.borrow().path()))
+	}
+}
+
+/// Route segment
+#[derive(Debug)]
+enum Segment {
+	/// Static segment
+	Static(RcPtr<str>),
+
+	/// Parameter
+	Param(RcPtr<str>),
+
+	/// Trailing parameter
+	Rest(RcPtr<str>),
+}
+
+impl Segment {
+	/// Parses a segment
+	fn new(segment: &str) -> Self {
+		if let Some(name) = segment.strip_prefix(':') {
+			return Self::Param(name.into());
+		}
+
+		if let Some(name) = segment.strip_prefix('*') {
+			return Self::Rest(name.into());
+		}
+
+		Self::Static(segment.into())
+	}
+}
+
+/// Returns all non-empty segments of `path`
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+	path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// Route parameters
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct Params {
+	/// All parameters, by name
+	params: Vec<(String, String)>,
+}
+
+impl Params {
+	/// Returns the raw value of parameter `name`
+	#[must_use]
+	pub fn get_str(&self, name: &str) -> Option<&str> {
+		self.params
+			.iter()
+			.find(|(param, _)| param == name)
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// Parses the value of parameter `name`
+	pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParamError<T>> {
+		let value = self
+			.get_str(name)
+			.ok_or_else(|| ParamError::Missing { name: name.to_owned() })?;
+		value.parse().map_err(|err| ParamError::Parse {
+			name: name.to_owned(),
+			value: value.to_owned(),
+			err,
+		})
+	}
+}
+
+/// Error for [`Params::get`]
+#[derive(thiserror::Error)]
+pub enum ParamError<T: FromStr> {
+	/// Parameter didn't exist
+	#[error("Missing parameter {name:?}")]
+	Missing {
+		/// Parameter name
+		name: String,
+	},
+
+	/// Unable to parse parameter
+	#[error("Unable to parse parameter {name:?}: {value:?}")]
+	Parse {
+		/// Parameter name
+		name: String,
+
+		/// Value we were unable to parse
+		value: String,
+
+		/// Inner error
+		#[source]
+		err: T::Err,
+	},
+}
+
+impl<T> fmt::Debug for ParamError<T>
+where
+	T: FromStr,
+	T::Err: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Missing { name } => f.debug_struct("Missing").field("name", name).finish(),
+			Self::Parse { name, value, err } => f
+				.debug_struct("Parse")
+				.field("name", name)
+				.field("value", value)
+				.field("err", err)
+				.finish(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn static_route() {
+		let route = Route::new("/users/all");
+
+		assert_eq!(route.matches("/users/all"), Some(Params::default()));
+		assert_eq!(route.matches("/users/all/"), Some(Params::default()));
+		assert_eq!(route.matches("/users"), None);
+		assert_eq!(route.matches("/users/all/more"), None);
+		assert_eq!(route.matches("/users/none"), None);
+	}
+
+	#[test]
+	fn single_param() {
+		let route = Route::new("/users/:id");
+
+		let params = route.matches("/users/5").expect("Route should match");
+		assert_eq!(params.get_str("id"), Some("5"));
+		assert_eq!(params.get::<u32>("id").expect("Unable to parse id"), 5);
+		assert!(matches!(params.get::<u32>("slug"), Err(ParamError::Missing { .. })));
+
+		let params = route.matches("/users/abc").expect("Route should match");
+		assert!(matches!(params.get::<u32>("id"), Err(ParamError::Parse { .. })));
+
+		assert_eq!(route.matches("/users"), None);
+		assert_eq!(route.matches("/users/5/posts"), None);
+	}
+
+	#[test]
+	fn multiple_params() {
+		let route = Route::new("/users/:id/posts/:slug");
+
+		let params = route.matches("/users/5/posts/hello").expect("Route should match");
+		assert_eq!(params.get::<u32>("id").expect("Unable to parse id"), 5);
+		assert_eq!(params.get_str("slug"), Some("hello"));
+
+		assert_eq!(route.matches("/users/5/comments/hello"), None);
+	}
+
+	#[test]
+	fn trailing_param() {
+		let route = Route::new("/files/*path");
+
+		let params = route.matches("/files/a/b/c").expect("Route should match");
+		assert_eq!(params.get_str("path"), Some("a/b/c"));
+
+		let params = route.matches("/files").expect("Route should match");
+		assert_eq!(params.get_str("path"), Some(""));
+
+		assert_eq!(route.matches("/other/a"), None);
+	}
+
+	#[test]
+	#[should_panic = "Trailing parameter must be the last segment"]
+	fn trailing_param_not_last() {
+		_ = Route::new("/files/*path/more");
+	}
+}
@@ -0,0 +1,213 @@
+//! Scroll restoration
+
+// Imports
+use {dynatos_web::DynatosWebCtx, std::collections::BTreeMap, wasm_bindgen::JsValue};
+
+/// Scroll behavior when navigating
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum ScrollBehavior {
+	/// Restores the previous scroll position when navigating back or forward
+	/// through history, and scrolls to the top when navigating to a new page.
+	Auto,
+
+	/// Leaves the scroll position untouched.
+	#[default]
+	Manual,
+
+	/// Always scrolls to the top.
+	Top,
+}
+
+/// Id of a history entry.
+///
+/// Stored as the state of each history entry we push.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug)]
+pub struct StateId(u32);
+
+impl StateId {
+	/// Id of the initial history entry.
+	///
+	/// Since we didn't push this entry, it doesn't have any state.
+	const INITIAL: Self = Self(0);
+
+	/// Parses an id from a history entry state
+	pub fn from_state(state: &JsValue) -> Self {
+		#[expect(
+			clippy::as_conversions,
+			clippy::cast_possible_truncation,
+			clippy::cast_sign_loss,
+			reason = "We only ever store `u32`s in the state"
+		)]
+		state.as_f64().map_or(Self::INITIAL, |id| Self(id as u32))
+	}
+
+	/// Converts this id into a history entry state
+	pub fn to_state(self) -> JsValue {
+		JsValue::from(f64::from(self.0))
+	}
+}
+
+/// Scroll position
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct ScrollPos {
+	x: f64,
+	y: f64,
+}
+
+impl ScrollPos {
+	/// Gets the current scroll position of the window
+	pub fn current(ctx: &DynatosWebCtx) -> Self {
+		let window = ctx.window();
+		Self {
+			x: window.scroll_x().expect("Unable to get window scroll x"),
+			y: window.scroll_y().expect("Unable to get window scroll y"),
+		}
+	}
+
+	/// Scrolls the window to this position
+	pub fn scroll_to(self, ctx: &DynatosWebCtx) {
+		ctx.window().scroll_to_with_x_and_y(self.x, self.y);
+	}
+}
+
+/// Scroll positions of each history entry
+#[derive(Debug)]
+pub struct ScrollHistory {
+	/// Current history entry
+	cur_id: StateId,
+
+	/// Next id to use for a new history entry
+	next_id: StateId,
+
+	/// Scroll positions of the history entries we left.
+	///
+	/// Holds at most [`Self::MAX_POSITIONS`] entries.
+	positions: BTreeMap<StateId, ScrollPos>,
+}
+
+impl ScrollHistory {
+	/// Maximum number of positions we keep.
+	///
+	/// Once exceeded, the oldest entries are forgotten, and will
+	/// be scrolled to the top when navigated to.
+	const MAX_POSITIONS: usize = 256;
+
+	/// Creates a new, empty, scroll history
+	pub const fn new() -> Self {
+		Self {
+			cur_id:    StateId::INITIAL,
+			next_id:   StateId(StateId::INITIAL.0 + 1),
+			positions: BTreeMap::new(),
+		}
+	}
+
+	/// Returns the id of the current history entry
+	pub const fn cur_id(&self) -> StateId {
+		self.cur_id
+	}
+
+	/// Saves `cur_pos` for the current history entry, evicting the oldest entry if full
+	fn save(&mut self, cur_pos: ScrollPos) {
+		_ = self.positions.insert(self.cur_id, cur_pos);
+		if self.positions.len() > Self::MAX_POSITIONS {
+			_ = self.positions.pop_first();
+		}
+	}
+
+	/// Leaves the current history entry for a new one.
+	///
+	/// Saves `cur_pos` for the current entry, and returns the id of the new entry.
+	pub fn push(&mut self, cur_pos: ScrollPos) -> StateId {
+		self.save(cur_pos);
+
+		let id = self.next_id;
+		self.next_id.0 += 1;
+		self.cur_id = id;
+
+		id
+	}
+
+	/// Leaves the current history entry for the existing entry `id`.
+	///
+	/// Saves `cur_pos` for the current entry, and returns the saved
+	/// position of entry `id`, if any.
+	pub fn pop(&mut self, id: StateId, cur_pos: ScrollPos) -> Option<ScrollPos> {
+		self.save(cur_pos);
+		self.cur_id = id;
+
+		self.positions.get(&id).copied()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use {super::*, core::iter};
+
+	/// Creates a scroll position with only a vertical component
+	const fn pos(y: f64) -> ScrollPos {
+		ScrollPos { x: 0.0, y }
+	}
+
+	#[test]
+	fn push_ids_increase() {
+		let mut history = ScrollHistory::new();
+
+		let id1 = history.push(pos(1.0));
+		let id2 = history.push(pos(2.0));
+		assert_ne!(id1, StateId::INITIAL);
+		assert!(id2.0 > id1.0, "Ids should be monotonically increasing");
+	}
+
+	#[test]
+	fn pop_restores() {
+		let mut history = ScrollHistory::new();
+
+		let id1 = history.push(pos(10.0));
+		let id2 = history.push(pos(20.0));
+
+		// Going back to the first entry restores its position
+		assert_eq!(history.pop(id1, pos(30.0)), Some(pos(20.0)));
+		assert_eq!(history.pop(StateId::INITIAL, pos(25.0)), Some(pos(10.0)));
+
+		// And going forward restores the positions we just left with
+		assert_eq!(history.pop(id1, pos(5.0)), Some(pos(25.0)));
+		assert_eq!(history.pop(id2, pos(6.0)), Some(pos(30.0)));
+	}
+
+	#[test]
+	fn pop_unknown() {
+		let mut history = ScrollHistory::new();
+
+		assert_eq!(history.pop(StateId(5), pos(1.0)), None);
+		assert_eq!(history.pop(StateId::INITIAL, pos(2.0)), Some(pos(1.0)));
+	}
+
+	#[test]
+	fn push_after_pop() {
+		let mut history = ScrollHistory::new();
+
+		let id1 = history.push(pos(1.0));
+		assert_eq!(history.pop(StateId::INITIAL, pos(2.0)), Some(pos(1.0)));
+
+		// Pushing a new entry never reuses an old id
+		let id2 = history.push(pos(3.0));
+		assert_ne!(id1, id2);
+		assert_eq!(history.pop(StateId::INITIAL, pos(4.0)), Some(pos(3.0)));
+	}
+
+	#[test]
+	fn evict_oldest() {
+		let mut history = ScrollHistory::new();
+
+		let ids = iter::repeat_with(|| history.push(pos(1.0)))
+			.take(ScrollHistory::MAX_POSITIONS + 1)
+			.collect::<Vec<_>>();
+		assert_eq!(history.positions.len(), ScrollHistory::MAX_POSITIONS);
+
+		// The initial entry was the oldest, so it's been forgotten
+		assert!(!history.positions.contains_key(&StateId::INITIAL));
+		assert!(history.positions.contains_key(&ids[0]));
+		assert_eq!(history.pop(StateId::INITIAL, pos(2.0)), None);
+	}
+}
@@ -0,0 +1,15 @@
+//! Test utilities
+
+// Imports
+use {crate::LocationSignal, dynatos_web::DynatosWebCtx};
+
+/// Creates a new server-side rendering context at `url`, with a location signal in its store
+pub fn ctx(url: &str) -> (DynatosWebCtx, LocationSignal) {
+	let state = dynatos_web_ssr::State::new(url.to_owned());
+	let ctx = DynatosWebCtx::new(state).expect("Unable to create web context");
+
+	let location = LocationSignal::new(&ctx);
+	ctx.store().set(location.clone());
+
+	(ctx, location)
+}
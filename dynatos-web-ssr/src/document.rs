@@ -14,6 +14,7 @@ use {
 		Object,
 		Text,
 		WebError,
+		element,
 		event_target::EventTargetFields,
 		node::NodeFields,
 		object::ObjectFields,
@@ -87,9 +88,20 @@ impl Document {
 		Comment::new(Some(contents.to_owned()))
 	}
 
-	pub fn create_element_ns(&self, _namespace: Option<&str>, name: &str) -> Result<Element, WebError> {
-		// TODO: Check the namespace?
-		Ok(HtmlElement::new(name).into())
+	pub fn create_element_ns(&self, namespace: Option<&str>, name: &str) -> Result<Element, WebError> {
+		let element = match namespace {
+			Some(element::HTML_NAMESPACE) =>
+				HtmlElement::new_specific(name).unwrap_or_else(|| HtmlElement::new(name).into()),
+			_ => Element::new_ns(namespace, name),
+		};
+		Ok(element)
+	}
+
+	#[must_use]
+	#[expect(clippy::unused_self, reason = "We want to mirror the browser api")]
+	pub const fn active_element(&self) -> Option<Element> {
+		// Note: There's no focus during server-side rendering
+		None
 	}
 
 	pub fn location(&self) -> Result<Location, WebError> {
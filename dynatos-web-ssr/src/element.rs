@@ -21,18 +21,27 @@ use {
 zutil_inheritance::value! {
 	pub struct Element(Node, EventTarget, Object): Send + Sync + Debug {
 		tag_name: String,
+		namespace_uri: Option<String>,
 		class_name: Mutex<String>,
 		attrs: Mutex<HashMap<String, String>>,
 	}
 	impl Self {}
 }
 
+/// Html namespace
+pub(crate) const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
 impl ElementFields {
 	pub fn new(tag_name: impl Into<String>) -> Self {
+		Self::new_ns(Some(HTML_NAMESPACE), tag_name)
+	}
+
+	pub fn new_ns(namespace_uri: Option<&str>, tag_name: impl Into<String>) -> Self {
 		Self {
-			tag_name:   tag_name.into(),
-			class_name: Mutex::new(String::new()),
-			attrs:      Mutex::new(HashMap::new()),
+			tag_name:      tag_name.into(),
+			namespace_uri: namespace_uri.map(str::to_owned),
+			class_name:    Mutex::new(String::new()),
+			attrs:         Mutex::new(HashMap::new()),
 		}
 	}
 }
@@ -51,6 +60,27 @@ impl Element {
 		))
 	}
 
+	/// Creates a new element on a namespace.
+	///
+	/// Unlike html elements, the node name keeps the case of `tag_name`.
+	#[must_use]
+	pub fn new_ns(namespace_uri: Option<&str>, tag_name: impl Into<String>) -> Self {
+		let tag_name = tag_name.into();
+		let node_name = tag_name.clone();
+
+		Self::from_fields((
+			ElementFields::new_ns(namespace_uri, tag_name),
+			NodeFields::new(node_name),
+			EventTargetFields::default(),
+			ObjectFields::default(),
+		))
+	}
+
+	#[must_use]
+	pub fn namespace_uri(&self) -> Option<String> {
+		self.fields().namespace_uri.clone()
+	}
+
 	#[must_use]
 	pub fn class_name(&self) -> String {
 		self.get_attribute("class").unwrap_or_default()
@@ -68,9 +98,10 @@ impl Element {
 		parent.replace_child(new, self)
 	}
 
-	pub fn set_inner_html(&self, _html: &str) {
-		// TODO: Support this
-		tracing::warn!("Ignoring `Element::set_inner_html` call");
+	pub fn set_inner_html(&self, html: &str) {
+		// Note: Text nodes are written as-is, so we don't need to parse the html.
+		// TODO: Parse the html, so the children may be accessed?
+		self.set_text_content(Some(html));
 	}
 
 	#[must_use]
@@ -98,6 +129,73 @@ impl Element {
 		Ok(())
 	}
 
+	/// Returns if this element is matched by `selectors`.
+	///
+	/// Only compound selectors made of a tag name, `#id`, `.class`, `[attr]` and
+	/// `[attr="value"]`, separated by commas, are supported.
+	pub fn matches(&self, selectors: &str) -> Result<bool, WebError> {
+		for selector in selectors.split(',') {
+			if self.matches_compound(selector.trim())? {
+				return Ok(true);
+			}
+		}
+
+		Ok(false)
+	}
+
+	/// Returns if this element is matched by a compound selector
+	fn matches_compound(&self, selector: &str) -> Result<bool, WebError> {
+		fn split_ident(s: &str) -> (&str, &str) {
+			let is_ident_char = |ch: char| ch.is_alphanumeric() || ch == '-' || ch == '_';
+			s.split_at(s.find(|ch| !is_ident_char(ch)).unwrap_or(s.len()))
+		}
+
+		if selector.is_empty() {
+			return Err(WebError(app_error!("Empty selector")));
+		}
+
+		let (tag_name, mut rest) = split_ident(selector);
+		if !tag_name.is_empty() && !tag_name.eq_ignore_ascii_case(&self.fields().tag_name) {
+			return Ok(false);
+		}
+
+		while let Some(ch) = rest.chars().next() {
+			rest = &rest[ch.len_utf8()..];
+			let is_match = match ch {
+				'#' => {
+					let id;
+					(id, rest) = split_ident(rest);
+					self.get_attribute("id").is_ok_and(|cur_id| cur_id == id)
+				},
+				'.' => {
+					let class;
+					(class, rest) = split_ident(rest);
+					self.class_name().split_whitespace().any(|cur_class| cur_class == class)
+				},
+				'[' => {
+					let attr;
+					(attr, rest) = rest
+						.split_once(']')
+						.ok_or_else(|| WebError(app_error!("Unterminated attribute selector: {selector:?}")))?;
+					match attr.split_once('=') {
+						Some((attr, value)) => {
+							let value = value.trim_matches(|ch| ch == '"' || ch == '\'');
+							self.get_attribute(attr).is_ok_and(|cur_value| cur_value == value)
+						},
+						None => self.has_attribute(attr),
+					}
+				},
+				_ => return Err(WebError(app_error!("Unsupported selector: {selector:?}"))),
+			};
+
+			if !is_match {
+				return Ok(false);
+			}
+		}
+
+		Ok(true)
+	}
+
 	fn write_outer_html(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
 		let tag_name = &self.fields().tag_name;
 
@@ -1,7 +1,7 @@
 //! Events
 
 // Imports
-use crate::Object;
+use crate::{JsValue, Object};
 
 zutil_inheritance::value! {
 	pub struct Event(Object): Send + Sync + Debug + Default {}
@@ -21,6 +21,7 @@ decl_events! {
 	ClipboardEvent,
 	DragEvent,
 	FocusEvent,
+	HashChangeEvent,
 	InputEvent,
 	MouseEvent,
 	PointerEvent,
@@ -39,3 +40,12 @@ macro decl_events($new:ident; $($Name:ident),* $(,)?) {
 		}
 	)*
 }
+
+impl PopStateEvent {
+	#[must_use]
+	#[expect(clippy::unused_self, reason = "We want to mirror the browser api")]
+	pub const fn state(&self) -> JsValue {
+		// Note: We don't store any history state during server-side rendering
+		JsValue::UNDEFINED
+	}
+}
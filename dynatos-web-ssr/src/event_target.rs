@@ -5,6 +5,7 @@ use {
 	crate::{Element, Event, HtmlElement, Object, State, WebError},
 	app_error::{AppError, Context, app_error},
 	core::any::type_name,
+	std::sync::{Arc, nonpoison::Mutex},
 	zutil_inheritance::{Downcast, Value},
 };
 
@@ -12,8 +13,18 @@ use {
 #[debug("{_0:p}")]
 pub(crate) struct Handler(Box<dyn Fn(Event) -> Result<(), AppError> + Send + Sync>);
 
+/// Listener for an event that's only ever dispatched manually
+#[derive(Clone, derive_more::Debug)]
+#[debug("{name:?}")]
+struct Listener {
+	name:    String,
+	handler: Arc<dyn Fn(Event) -> Result<(), AppError> + Send + Sync>,
+}
+
 zutil_inheritance::value! {
-	pub struct EventTarget(Object): Send + Sync + Debug + Default {}
+	pub struct EventTarget(Object): Send + Sync + Debug + Default {
+		listeners: Mutex<Vec<Listener>>,
+	}
 	impl Self {}
 }
 
@@ -29,6 +40,19 @@ impl EventTarget {
 		Ok(())
 	}
 
+	/// Dispatches `event` to all listeners of the event `name` on this target.
+	///
+	/// Only events that have no server-side equivalent, such as `change`, are
+	/// dispatched this way, since they're never fired during server-side rendering.
+	pub fn dispatch_event_with_name(&self, name: &str, event: &Event) -> Result<(), WebError> {
+		let listeners = self.fields().listeners.lock().clone();
+		for listener in listeners.iter().filter(|listener| listener.name == name) {
+			(listener.handler)(event.clone())?;
+		}
+
+		Ok(())
+	}
+
 	pub fn add_event_listener_with_callback<Ev, F>(&self, state: &State, name: &str, f: F) -> Result<(), WebError>
 	where
 		Ev: Value,
@@ -62,19 +86,7 @@ impl EventTarget {
 				data.set_attribute("value", &handler_idx.to_string())?;
 				form.append_child(&data)?;
 
-				let handler = move |ev: Event| {
-					let ev = ev.downcast::<Ev>().map_err(|obj| {
-						app_error!(
-							"Event object was of the wrong type. Expected {}, found {obj:?}",
-							type_name::<Ev>()
-						)
-					})?;
-
-					f(ev);
-					Ok(())
-				};
-
-				handlers.push(Handler(Box::new(handler)));
+				handlers.push(Handler(Box::new(self::typed_handler(f))));
 			},
 			"popstate" => state.history().listen(move |ev| match ev.downcast::<Ev>() {
 				Ok(ev) => f(ev),
@@ -83,6 +95,19 @@ impl EventTarget {
 					type_name::<Ev>()
 				),
 			}),
+			"hashchange" => state.history().listen_hash(move |ev| match ev.downcast::<Ev>() {
+				Ok(ev) => f(ev),
+				Err(ev) => tracing::warn!(
+					"Event object was of the wrong type. Expected {}, found {ev:?}",
+					type_name::<Ev>()
+				),
+			}),
+			// Note: There are no animations or transitions during server-side rendering.
+			"animationend" | "transitionend" => (),
+			"change" | "input" | "toggle" => self.fields().listeners.lock().push(Listener {
+				name:    name.to_owned(),
+				handler: Arc::new(self::typed_handler(f)),
+			}),
 
 			_ => return Err(WebError(app_error!("Unable to handle event: {name:?}"))),
 		}
@@ -90,3 +115,22 @@ impl EventTarget {
 		Ok(())
 	}
 }
+
+/// Wraps `f` into a handler that downcasts the event to `Ev`
+fn typed_handler<Ev, F>(f: F) -> impl Fn(Event) -> Result<(), AppError> + Send + Sync + 'static
+where
+	Ev: Value,
+	F: Fn(Ev) + Send + Sync + 'static,
+{
+	move |ev: Event| {
+		let ev = ev.downcast::<Ev>().map_err(|obj| {
+			app_error!(
+				"Event object was of the wrong type. Expected {}, found {obj:?}",
+				type_name::<Ev>()
+			)
+		})?;
+
+		f(ev);
+		Ok(())
+	}
+}
@@ -2,17 +2,23 @@
 
 // Imports
 use {
-	crate::{JsValue, Location, Object, PopStateEvent, WebError, object::ObjectFields},
+	crate::{Event, HashChangeEvent, JsValue, Location, Object, PopStateEvent, WebError, object::ObjectFields},
 	zutil_inheritance::{FromFields, Value},
 	std::sync::{Arc, nonpoison::Mutex},
 };
 
 #[derive(Clone, derive_more::Debug)]
 #[debug("{_0:p}")]
-struct LocationCb(Arc<dyn Fn(PopStateEvent) + Send + Sync>);
+struct LocationCb(Arc<dyn Fn(Event) + Send + Sync>);
 
-#[derive(Clone, Debug)]
-struct LocationCbs(Vec<LocationCb>);
+#[derive(Clone, Default, Debug)]
+struct LocationCbs {
+	/// `popstate` callbacks
+	pop_state: Vec<LocationCb>,
+
+	/// `hashchange` callbacks
+	hash_change: Vec<LocationCb>,
+}
 
 zutil_inheritance::value! {
 	pub struct History(Object): Send + Sync + Debug {
@@ -30,7 +36,7 @@ impl History {
 		Self::from_fields((
 			HistoryFields {
 				location,
-				cbs: Mutex::new(LocationCbs(vec![])),
+				cbs: Mutex::new(LocationCbs::default()),
 			},
 			ObjectFields::default(),
 		))
@@ -49,17 +55,41 @@ impl History {
 		Ok(())
 	}
 
+	#[expect(clippy::unused_self, reason = "We want to mirror the browser api")]
+	pub const fn state(&self) -> Result<JsValue, WebError> {
+		// Note: We don't store any history state during server-side rendering
+		Ok(JsValue::UNDEFINED)
+	}
+
 	/// Updates all listeners
 	pub fn update_listeners(&self) {
-		let cbs = self.fields().cbs.lock().clone();
-		let event = PopStateEvent::default();
-		for cb in cbs.0 {
+		let cbs = self.fields().cbs.lock().pop_state.clone();
+		let event = Event::from(PopStateEvent::default());
+		for cb in cbs {
+			(cb.0)(event.clone());
+		}
+	}
+
+	/// Updates all fragment listeners.
+	///
+	/// Since the fragment is never sent to the server, it never changes by
+	/// itself during server-side rendering, so this must be called manually
+	/// after changing it.
+	pub fn update_hash_listeners(&self) {
+		let cbs = self.fields().cbs.lock().hash_change.clone();
+		let event = Event::from(HashChangeEvent::default());
+		for cb in cbs {
 			(cb.0)(event.clone());
 		}
 	}
 
 	/// Adds a callback for when the location changes
-	pub(crate) fn listen(&self, cb: impl Fn(PopStateEvent) + Send + Sync + 'static) {
-		self.fields().cbs.lock().0.push(LocationCb(Arc::new(cb)));
+	pub(crate) fn listen(&self, cb: impl Fn(Event) + Send + Sync + 'static) {
+		self.fields().cbs.lock().pop_state.push(LocationCb(Arc::new(cb)));
+	}
+
+	/// Adds a callback for when the location fragment changes
+	pub(crate) fn listen_hash(&self, cb: impl Fn(Event) + Send + Sync + 'static) {
+		self.fields().cbs.lock().hash_change.push(LocationCb(Arc::new(cb)));
 	}
 }
@@ -8,12 +8,14 @@ use {
 		EventTarget,
 		Node,
 		Object,
+		Text,
+		WebError,
 		element::ElementFields,
 		event_target::EventTargetFields,
 		node::NodeFields,
 		object::ObjectFields,
 	},
-	zutil_inheritance::{FromFields, Value},
+	zutil_inheritance::{Downcast, FromFields, Value},
 };
 
 zutil_inheritance::value! {
@@ -41,10 +43,16 @@ impl HtmlElement {
 	pub fn style(&self) -> CssStyleProperties {
 		CssStyleProperties::new(self.clone())
 	}
+
+	#[expect(clippy::unused_self, reason = "We want to mirror the browser api")]
+	pub const fn focus(&self) -> Result<(), WebError> {
+		// Note: There's no focus during server-side rendering
+		Ok(())
+	}
 }
 
 decl_html_elements! {
-	new;
+	new, new_specific;
 
 	HtmlBodyElement = "body",
 	HtmlCanvasElement = "canvas",
@@ -53,10 +61,12 @@ decl_html_elements! {
 	HtmlHeadElement = "head",
 	HtmlImageElement = "image",
 	HtmlInputElement = "input",
+	HtmlOptionElement = "option",
+	HtmlSelectElement = "select",
 	HtmlTextAreaElement = "textarea",
 }
 
-macro decl_html_elements($new:ident; $($Name:ident = $tag:literal),* $(,)?) {
+macro decl_html_elements($new:ident, $new_specific:ident; $($Name:ident = $tag:literal),* $(,)?) {
 	$(
 		zutil_inheritance::value! {
 			pub struct $Name(HtmlElement, Element, Node, EventTarget, Object): Send + Sync + Debug + DefaultFields {}
@@ -86,9 +96,25 @@ macro decl_html_elements($new:ident; $($Name:ident = $tag:literal),* $(,)?) {
 			}
 		}
 	)*
+
+	impl HtmlElement {
+		/// Creates a new html element of the specific type for `tag_name`, if it has one
+		#[must_use]
+		pub fn $new_specific(tag_name: &str) -> Option<Element> {
+			match tag_name {
+				$( $tag => Some($Name::$new().into()), )*
+				_ => None,
+			}
+		}
+	}
 }
 
-impl HtmlImageElement {
+#[duplicate::duplicate_item(
+	Ty;
+	[HtmlDetailsElement];
+	[HtmlDialogElement];
+)]
+impl Ty {
 	#[must_use]
 	pub fn open(&self) -> bool {
 		self.has_attribute("open")
@@ -101,3 +127,116 @@ impl HtmlImageElement {
 		}
 	}
 }
+
+impl HtmlInputElement {
+	#[must_use]
+	pub fn value(&self) -> String {
+		self.get_attribute("value").unwrap_or_default()
+	}
+
+	pub fn set_value(&self, value: &str) {
+		self.set_attribute("value", value)
+			.expect("Unable to set value attribute");
+	}
+
+	#[must_use]
+	pub fn checked(&self) -> bool {
+		self.has_attribute("checked")
+	}
+
+	pub fn set_checked(&self, checked: bool) {
+		match checked {
+			true => self
+				.set_attribute("checked", "")
+				.expect("Unable to set checked attribute"),
+			false => _ = self.remove_attribute("checked"),
+		}
+	}
+}
+
+impl HtmlTextAreaElement {
+	#[must_use]
+	pub fn value(&self) -> String {
+		let node: &Node = self;
+		node.fields()
+			.children
+			.lock()
+			.iter()
+			.filter_map(|child| child.clone().downcast::<Text>().ok())
+			.filter_map(|text| text.fields().contents.clone())
+			.collect()
+	}
+
+	pub fn set_value(&self, value: &str) {
+		self.set_text_content(Some(value));
+	}
+}
+
+impl HtmlOptionElement {
+	#[must_use]
+	pub fn value(&self) -> String {
+		// TODO: Default to the text contents, like the browser does
+		self.get_attribute("value").unwrap_or_default()
+	}
+
+	pub fn set_value(&self, value: &str) {
+		self.set_attribute("value", value)
+			.expect("Unable to set value attribute");
+	}
+
+	#[must_use]
+	pub fn selected(&self) -> bool {
+		self.has_attribute("selected")
+	}
+
+	pub fn set_selected(&self, selected: bool) {
+		match selected {
+			true => self
+				.set_attribute("selected", "")
+				.expect("Unable to set selected attribute"),
+			false => _ = self.remove_attribute("selected"),
+		}
+	}
+}
+
+impl HtmlSelectElement {
+	/// Returns all options of this select
+	// TODO: Support options within `<optgroup>`s
+	fn options(&self) -> Vec<HtmlOptionElement> {
+		let node: &Node = self;
+		node.fields()
+			.children
+			.lock()
+			.iter()
+			.filter_map(|child| child.clone().downcast().ok())
+			.collect()
+	}
+
+	#[must_use]
+	pub fn length(&self) -> u32 {
+		u32::try_from(self.options().len()).expect("Too many options")
+	}
+
+	#[must_use]
+	pub fn item(&self, index: u32) -> Option<Element> {
+		let index = usize::try_from(index).ok()?;
+		self.options().into_iter().nth(index).map(Element::from)
+	}
+
+	#[must_use]
+	pub fn value(&self) -> String {
+		let options = self.options();
+		options
+			.iter()
+			.find(|option| option.selected())
+			.or_else(|| options.first())
+			.map(HtmlOptionElement::value)
+			.unwrap_or_default()
+	}
+
+	pub fn set_value(&self, value: &str) {
+		for option in self.options() {
+			option.set_selected(option.value() == value);
+		}
+	}
+}
@@ -31,6 +31,11 @@ pub struct JsValue(Inner);
 impl JsValue {
 	pub const UNDEFINED: Self = Self(Inner::Undefined);
 
+	#[must_use]
+	pub const fn is_undefined(&self) -> bool {
+		matches!(self.0, Inner::Undefined)
+	}
+
 	pub fn from_any<T: Any + Send + Sync + 'static>(any: T) -> Self {
 		Self(Inner::Any(Arc::new(any)))
 	}
@@ -61,6 +66,11 @@ impl JsValue {
 		}
 	}
 
+	#[must_use]
+	pub fn as_f64(&self) -> Option<f64> {
+		self.as_float().copied()
+	}
+
 	#[duplicate::duplicate_item(
 		fn_name Variant Ty;
 		[try_into_string] [String] [Arc<str>];
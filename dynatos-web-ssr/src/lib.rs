@@ -38,6 +38,7 @@ pub use self::{
 		DragEvent,
 		Event,
 		FocusEvent,
+		HashChangeEvent,
 		InputEvent,
 		MouseEvent,
 		PointerEvent,
@@ -58,6 +59,8 @@ pub use self::{
 		HtmlHeadElement,
 		HtmlImageElement,
 		HtmlInputElement,
+		HtmlOptionElement,
+		HtmlSelectElement,
 		HtmlTextAreaElement,
 	},
 	js_value::JsValue,
@@ -70,13 +73,11 @@ pub use self::{
 };
 
 // Imports
-#[cfg(feature = "reactive")]
-use std::collections::HashMap;
 use {
 	self::{event_target::EventTargetFields, node::NodeFields, object::ObjectFields},
 	app_error::AppError,
-	core::any::Any,
-	std::sync::nonpoison::Mutex,
+	core::any::{Any, TypeId},
+	std::{collections::HashMap, sync::nonpoison::Mutex},
 	zutil_inheritance::FromFields,
 };
 
@@ -135,7 +136,7 @@ impl AsRef<Self> for WebError {
 //       of the parent storage/vtable.
 zutil_inheritance::value! {
 	pub struct ObjectAttachValueValues(Object): Send + Sync + Debug {
-		values: Mutex<Vec<Box<dyn Any + Send + Sync>>>,
+		values: Mutex<HashMap<TypeId, Vec<Box<dyn Any + Send + Sync>>>>,
 	}
 	impl Self {}
 }
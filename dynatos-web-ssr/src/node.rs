@@ -89,7 +89,13 @@ impl Node {
 		children.contains(other) || children.iter().any(|child| child.contains(Some(other)))
 	}
 
+	#[must_use]
+	pub fn parent_node(&self) -> Option<Self> {
+		self.fields().parent.lock().get()
+	}
+
 	pub fn append_child(&self, child: &Self) -> Result<(), WebError> {
+		child.detach();
 		let mut children = self.fields().children.lock();
 		*child.fields().parent.lock() = Parent::new(self);
 		children.push(child.clone());
@@ -105,10 +111,12 @@ impl Node {
 			.position(|cur_child| cur_child == child)
 			.context("Child didn't exist")?;
 
+		*child.fields().parent.lock() = Parent::none();
 		Ok(children.remove(idx))
 	}
 
 	pub fn replace_child(&self, new_child: &Self, old_child: &Self) -> Result<(), WebError> {
+		new_child.detach();
 		let mut children = self.fields().children.lock();
 		let idx = children
 			.iter()
@@ -124,6 +132,7 @@ impl Node {
 	pub fn insert_before(&self, new_child: &Self, child: Option<&Self>) -> Result<(), WebError> {
 		match child {
 			Some(child) => {
+				new_child.detach();
 				let mut children = self.fields().children.lock();
 				let idx = children
 					.iter()
@@ -138,9 +147,14 @@ impl Node {
 		}
 	}
 
+	#[must_use]
+	pub fn first_child(&self) -> Option<Self> {
+		self.fields().children.lock().first().cloned()
+	}
+
 	#[must_use]
 	pub fn next_sibling(&self) -> Option<Self> {
-		let parent = self.fields().parent.lock().clone().get()?;
+		let parent = self.parent_node()?;
 
 		let parent_children = parent.fields().children.lock();
 		let idx = parent_children
@@ -161,4 +175,13 @@ impl Node {
 	pub fn take_children(&self) -> Vec<Self> {
 		mem::take(&mut self.fields().children.lock())
 	}
+
+	/// Removes this node from its parent, if any.
+	///
+	/// Like in the browser, adding a node that already has a parent moves it.
+	fn detach(&self) {
+		if let Some(parent) = self.parent_node() {
+			parent.fields().children.lock().retain(|child| child != self);
+		}
+	}
 }
@@ -18,6 +18,9 @@ impl Object {
 	pub fn get_prop_inner<T: Value>(&self, prop: &str) -> Result<T, GetError> {
 		let fields = self.fields().props.lock();
 		let value = fields.get(prop).ok_or(GetError::Missing)?.clone();
+		if value.is_undefined() {
+			return Err(GetError::Null);
+		}
 
 		match value.try_into_object() {
 			Ok(obj) => obj.downcast::<T>().map_err(|obj| GetError::WrongType(obj.into())),
@@ -40,6 +43,9 @@ pub enum GetError {
 	/// Property was missing
 	Missing,
 
+	/// Property existed, but was `undefined`
+	Null,
+
 	/// Property was the wrong type
 	WrongType(JsValue),
 }
@@ -3,12 +3,14 @@
 // Imports
 use {
 	crate::{Document, EventTarget, Object, WebError, event_target::EventTargetFields, object::ObjectFields},
+	std::sync::nonpoison::Mutex,
 	zutil_inheritance::{FromFields, Value},
 };
 
 zutil_inheritance::value! {
 	pub struct Window(EventTarget, Object): Send + Sync + Debug {
 		document: Document,
+		scroll: Mutex<(f64, f64)>,
 	}
 	impl Self {}
 }
@@ -19,6 +21,7 @@ impl Window {
 		Self::from_fields((
 			WindowFields {
 				document: Document::new(location),
+				scroll:   Mutex::new((0.0, 0.0)),
 			},
 			EventTargetFields::default(),
 			ObjectFields::default(),
@@ -34,4 +37,18 @@ impl Window {
 	pub fn document_ref(&self) -> &Document {
 		&self.fields().document
 	}
+
+	// Note: There's no actual scrolling during server-side rendering, we
+	//       only keep track of the last position we scrolled to.
+	pub fn scroll_x(&self) -> Result<f64, WebError> {
+		Ok(self.fields().scroll.lock().0)
+	}
+
+	pub fn scroll_y(&self) -> Result<f64, WebError> {
+		Ok(self.fields().scroll.lock().1)
+	}
+
+	pub fn scroll_to_with_x_and_y(&self, x: f64, y: f64) {
+		*self.fields().scroll.lock() = (x, y);
+	}
 }
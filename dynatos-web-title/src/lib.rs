@@ -1,20 +1,23 @@
 //! Title management for `dynatos`
 
-// TODO: It seems that titles aren't getting dropped for some reason.
-
 // Features
 #![feature(macro_attr)]
 #![cfg_attr(not(feature = "sync"), feature(thread_local))]
 
 // Imports
 use {
-	dynatos_sync_types::{IMut, thread_local_or_global},
+	dynatos_reactive::Effect,
+	dynatos_sync_types::{IMut, SyncBounds, thread_local_or_global},
 	dynatos_util::HoleyStack,
-	dynatos_web::{DynatosWebCtx, ObjectSetProp, cfg_ssr_expr},
+	dynatos_web::{DynatosWebCtx, ObjectRemoveProp, ObjectSetProp, cfg_ssr_expr},
 	js_sys::Object,
 	web_sys as _,
 };
 
+// Note: `dynatos-web-ssr` is only used by the ssr tests
+#[cfg(feature = "ssr")]
+use dynatos_web_ssr as _;
+
 /// Title stack.
 #[thread_local_or_global]
 static TITLE_STACK: IMut<HoleyStack<String>> = IMut::new(HoleyStack::new());
@@ -27,35 +30,116 @@ pub struct Title {
 	/// Title index
 	title_idx: usize,
 	ctx:       DynatosWebCtx,
+
+	/// Effect updating the title, if dynamic
+	effect: Option<Effect>,
 }
 
 impl Title {
 	/// Creates a title.
+	///
+	/// If a [`TitleTemplate`] exists in the store, it's applied to the title.
 	pub fn new<S>(ctx: &DynatosWebCtx, title: S) -> Self
 	where
 		S: Into<String>,
 	{
 		let title: String = title.into();
+		let title = ctx
+			.store()
+			.with::<TitleTemplate, _, _>(|template| template.apply(&title))
+			.unwrap_or(title);
 
-		// If no title exists, add the current one
-		let mut stack = TITLE_STACK.lock();
-		if stack.is_empty() {
-			stack.push(ctx.document().title());
-		}
+		Self::new_raw(ctx, title)
+	}
+
+	/// Creates a dynamic title.
+	///
+	/// Whenever any dependencies of `title` change, the title is updated.
+	/// If this isn't the most recent title, the update will only be visible
+	/// once all newer titles are dropped.
+	///
+	/// If a [`TitleTemplate`] exists in the store, it's applied to the title.
+	#[track_caller]
+	pub fn dyn_new<F>(ctx: &DynatosWebCtx, title: F) -> Self
+	where
+		F: SyncBounds + Fn() -> String + 'static,
+	{
+		let template = ctx.store().try_get::<TitleTemplate>();
+
+		// Note: We reserve our place in the stack first, and only then set the title,
+		//       once the effect runs.
+		let title_idx = Self::push(ctx, String::new());
+		let mut this = Self {
+			title_idx,
+			ctx: ctx.clone(),
+			effect: None,
+		};
+		let ctx = ctx.clone();
+		let effect = Effect::new(move || {
+			let title = title();
+			let title = match &template {
+				Some(template) => template.apply(&title),
+				None => title,
+			};
+
+			let mut stack = TITLE_STACK.lock();
+			*stack.get_mut(title_idx).expect("Title was already taken") = title;
+
+			// Note: We might not be the top title, in which case this is a no-op.
+			let top_title = stack.top().expect("Should contain at least 1 title");
+			ctx.document().set_title(top_title);
+		});
+		this.effect = Some(effect.unsize());
+
+		this
+	}
 
-		// Then set the title and add ours to the stack
-		ctx.document().set_title(&title);
-		let title_idx = stack.push(title);
+	/// Creates a title from a template and a page title.
+	///
+	/// Any [`TitleTemplate`] in the store is ignored, in favor of `template`.
+	pub fn with_template<S>(ctx: &DynatosWebCtx, template: &TitleTemplate, page: S) -> Self
+	where
+		S: AsRef<str>,
+	{
+		Self::new_raw(ctx, template.apply(page.as_ref()))
+	}
+
+	/// Creates a title, without applying any templates
+	fn new_raw(ctx: &DynatosWebCtx, title: String) -> Self {
+		// Note: We must push before setting the title, so the current title
+		//       gets added to the stack if it's empty.
+		let title_idx = Self::push(ctx, title);
+		let stack = TITLE_STACK.lock();
+		let title = stack.top().expect("Should contain at least 1 title");
+		ctx.document().set_title(title);
+		drop(stack);
 
 		Self {
 			title_idx,
 			ctx: ctx.clone(),
+			effect: None,
 		}
 	}
+
+	/// Pushes a title onto the stack, without setting it.
+	///
+	/// Returns the index of the title.
+	fn push(ctx: &DynatosWebCtx, title: String) -> usize {
+		// If no title exists, add the current one
+		let mut stack = TITLE_STACK.lock();
+		if stack.is_empty() {
+			stack.push(ctx.document().title());
+		}
+
+		stack.push(title)
+	}
 }
 
 impl Drop for Title {
 	fn drop(&mut self) {
+		// Stop updating our title
+		drop(self.effect.take());
+
 		// Remove our title
 		let mut stack = TITLE_STACK.lock();
 		let _prev_title = stack.pop(self.title_idx).expect("Title was already taken");
@@ -66,12 +150,62 @@ impl Drop for Title {
 	}
 }
 
+/// Title template.
+///
+/// When present in the store, all titles created with [`Title::new`]
+/// are formatted with this template.
+#[derive(Clone, Debug)]
+pub struct TitleTemplate {
+	/// Template
+	template: String,
+}
+
+impl TitleTemplate {
+	/// Placeholder for the page title in the template
+	pub const PLACEHOLDER: &str = "%s";
+
+	/// Creates a new title template.
+	///
+	/// Every [`PLACEHOLDER`](Self::PLACEHOLDER) in `template` will be replaced
+	/// by the page title.
+	pub fn new<S>(template: S) -> Self
+	where
+		S: Into<String>,
+	{
+		Self {
+			template: template.into(),
+		}
+	}
+
+	/// Applies this template to a page title
+	#[must_use]
+	pub fn apply(&self, page: &str) -> String {
+		self.template.replace(Self::PLACEHOLDER, page)
+	}
+}
+
+/// Property name for attached titles
+const TITLE_PROP: &str = "__dynatos_web_title";
+
+/// A wasm `Title` type.
+#[cfg(feature = "csr")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+#[expect(dead_code, reason = "We just want to keep the field alive, not use it")]
+struct WasmTitle(Title);
+
 /// Extension trait to attach a title to an object.
 #[extend::ext(name = ObjectAttachTitle)]
 pub impl Object {
-	/// Attaches a title to this object
+	/// Attaches a title to this object.
+	///
+	/// Any title previously attached to this object is removed first.
+	///
+	/// Note that the title is only dropped once the object's property is.
+	/// On the client, this doesn't happen when the object is garbage collected,
+	/// so you should call [`remove_title`](ObjectRemoveTitle::remove_title) once
+	/// the title is no longer needed.
 	fn attach_title(&self, ctx: &DynatosWebCtx, title: &str) {
-		let prop_name = "__dynatos_web_title";
+		_ = self.remove_title();
 		let title = Title::new(ctx, title);
 
 		let title = cfg_ssr_expr!(
@@ -79,17 +213,40 @@ pub impl Object {
 				use wasm_bindgen::JsValue;
 				JsValue::from_any(title)
 			},
+			csr = WasmTitle(title)
+		);
+
+		self.set_prop(TITLE_PROP, title);
+	}
+}
+
+/// Extension trait to remove a title from an object.
+#[extend::ext(name = ObjectRemoveTitle)]
+pub impl Object {
+	/// Removes the title attached to this object, restoring the previous title.
+	///
+	/// Returns if a title was attached
+	fn remove_title(&self) -> bool {
+		cfg_ssr_expr!(
+			ssr = self.remove_prop(TITLE_PROP),
 			csr = {
-				/// A wasm `Title` type.
-				#[wasm_bindgen::prelude::wasm_bindgen]
-				#[expect(dead_code, reason = "We just want to keep the field alive, not use it")]
-				struct WasmTitle(Title);
+				use wasm_bindgen::convert::TryFromJsValue;
 
-				WasmTitle(title)
-			}
-		);
+				let title = js_sys::Reflect::get(self, &TITLE_PROP.into()).expect("Unable to get object property");
+				if title.is_undefined() {
+					return false;
+				}
+				_ = self.remove_prop(TITLE_PROP);
 
-		self.set_prop(prop_name, title);
+				// Note: Converting it back takes ownership of the title from
+				//       the js object, so we can drop it.
+				let title = WasmTitle::try_from_js_value(title)
+					.unwrap_or_else(|title| panic!("Attached title was the wrong type: {title:?}"));
+				drop(title);
+
+				true
+			}
+		)
 	}
 }
 
@@ -107,3 +264,151 @@ where
 		self
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn template_apply() {
+		let template = TitleTemplate::new("%s - My site");
+		assert_eq!(template.apply("Home"), "Home - My site");
+		assert_eq!(template.apply(""), " - My site");
+
+		assert_eq!(TitleTemplate::new("%s | %s").apply("a"), "a | a");
+		assert_eq!(TitleTemplate::new("My site").apply("a"), "My site");
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod ssr_tests {
+	// Imports
+	use {
+		super::*,
+		dynatos_reactive::{Signal, SignalGetCloned, SignalSet},
+		dynatos_web::html,
+	};
+
+	/// Lock for the title stack.
+	///
+	/// Tests are run in parallel, but share the title stack, so
+	/// each test must hold this lock while it uses it.
+	static LOCK: IMut<()> = IMut::new(());
+
+	/// Creates a new server-side rendering context
+	fn ctx() -> DynatosWebCtx {
+		let state = dynatos_web_ssr::State::new("http://localhost/".to_owned());
+		DynatosWebCtx::new(state).expect("Unable to create web context")
+	}
+
+	#[test]
+	fn nested_template() {
+		let _lock = LOCK.lock();
+		let ctx = self::ctx();
+		let initial_title = ctx.document().title();
+		ctx.store().set(TitleTemplate::new("%s - My site"));
+
+		let outer = Title::new(&ctx, "Home");
+		assert_eq!(ctx.document().title(), "Home - My site");
+
+		let inner = Title::new(&ctx, "Settings");
+		assert_eq!(ctx.document().title(), "Settings - My site");
+
+		let custom = Title::with_template(&ctx, &TitleTemplate::new("%s | Admin"), "Users");
+		assert_eq!(ctx.document().title(), "Users | Admin");
+
+		drop(custom);
+		assert_eq!(ctx.document().title(), "Settings - My site");
+
+		drop(inner);
+		assert_eq!(ctx.document().title(), "Home - My site");
+
+		drop(outer);
+		assert_eq!(ctx.document().title(), initial_title);
+	}
+
+	#[test]
+	fn out_of_order_drop() {
+		let _lock = LOCK.lock();
+		let ctx = self::ctx();
+
+		let outer = Title::new(&ctx, "Outer");
+		let middle = Title::new(&ctx, "Middle");
+		let inner = Title::new(&ctx, "Inner");
+
+		// Note: Dropping a title that isn't the top one shouldn't change the title
+		drop(middle);
+		assert_eq!(ctx.document().title(), "Inner");
+
+		drop(inner);
+		assert_eq!(ctx.document().title(), "Outer");
+
+		drop(outer);
+	}
+
+	#[test]
+	fn attach_remove() {
+		let _lock = LOCK.lock();
+		let ctx = self::ctx();
+		let initial_title = ctx.document().title();
+		let div = html::div(&ctx);
+		let object = AsRef::<Object>::as_ref(&div);
+
+		let outer = Title::new(&ctx, "Outer");
+
+		object.attach_title(&ctx, "First");
+		assert_eq!(ctx.document().title(), "First");
+
+		// Note: Re-attaching should drop the first title, so it isn't restored later
+		object.attach_title(&ctx, "Second");
+		assert_eq!(ctx.document().title(), "Second");
+
+		assert!(object.remove_title(), "Title wasn't attached");
+		assert_eq!(ctx.document().title(), "Outer");
+
+		assert!(!object.remove_title(), "Title was removed twice");
+		assert_eq!(ctx.document().title(), "Outer");
+
+		drop(outer);
+		assert_eq!(ctx.document().title(), initial_title);
+	}
+
+	#[test]
+	fn dyn_new() {
+		let _lock = LOCK.lock();
+		let ctx = self::ctx();
+		let initial_title = ctx.document().title();
+
+		let outer = Title::new(&ctx, "Outer");
+		assert_eq!(ctx.document().title(), "Outer");
+
+		let count = Signal::new(0);
+		let title = Title::dyn_new(&ctx, {
+			let ctx = ctx.clone();
+			let count = count.clone();
+			move || {
+				// Note: Reserving our place in the stack shouldn't clear the title.
+				assert_ne!(ctx.document().title(), "", "Title was cleared");
+				format!("Inbox ({})", count.get_cloned())
+			}
+		});
+		assert_eq!(ctx.document().title(), "Inbox (0)");
+
+		count.set(5);
+		assert_eq!(ctx.document().title(), "Inbox (5)");
+
+		// Note: Updates while we aren't the top title shouldn't be visible
+		let inner = Title::new(&ctx, "Inner");
+		count.set(6);
+		assert_eq!(ctx.document().title(), "Inner");
+		drop(inner);
+		assert_eq!(ctx.document().title(), "Inbox (6)");
+
+		drop(title);
+		assert_eq!(ctx.document().title(), "Outer");
+
+		drop(outer);
+		assert_eq!(ctx.document().title(), initial_title);
+	}
+}
@@ -120,6 +120,8 @@ impl_as_parent! {
 						web_sys::HtmlHeadElement: {},
 						web_sys::HtmlImageElement: {},
 						web_sys::HtmlInputElement: {},
+						web_sys::HtmlOptionElement: {},
+						web_sys::HtmlSelectElement: {},
 						web_sys::HtmlTextAreaElement: {},
 					}
 				},
@@ -0,0 +1,31 @@
+//! Clipboard
+
+// Imports
+use {crate::DynatosWebCtx, core::future::Future, web_sys::WebError};
+
+/// Copies `text` to the clipboard.
+///
+/// The returned future resolves once the text has been written. This may fail
+/// if the page isn't focused, or if the user denied the clipboard permission.
+///
+/// Note that the text is captured immediately, and not when the future is polled.
+pub fn copy_to_clipboard(ctx: &DynatosWebCtx, text: &str) -> impl Future<Output = Result<(), WebError>> + use<> {
+	crate::cfg_ssr_expr!(
+		ssr = {
+			// Note: There's no clipboard during server-side rendering
+			_ = (ctx, text);
+			async {
+				Err(WebError(app_error::app_error!(
+					"Clipboard is unavailable during server-side rendering"
+				)))
+			}
+		},
+		csr = {
+			let promise = ctx.window().navigator().clipboard().write_text(text);
+			async move {
+				wasm_bindgen_futures::JsFuture::from(promise).await?;
+				Ok(())
+			}
+		},
+	)
+}
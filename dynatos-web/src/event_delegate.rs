@@ -0,0 +1,242 @@
+//! Delegated event listener
+
+// Imports
+use {
+	crate::{DynatosWebCtx, EventListener, ListenerHandle},
+	dynatos_sync_types::SyncBounds,
+	web_sys::{Element, EventTarget},
+};
+
+/// Extension trait to define a delegated event listener on an event target
+#[extend::ext(name = EventTargetAddDelegatedListener)]
+pub impl EventTarget {
+	/// Adds a delegated event listener to this target.
+	///
+	/// Instead of adding a listener to each element, a single listener is added to
+	/// this target. Whenever an event reaches it, the ancestors of the event's target
+	/// are walked (up to, but excluding this target), and `f` is called with the first
+	/// element matched by `matcher`. If no element matches, `f` isn't called.
+	///
+	/// Since the event must bubble up to this target, events that don't bubble won't
+	/// be seen, unless the listener is in the capture phase.
+	///
+	/// In ssr, events aren't delegated, so this does nothing.
+	fn add_delegated_event_listener<E, M>(
+		&self,
+		ctx: &DynatosWebCtx,
+		matcher: M,
+		f: impl SyncBounds + Fn(Element, E::Event) + 'static,
+	) -> ListenerHandle
+	where
+		E: EventListener<Event: AsRef<web_sys::Event>>,
+		M: DelegateMatch,
+	{
+		crate::cfg_ssr_expr!(
+			ssr = {
+				// Note: In ssr, events are only dispatched to the element they were
+				//       added to, so there's no ancestor chain to walk.
+				_ = (ctx, matcher, f);
+				ListenerHandle::detached()
+			},
+			csr = {
+				use crate::EventTargetAddListener;
+
+				self.add_event_listener::<E>(ctx, move |ev| {
+					if let Some(el) = self::find_match(ev.as_ref(), &matcher) {
+						f(el, ev);
+					}
+				})
+			},
+		)
+	}
+}
+
+/// Extension trait to define a delegated event listener on an event target
+#[extend::ext(name = EventTargetWithDelegatedListener)]
+pub impl<T> T
+where
+	T: AsRef<EventTarget>,
+{
+	/// Adds a delegated event listener to this target.
+	///
+	/// See [`add_delegated_event_listener`](EventTargetAddDelegatedListener::add_delegated_event_listener)
+	/// for details.
+	///
+	/// Returns the type, for chaining
+	fn with_delegated_event_listener<E, M>(
+		self,
+		ctx: &DynatosWebCtx,
+		matcher: M,
+		f: impl SyncBounds + Fn(Element, E::Event) + 'static,
+	) -> Self
+	where
+		E: EventListener<Event: AsRef<web_sys::Event>>,
+		M: DelegateMatch,
+	{
+		self.as_ref().add_delegated_event_listener::<E, M>(ctx, matcher, f);
+		self
+	}
+}
+
+/// Finds the first ancestor of the event's target matched by `matcher`.
+///
+/// The ancestors are walked until the event's current target.
+#[cfg(feature = "csr")]
+fn find_match<M: DelegateMatch>(ev: &web_sys::Event, matcher: &M) -> Option<Element> {
+	use crate::JsCast;
+
+	let root = ev.current_target()?;
+	let node = ev.target()?.dyn_into::<web_sys::Node>().ok()?;
+	self::find_ancestor_match(node, &root, matcher)
+}
+
+/// Finds the first ancestor of `node` (including itself) matched by `matcher`.
+///
+/// The ancestors are walked until `root`, which is never matched.
+#[cfg(any(feature = "csr", test))]
+fn find_ancestor_match<M: DelegateMatch>(mut node: web_sys::Node, root: &EventTarget, matcher: &M) -> Option<Element> {
+	loop {
+		if AsRef::<EventTarget>::as_ref(&node) == root {
+			return None;
+		}
+
+		let el = crate::cfg_ssr_expr!(
+			ssr = {
+				use zutil_inheritance::Downcast;
+				node.clone().downcast::<Element>().ok()
+			},
+			csr = {
+				use crate::JsCast;
+				node.dyn_ref::<Element>().cloned()
+			},
+		);
+		if let Some(el) = el &&
+			matcher.matches(&el)
+		{
+			return Some(el);
+		}
+
+		node = node.parent_node()?;
+	}
+}
+
+/// Matcher for delegated event listeners
+pub trait DelegateMatch: SyncBounds + 'static {
+	/// Returns whether `el` is matched
+	fn matches(&self, el: &Element) -> bool;
+}
+
+impl<F> DelegateMatch for F
+where
+	F: SyncBounds + Fn(&Element) -> bool + 'static,
+{
+	fn matches(&self, el: &Element) -> bool {
+		self(el)
+	}
+}
+
+/// Matches elements using a css selector
+#[derive(Clone, Debug)]
+pub struct DelegateSelector<S>(pub S);
+
+impl<S> DelegateMatch for DelegateSelector<S>
+where
+	S: SyncBounds + AsRef<str> + 'static,
+{
+	fn matches(&self, el: &Element) -> bool {
+		el.matches(self.0.as_ref()).expect("Invalid selector")
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::{ElementWithClass, NodeWithChildren, html, test_util},
+		web_sys::HtmlElement,
+	};
+
+	/// Creates an element with a tag name, id, classes and attributes
+	fn element(ctx: &DynatosWebCtx) -> HtmlElement {
+		let button = html::button(ctx);
+		button.set_attribute("id", "submit").expect("Unable to set attribute");
+		button.set_class_name("btn primary");
+		button
+			.set_attribute("data-action", "save")
+			.expect("Unable to set attribute");
+		button
+	}
+
+	#[test]
+	fn selector() {
+		let ctx = test_util::ctx();
+		let el = self::element(&ctx);
+		let el = AsRef::<Element>::as_ref(&el);
+
+		for selector in [
+			"button",
+			"#submit",
+			".btn",
+			".btn.primary",
+			"button#submit.primary",
+			"[data-action]",
+			"[data-action=save]",
+			"[data-action=\"save\"]",
+			"a, .primary",
+		] {
+			assert!(
+				DelegateSelector(selector).matches(el),
+				"Selector {selector:?} didn't match"
+			);
+		}
+
+		for selector in [
+			"a",
+			"#cancel",
+			".secondary",
+			".btn.secondary",
+			"[data-id]",
+			"[data-action=load]",
+		] {
+			assert!(!DelegateSelector(selector).matches(el), "Selector {selector:?} matched");
+		}
+	}
+
+	#[test]
+	fn find_ancestor() {
+		let ctx = test_util::ctx();
+		let span = html::span(&ctx);
+		let item = html::li(&ctx).with_class("item").with_child(span.clone());
+		let root = html::ul(&ctx).with_child(item.clone());
+		let find = |matcher: DelegateSelector<&'static str>| {
+			super::find_ancestor_match(span.clone().into(), root.as_ref(), &matcher)
+		};
+
+		// Note: The root is skipped, even if it matches
+		assert_eq!(find(DelegateSelector(".item")), Some(item.into()));
+		assert_eq!(find(DelegateSelector("span")), Some(span.clone().into()));
+		assert_eq!(find(DelegateSelector("ul")), None);
+		assert_eq!(find(DelegateSelector("a")), None);
+	}
+
+	#[test]
+	#[should_panic = "Invalid selector"]
+	fn selector_invalid() {
+		let ctx = test_util::ctx();
+		let el = self::element(&ctx);
+		DelegateSelector("button > span").matches(el.as_ref());
+	}
+
+	#[test]
+	fn closure() {
+		let ctx = test_util::ctx();
+		let el = self::element(&ctx);
+
+		let matcher = |el: &Element| el.has_attribute("disabled");
+		assert!(!matcher.matches(el.as_ref()));
+
+		el.set_attribute("disabled", "").expect("Unable to set attribute");
+		assert!(matcher.matches(el.as_ref()));
+	}
+}
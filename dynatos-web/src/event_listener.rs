@@ -1,24 +1,45 @@
 //! Event listener
 
 // Imports
+#[cfg(feature = "csr")]
+use core::cell::Cell;
 use {
 	crate::DynatosWebCtx,
+	core::fmt,
 	dynatos_sync_types::SyncBounds,
 	dynatos_util::{TryOrReturnExt, web::cfg_ssr_expr},
 	js_sys::WeakRef,
 	wasm_bindgen::{ErasableGeneric, JsValue, convert::FromWasmAbi},
 	web_sys::EventTarget,
 };
+#[cfg(feature = "ssr")]
+use {
+	core::sync::atomic::{self, AtomicBool},
+	std::sync::Arc,
+};
 
 /// Extension trait to define an event listener on an event target with a closure
 #[extend::ext(name = EventTargetAddListener)]
 pub impl EventTarget {
 	/// Adds an event listener to this target
-	fn add_event_listener<E>(&self, ctx: &DynatosWebCtx, f: impl SyncBounds + Fn(E::Event) + 'static)
+	fn add_event_listener<E>(&self, ctx: &DynatosWebCtx, f: impl SyncBounds + Fn(E::Event) + 'static) -> ListenerHandle
+	where
+		E: EventListener,
+	{
+		self.add_event_listener_untyped(ctx, E::name(), f)
+	}
+
+	/// Adds an event listener to this target with options
+	fn add_event_listener_with_options<E>(
+		&self,
+		ctx: &DynatosWebCtx,
+		options: EventListenerOptions,
+		f: impl SyncBounds + Fn(E::Event) + 'static,
+	) -> ListenerHandle
 	where
 		E: EventListener,
 	{
-		self.add_event_listener_untyped(ctx, E::name(), f);
+		self.add_event_listener_untyped_with_options(ctx, E::name(), options, f)
 	}
 
 	/// Adds an untyped event listener to this target
@@ -27,22 +48,71 @@ pub impl EventTarget {
 		ctx: &DynatosWebCtx,
 		event_type: &str,
 		f: impl SyncBounds + Fn(Ev) + 'static,
-	) {
+	) -> ListenerHandle {
+		self.add_event_listener_untyped_with_options(ctx, event_type, EventListenerOptions::default(), f)
+	}
+
+	/// Adds an untyped event listener to this target with options
+	fn add_event_listener_untyped_with_options<Ev: FromWasmAbi>(
+		&self,
+		ctx: &DynatosWebCtx,
+		event_type: &str,
+		options: EventListenerOptions,
+		f: impl SyncBounds + Fn(Ev) + 'static,
+	) -> ListenerHandle {
 		cfg_ssr_expr!(
-			ssr = self
-				.add_event_listener_with_callback(ctx.ssr_state(), event_type, f)
-				.expect("Unable to add event listener"),
+			ssr = {
+				// Note: Events are dispatched by form submissions in ssr, so
+				//       there are no phases or scrolling to configure.
+				let removed = Arc::new(AtomicBool::new(false));
+				let f = {
+					let removed = Arc::clone(&removed);
+					move |ev| {
+						let was_removed = match options.once {
+							true => removed.swap(true, atomic::Ordering::AcqRel),
+							false => removed.load(atomic::Ordering::Acquire),
+						};
+						if was_removed {
+							return;
+						}
+						f(ev);
+					}
+				};
+				self.add_event_listener_with_callback(ctx.ssr_state(), event_type, f)
+					.expect("Unable to add event listener");
+
+				ListenerHandle { removed }
+			},
 			csr = {
 				use crate::util;
 
 				let _: &DynatosWebCtx = ctx;
 
+				let js_options = web_sys::AddEventListenerOptions::new();
+				js_options.set_once(options.once);
+				js_options.set_passive(options.passive);
+				js_options.set_capture(options.capture);
+
 				// Then add it
 				// TODO: Can this fail? On MDN, nothing seems to mention it can throw.
-				self.add_event_listener_with_callback(event_type, &util::csr::js_fn::<dyn Fn(Ev)>(f))
-					.expect("Unable to add event listener");
+				let callback = util::csr::js_fn::<dyn Fn(Ev)>(f);
+				self.add_event_listener_with_callback_and_add_event_listener_options(
+					event_type,
+					&callback,
+					&js_options,
+				)
+				.expect("Unable to add event listener");
+
+				ListenerHandle {
+					inner: Cell::new(Some(ListenerHandleInner {
+						target: WeakRef::new(self),
+						event_type: event_type.to_owned(),
+						callback,
+						capture: options.capture,
+					})),
+				}
 			}
-		);
+		)
 	}
 }
 
@@ -62,6 +132,22 @@ where
 		self.as_ref().add_event_listener::<E>(ctx, f);
 		self
 	}
+
+	/// Adds an event listener to this target with options
+	///
+	/// Returns the type, for chaining
+	fn with_event_listener_with_options<E>(
+		self,
+		ctx: &DynatosWebCtx,
+		options: EventListenerOptions,
+		f: impl SyncBounds + Fn(E::Event) + 'static,
+	) -> Self
+	where
+		E: EventListener,
+	{
+		self.as_ref().add_event_listener_with_options::<E>(ctx, options, f);
+		self
+	}
 }
 
 /// Extension trait to define an event listener on an element with a closure
@@ -71,7 +157,11 @@ where
 	ET: SyncBounds + ErasableGeneric<Repr = JsValue> + AsRef<EventTarget> + 'static,
 {
 	/// Adds an event listener to this target
-	fn add_event_listener_el<E>(&self, ctx: &DynatosWebCtx, f: impl SyncBounds + Fn(ET, E::Event) + 'static)
+	fn add_event_listener_el<E>(
+		&self,
+		ctx: &DynatosWebCtx,
+		f: impl SyncBounds + Fn(ET, E::Event) + 'static,
+	) -> ListenerHandle
 	where
 		E: EventListener,
 	{
@@ -82,7 +172,7 @@ where
 		<ET as AsRef<EventTarget>>::as_ref(self).add_event_listener::<E>(ctx, move |ev| {
 			let el = el.deref().or_return()?;
 			f(el, ev);
-		});
+		})
 	}
 
 	/// Adds an event listener to this target
@@ -97,6 +187,88 @@ where
 	}
 }
 
+/// Event listener handle.
+///
+/// Allows removing an event listener before its target is dropped.
+///
+/// Dropping the handle does *not* remove the listener.
+pub struct ListenerHandle {
+	/// Inner
+	#[cfg(feature = "csr")]
+	inner: Cell<Option<ListenerHandleInner>>,
+
+	/// Whether the listener was removed
+	// Note: In ssr, listeners can't be removed from their target, so
+	//       instead the listener checks this before being called.
+	#[cfg(feature = "ssr")]
+	removed: Arc<AtomicBool>,
+}
+
+/// Inner data for [`ListenerHandle`]
+#[cfg(feature = "csr")]
+struct ListenerHandleInner {
+	/// Target
+	target: WeakRef<EventTarget>,
+
+	/// Event type
+	event_type: String,
+
+	/// Callback
+	callback: js_sys::Function,
+
+	/// Whether the listener was added for the capture phase
+	capture: bool,
+}
+
+impl ListenerHandle {
+	/// Creates a handle for a listener that isn't added to any target
+	#[cfg(feature = "ssr")]
+	pub(crate) fn detached() -> Self {
+		Self {
+			removed: Arc::new(AtomicBool::new(true)),
+		}
+	}
+
+	/// Removes the event listener.
+	///
+	/// If the listener was already removed, or the target no longer exists, does nothing.
+	pub fn remove(&self) {
+		#[cfg(feature = "ssr")]
+		self.removed.store(true, atomic::Ordering::Release);
+
+		#[cfg(feature = "csr")]
+		if let Some(inner) = self.inner.take() &&
+			let Some(target) = inner.target.deref()
+		{
+			target
+				.remove_event_listener_with_callback_and_bool(&inner.event_type, &inner.callback, inner.capture)
+				.expect("Unable to remove event listener");
+		}
+	}
+}
+
+impl fmt::Debug for ListenerHandle {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ListenerHandle").finish_non_exhaustive()
+	}
+}
+
+/// Event listener options
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct EventListenerOptions {
+	/// Whether the listener should be removed after its first invocation
+	pub once: bool,
+
+	/// Whether the listener will never call `preventDefault`.
+	///
+	/// Allows the browser to scroll without waiting for the listener.
+	pub passive: bool,
+
+	/// Whether the listener should be invoked in the capture phase,
+	/// instead of the bubbling phase.
+	pub capture: bool,
+}
+
 /// Event listener
 pub trait EventListener {
 	/// Event type
@@ -151,6 +323,7 @@ mod ev {
 		dragstart: web_sys::DragEvent;
 		dragover: web_sys::DragEvent;
 		popstate: web_sys::PopStateEvent;
+		hashchange: web_sys::HashChangeEvent;
 		pointermove: web_sys::PointerEvent;
 		pointerdown: web_sys::PointerEvent;
 		pointerup: web_sys::PointerEvent;
@@ -161,3 +334,75 @@ mod ev {
 		contextmenu: web_sys::PointerEvent;
 	}
 }
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::{html, test_util},
+		dynatos_util::Counter,
+	};
+
+	#[test]
+	fn listener() {
+		static CLICKS: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let button = html::button(&ctx).with_event_listener::<ev!(click)>(&ctx, |_ev| CLICKS.bump());
+
+		test_util::click(&ctx, &button);
+		test_util::click(&ctx, &button);
+		assert_eq!(CLICKS.get(), 2);
+	}
+
+	#[test]
+	fn listener_once() {
+		static CLICKS: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let options = EventListenerOptions {
+			once: true,
+			..EventListenerOptions::default()
+		};
+		let button =
+			html::button(&ctx).with_event_listener_with_options::<ev!(click)>(&ctx, options, |_ev| CLICKS.bump());
+
+		test_util::click(&ctx, &button);
+		test_util::click(&ctx, &button);
+		assert_eq!(CLICKS.get(), 1, "Listener added with `once` was called more than once");
+	}
+
+	#[test]
+	fn listener_el() {
+		static CLICKS: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let button = html::button(&ctx);
+		button.set_attribute("id", "button").expect("Unable to set attribute");
+		let button = button.with_event_listener_el::<ev!(click)>(&ctx, |button, _ev| {
+			assert_eq!(button.get_attribute("id").ok().as_deref(), Some("button"));
+			CLICKS.bump();
+		});
+
+		test_util::click(&ctx, &button);
+		assert_eq!(CLICKS.get(), 1);
+	}
+
+	#[test]
+	fn remove() {
+		static CLICKS: Counter = Counter::new();
+
+		let ctx = test_util::ctx();
+		let button = html::button(&ctx);
+		let handle = button.add_event_listener::<ev!(click)>(&ctx, |_ev| CLICKS.bump());
+
+		test_util::click(&ctx, &button);
+		assert_eq!(CLICKS.get(), 1);
+
+		handle.remove();
+		handle.remove();
+		test_util::click(&ctx, &button);
+		assert_eq!(CLICKS.get(), 1, "Listener was called after being removed");
+	}
+}
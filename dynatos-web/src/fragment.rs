@@ -0,0 +1,77 @@
+//! Fragment
+
+// Imports
+use {crate::Children, dynatos_sync_types::RcPtr, web_sys::WebError};
+
+/// A group of sibling nodes, without a wrapper element.
+///
+/// Unlike a `DocumentFragment`, the nodes aren't moved out of the fragment
+/// when it's added to a parent, so the same fragment may be added, removed
+/// and re-added as a unit (e.g. as a reactive child).
+///
+/// Cloning a fragment is cheap, and the clone refers to the same nodes.
+#[derive(Clone, Debug)]
+pub struct Fragment {
+	/// Nodes
+	nodes: RcPtr<Vec<web_sys::Node>>,
+}
+
+impl Fragment {
+	/// Creates a new fragment from `nodes`
+	pub fn new<I>(nodes: I) -> Self
+	where
+		I: IntoIterator<Item: AsRef<web_sys::Node>>,
+	{
+		let nodes = nodes.into_iter().map(|node| node.as_ref().clone()).collect::<Vec<_>>();
+		Self {
+			nodes: RcPtr::new(nodes),
+		}
+	}
+
+	/// Creates a new, empty, fragment
+	#[must_use]
+	pub fn empty() -> Self {
+		Self {
+			nodes: RcPtr::new(vec![]),
+		}
+	}
+
+	/// Returns the nodes of this fragment
+	#[must_use]
+	pub fn nodes(&self) -> &[web_sys::Node] {
+		&self.nodes
+	}
+
+	/// Returns the number of nodes in this fragment
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.nodes.len()
+	}
+
+	/// Returns if this fragment has no nodes
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.nodes.is_empty()
+	}
+}
+
+impl<N> FromIterator<N> for Fragment
+where
+	N: AsRef<web_sys::Node>,
+{
+	fn from_iter<I: IntoIterator<Item = N>>(iter: I) -> Self {
+		Self::new(iter)
+	}
+}
+
+impl Children for Fragment {
+	fn append_all(self, node: &web_sys::Node) -> Result<(), WebError> {
+		(&self).append_all(node)
+	}
+}
+
+impl Children for &'_ Fragment {
+	fn append_all(self, node: &web_sys::Node) -> Result<(), WebError> {
+		self.nodes().append_all(node)
+	}
+}
@@ -141,7 +141,7 @@ decl_elements! {
 	object,
 	ol,
 	optgroup,
-	option,
+	option: web_sys::HtmlOptionElement,
 	output,
 	p,
 	param,
@@ -161,7 +161,7 @@ decl_elements! {
 	script,
 	search,
 	section,
-	select,
+	select: web_sys::HtmlSelectElement,
 	shadow,
 	slot,
 	small,
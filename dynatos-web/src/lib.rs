@@ -7,19 +7,45 @@
 
 // Modules
 mod as_parent;
+pub mod clipboard;
 mod ctx;
+mod event_delegate;
 mod event_listener;
+mod fragment;
 pub mod html;
+mod mount;
+mod node_key;
 mod object_attach_value;
 pub mod parse;
+pub mod svg;
+#[cfg(all(test, feature = "ssr"))]
+mod test_util;
+pub mod timer;
 mod util;
 
 // Exports
 pub use {
 	self::{
 		ctx::DynatosWebCtx,
-		event_listener::{ElementAddListener, EventListener, EventTargetAddListener, EventTargetWithListener, ev},
-		object_attach_value::{ObjectAttachValue, ObjectWithValue},
+		event_delegate::{
+			DelegateMatch,
+			DelegateSelector,
+			EventTargetAddDelegatedListener,
+			EventTargetWithDelegatedListener,
+		},
+		event_listener::{
+			ElementAddListener,
+			EventListener,
+			EventListenerOptions,
+			EventTargetAddListener,
+			EventTargetWithListener,
+			ListenerHandle,
+			ev,
+		},
+		fragment::Fragment,
+		mount::{MountHandle, mount},
+		node_key::NodeKey,
+		object_attach_value::{ObjectAttachValue, ObjectGetValue, ObjectWithValue},
 		parse::{parse, parse_html_element},
 	},
 	dynatos_util::web::*,
@@ -35,6 +61,68 @@ use {self::as_parent::AsParent, itertools::Itertools, web_sys::WebError};
 /// # Expression
 /// This macro supports expressions using an empty tag: `<>this_is_a_variable</>`
 ///
+/// # Text interpolation
+/// Text may contain arguments using `%{expr}%`. By default, these are dynamic,
+/// and the text node is updated whenever any signals used by `expr` change.
+///
+/// The following prefixes change how the argument is evaluated:
+/// - `%{static expr}%`: `expr` is evaluated only once, when creating the text node.
+/// - `%{dyn expr}%`: Same as the default.
+/// - `%{~signal}%`: `signal` is a signal, whose value is displayed. This
+///   requires `dynatos_reactive` to be available.
+///
+/// Entities in the text outside of arguments, such as `&lt;` or `&#x3c;`, are
+/// decoded. See [`dynatos_web_parser::decode_entities`] for the supported entities.
+///
+/// Attribute values may also contain arguments: `<a href="/users/%{id}%">`. If any
+/// of them are dynamic, the attribute is updated whenever any signals used change,
+/// using `ElementWithDynAttr::with_dyn_attr`. Otherwise, it's set only once.
+/// This doesn't apply to `:attr="expr"` attributes, since their value is already
+/// an expression.
+///
+/// # Dynamic attributes
+/// Attributes may be given a reactive value using braces: `<input disabled={is_disabled} />`.
+/// The value may be any type that implements `WithDynText`, and is set using
+/// `ElementWithDynAttr::with_dyn_attr`. This requires `dynatos_web_reactive` to be available.
+///
+/// # Lists
+/// Children may be repeated for each item of an iterator using
+/// `<for each={iter} item={pat}>...</for>`, where `pat` is bound
+/// to each item. This may only be used inside of an element.
+///
+/// The loop runs once, when the element is created, so there is no
+/// keying or re-use of nodes. For a reactive list, use an expression
+/// instead (e.g. `<>move || items.get_cloned()</>`), which re-renders
+/// all of its children whenever it changes, or `NodeWithDynFor::with_dyn_for`
+/// from `dynatos_web_reactive`, which only updates the items that changed.
+///
+/// # Fragments
+/// Expressions may be spliced into an element once, without any reactivity,
+/// using `<>static expr</>`. The value of `expr` must implement [`Children`],
+/// and all of its nodes are added to the element.
+///
+/// This allows components to return multiple root nodes, for example by returning
+/// the `[Node; _]` array of an inner `html!` with multiple roots, or `impl Children`.
+/// Since the value is consumed when added, the component is called once per use.
+///
+/// At the top-level, there's no element to add the nodes to, so the value of `expr`
+/// is used as-is, just like `<>expr</>`.
+///
+/// # Keys
+/// The `key` attribute is consumed by the macro, instead of being rendered as an
+/// attribute. Its value is attached to the element as a [`NodeKey`] using
+/// [`ObjectAttachValue`], so that list reconciliation may identify the element.
+/// Use `key="..."` for a constant key, or `:key="expr"` to use the value of `expr`,
+/// converted with `ToString`.
+///
+/// # Attribute checks
+/// Attributes that aren't known for an html element, such as `<a hreff="...">`,
+/// emit a warning. To make them an error instead, use `html!(#[html(strict)] "...")`.
+///
+/// Global attributes, as well as `data-*`, `aria-*` and `on*` attributes, are known for
+/// all elements. Expression (`:attr`) and event listener (`@attr`) attributes, as well as
+/// any attributes of svg elements or unknown elements, aren't checked.
+///
 /// # Output type
 /// The type will be `[Node; _]` if there are both `Element`s and `Text` nodes in the html.
 ///
@@ -82,14 +170,35 @@ pub impl<T> T
 where
 	T: AsRef<web_sys::Element>,
 {
+	/// Sets the inner html of this element.
+	///
+	/// If `html` is `None`, all children of the element are removed.
+	///
+	/// # Security
+	/// `html` is parsed as-is, so it must be trusted. Any user-provided html
+	/// may inject scripts into the page. For untrusted content, use
+	/// [`with_sanitized_html`](ElementWithInnerHtml::with_sanitized_html) instead.
 	fn with_inner_html<C>(self, html: C) -> Self
 	where
 		C: AsTextContent,
 	{
-		// TODO: Is a default of `""` fine here?
 		self.as_ref().set_inner_html(html.as_text().unwrap_or(""));
 		self
 	}
+
+	/// Sets the inner html of this element, after passing it through `sanitizer`.
+	///
+	/// `sanitizer` receives the html and should return it with anything
+	/// unsafe removed (or escaped). It isn't called if `html` is `None`,
+	/// in which case all children of the element are removed.
+	fn with_sanitized_html<C, S>(self, html: C, sanitizer: S) -> Self
+	where
+		C: AsTextContent,
+		S: FnOnce(&str) -> String,
+	{
+		let html = html.as_text().map(sanitizer);
+		self.with_inner_html(html)
+	}
 }
 
 /// Types that may be used for [`NodeWithText`]
@@ -137,6 +246,16 @@ pub impl web_sys::Node {
 	{
 		children.append_all(self)
 	}
+
+	/// Adds all children yielded by `children`.
+	///
+	/// See [`IterChildren`] for details.
+	fn add_children_iter<I>(&self, children: I)
+	where
+		I: IntoIterator<Item: Child>,
+	{
+		self.add_children(IterChildren(children));
+	}
 }
 
 /// Extension trait to add children to an node
@@ -166,6 +285,16 @@ where
 	{
 		children.append_all(self.as_ref()).map(|()| self)
 	}
+
+	/// Adds all children yielded by `children`.
+	///
+	/// See [`IterChildren`] for details.
+	fn with_children_iter<I>(self, children: I) -> Self
+	where
+		I: IntoIterator<Item: Child>,
+	{
+		self.with_children(IterChildren(children))
+	}
 }
 
 /// Types that may be used for [`NodeWithChildren`]'s single child methods
@@ -239,6 +368,28 @@ where
 	}
 }
 
+/// Children from an iterator.
+///
+/// Allows adding the children yielded by any iterator (e.g. a `map` or `filter`),
+/// without first collecting them.
+// Note: We can't implement `Children` for all iterators directly, since it
+//       would conflict with the impl for all `Child`ren.
+#[derive(Clone, Copy, Debug)]
+pub struct IterChildren<I>(pub I);
+
+impl<I> Children for IterChildren<I>
+where
+	I: IntoIterator<Item: Child>,
+{
+	fn append_all(self, node: &web_sys::Node) -> Result<(), WebError> {
+		for child in self.0 {
+			child.append(node)?;
+		}
+
+		Ok(())
+	}
+}
+
 /// Implements `Children` on tuples
 macro impl_children_tuple( $( $( $C:ident($idx:tt) ),*; )* ) {
 	$(
@@ -405,6 +556,56 @@ pub impl web_sys::Element {
 	}
 }
 
+/// Extension trait to remove a class
+#[extend::ext_sized(name = ElementRemoveClass)]
+pub impl web_sys::Element {
+	fn remove_class<C>(&self, class: C)
+	where
+		C: AsRef<str>,
+	{
+		self.remove_classes([class]);
+	}
+
+	fn remove_classes<I, C>(&self, classes: I)
+	where
+		I: IntoIterator<Item = C>,
+		C: AsRef<str>,
+	{
+		// Remove all classes from the existing class name and set it.
+		let classes = classes.into_iter().collect::<Vec<_>>();
+		let class_name = self
+			.class_name()
+			.split_whitespace()
+			.filter(|class| !classes.iter().any(|remove_class| remove_class.as_ref() == *class))
+			.join(" ");
+
+		self.set_class_name(&class_name);
+	}
+}
+
+/// Extension trait to toggle a class
+#[extend::ext_sized(name = ElementToggleClass)]
+pub impl web_sys::Element {
+	/// Adds `class` if `on`, otherwise removes it.
+	///
+	/// Unlike [`add_class`](ElementAddClass::add_class), the class
+	/// isn't added again if the element already has it.
+	fn toggle_class<C>(&self, class: C, on: bool)
+	where
+		C: AsRef<str>,
+	{
+		let has_class = self
+			.class_name()
+			.split_whitespace()
+			.any(|cur_class| cur_class == class.as_ref());
+		match (on, has_class) {
+			(true, false) => self.add_class(class),
+			(false, true) => self.remove_class(class),
+			(true, true) | (false, false) => (),
+		}
+	}
+}
+
 /// Extension trait to *append* a class in a builder-style.
 #[extend::ext_sized(name = ElementWithClass)]
 pub impl<T> T
@@ -498,6 +699,9 @@ pub enum GetError {
 	/// Property was missing
 	Missing,
 
+	/// Property existed, but was `null` or `undefined`
+	Null,
+
 	/// Property was the wrong type
 	WrongType(JsValue),
 }
@@ -512,16 +716,21 @@ pub impl Object {
 		cfg_ssr_expr!(
 			ssr = self.get_prop_inner(prop).map_err(|err| match err {
 				dynatos_web_ssr::object::GetError::Missing => GetError::Missing,
+				dynatos_web_ssr::object::GetError::Null => GetError::Null,
 				dynatos_web_ssr::object::GetError::WrongType(ty) => GetError::WrongType(ty),
 			}),
 			csr = {
-				// Note: This returning `Err` should only happen if `self` isn't an object,
+				// Note: These returning `Err` should only happen if `self` isn't an object,
 				//       which we guarantee, so no errors can occur.
-				let value = js_sys::Reflect::get(self, &prop.into()).expect("Unable to get object property");
-
-				if value.is_undefined() {
+				let prop = prop.into();
+				if !js_sys::Reflect::has(self, &prop).expect("Unable to check object property") {
 					return Err(GetError::Missing);
 				}
+				let value = js_sys::Reflect::get(self, &prop).expect("Unable to get object property");
+
+				if value.is_null() || value.is_undefined() {
+					return Err(GetError::Null);
+				}
 
 				value.dyn_into().map_err(GetError::WrongType)
 			}
@@ -563,3 +772,143 @@ pub impl web_sys::Window {
 
 /// Html namespace
 const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+/// Svg namespace
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+// Note: The tests use a fake `JsValue` as the parent node, which is `csr`-only.
+#[cfg(all(test, feature = "csr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		core::{cell::RefCell, iter},
+	};
+
+	/// Child that records its index when appended
+	struct IdxChild<'a> {
+		appended: &'a RefCell<Vec<usize>>,
+		idx:      usize,
+	}
+
+	impl Child for IdxChild<'_> {
+		fn append(&self, _node: &web_sys::Node) -> Result<(), WebError> {
+			self.appended.borrow_mut().push(self.idx);
+			Ok(())
+		}
+	}
+
+	/// Returns a parent node for the children.
+	// Note: The children don't use the node, so we don't need a real one.
+	fn parent_node() -> web_sys::Node {
+		JsValue::NULL.unchecked_into()
+	}
+
+	#[test]
+	fn iter_children_map() {
+		let appended = RefCell::new(vec![]);
+		IterChildren((0..5).map(|idx| IdxChild {
+			appended: &appended,
+			idx,
+		}))
+		.append_all(&parent_node())
+		.expect("Unable to append children");
+		assert_eq!(*appended.borrow(), [0, 1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn iter_children_empty() {
+		IterChildren(iter::empty::<IdxChild<'_>>())
+			.append_all(&parent_node())
+			.expect("Unable to append children");
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod ssr_tests {
+	// Imports
+	use {super::*, crate::test_util};
+
+	#[test]
+	fn toggle_class() {
+		let ctx = test_util::ctx();
+		let div = html::div(&ctx).with_class("item");
+
+		div.toggle_class("active", true);
+		assert_eq!(div.class_name(), "item active");
+
+		// Note: The class isn't added twice
+		div.toggle_class("active", true);
+		assert_eq!(div.class_name(), "item active");
+
+		div.toggle_class("active", false);
+		assert_eq!(div.class_name(), "item");
+
+		div.toggle_class("active", false);
+		assert_eq!(div.class_name(), "item");
+	}
+
+	#[test]
+	fn with_sanitized_html() {
+		/// Removes all `<script>` elements and `on*` attributes
+		fn sanitize(mut html: &str) -> String {
+			let mut output = String::new();
+			while !html.is_empty() {
+				if let Some(rest) = html.strip_prefix("<script>") {
+					let end = rest.find("</script>").expect("Missing `</script>`");
+					html = &rest[end + "</script>".len()..];
+					continue;
+				}
+				if let Some(rest) = html.strip_prefix(" on") {
+					let end = rest.find('"').expect("Missing attribute value");
+					let rest = &rest[end + 1..];
+					let end = rest.find('"').expect("Missing attribute value");
+					html = &rest[end + 1..];
+					continue;
+				}
+
+				let ch = html.chars().next().expect("Html shouldn't be empty");
+				output.push(ch);
+				html = &html[ch.len_utf8()..];
+			}
+
+			output
+		}
+
+		let ctx = test_util::ctx();
+		let div = html::div(&ctx).with_sanitized_html(
+			r#"<p onclick="alert(1)">a</p><script>alert(2)</script><img src="b.png" onerror="alert(3)">"#,
+			sanitize,
+		);
+		assert_eq!(div.outer_html(), r#"<div><p>a</p><img src="b.png"></div>"#);
+
+		// Note: The sanitizer isn't called when clearing
+		let div = div.with_sanitized_html(None, |_| panic!("Sanitizer was called"));
+		assert_eq!(div.outer_html(), "<div></div>");
+	}
+
+	#[test]
+	fn object_get() {
+		let ctx = test_util::ctx();
+		let div = html::div(&ctx);
+
+		assert!(matches!(div.get::<web_sys::Element>("a"), Err(GetError::Missing)));
+
+		// Note: In ssr, there's no `null`, so we use `undefined`
+		div.set_prop("a", JsValue::UNDEFINED);
+		assert!(matches!(div.get::<web_sys::Element>("a"), Err(GetError::Null)));
+
+		div.set_prop("a", "value");
+		assert!(matches!(div.get::<web_sys::Element>("a"), Err(GetError::WrongType(_))));
+
+		let span = web_sys::Node::from(html::span(&ctx));
+		div.set_prop("a", span.clone());
+		assert!(matches!(
+			div.get::<web_sys::HtmlInputElement>("a"),
+			Err(GetError::WrongType(_))
+		));
+
+		let value = div.get::<web_sys::Element>("a").expect("Unable to get property");
+		assert_eq!(web_sys::Node::from(value), span);
+	}
+}
@@ -0,0 +1,119 @@
+//! Mounting
+
+// Imports
+use {
+	crate::{DynatosWebCtx, JsResultContext},
+	app_error::{AppError, app_error},
+	core::{fmt, mem},
+	dynatos_util::web::cfg_ssr_expr,
+	web_sys::Node,
+};
+
+/// Mounts the node returned by `view` onto the element matched by `selector`.
+///
+/// The node is appended as the last child of the first element matched
+/// by `selector`, and is removed once the returned handle is dropped.
+/// `view` is only called if an element was matched.
+///
+/// # SSR
+/// There's no selector engine during server-side rendering, so only
+/// the `body` and `head` selectors are supported.
+pub fn mount<F, N>(ctx: &DynatosWebCtx, selector: &str, view: F) -> Result<MountHandle, AppError>
+where
+	F: FnOnce() -> N,
+	N: AsRef<Node>,
+{
+	let target = cfg_ssr_expr!(
+		ssr = match selector {
+			"body" => AsRef::<Node>::as_ref(ctx.body()).clone(),
+			"head" => AsRef::<Node>::as_ref(ctx.head()).clone(),
+			_ => {
+				return Err(app_error!(
+					"Only the `body` and `head` selectors may be mounted onto during server-side rendering, found \
+					 {selector:?}"
+				));
+			},
+		},
+		csr = {
+			let target = ctx
+				.document()
+				.query_selector(selector)
+				.context("Unable to query selector")?
+				.ok_or_else(|| app_error!("No element matched selector {selector:?}"))?;
+			Node::from(target)
+		}
+	);
+
+	let node = AsRef::<Node>::as_ref(&view()).clone();
+	target.append_child(&node).context("Unable to append view")?;
+
+	Ok(MountHandle { target, node })
+}
+
+/// Mount handle.
+///
+/// Removes the mounted node once dropped.
+pub struct MountHandle {
+	/// Target
+	target: Node,
+
+	/// Mounted node
+	node: Node,
+}
+
+impl MountHandle {
+	/// Returns the mounted node
+	#[must_use]
+	pub const fn node(&self) -> &Node {
+		&self.node
+	}
+
+	/// Forgets this handle, keeping the node mounted forever
+	pub const fn forget(self) {
+		mem::forget(self);
+	}
+}
+
+impl Drop for MountHandle {
+	fn drop(&mut self) {
+		// Note: If the node was already removed, there's nothing to do
+		_ = self.target.remove_child(&self.node);
+	}
+}
+
+impl fmt::Debug for MountHandle {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("MountHandle").finish_non_exhaustive()
+	}
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::{html, test_util},
+	};
+
+	#[test]
+	fn body() {
+		let ctx = test_util::ctx();
+		let body = AsRef::<Node>::as_ref(ctx.body());
+
+		let handle = mount(&ctx, "body", || html::div(&ctx)).expect("Unable to mount");
+		let node = handle.node().clone();
+		assert_eq!(node.parent_node().as_ref(), Some(body));
+		assert!(body.contains(Some(&node)));
+
+		drop(handle);
+		assert_eq!(node.parent_node(), None);
+		assert!(!body.contains(Some(&node)));
+	}
+
+	#[test]
+	fn unsupported_selector() {
+		let ctx = test_util::ctx();
+		mount(&ctx, "#app", || -> Node { panic!("View shouldn't be called") })
+			.expect_err("Mounting onto an unsupported selector should fail");
+	}
+}
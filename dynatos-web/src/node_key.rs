@@ -0,0 +1,16 @@
+//! Node key
+
+/// Key of a node, identifying it within a list.
+///
+/// Created by the `key` attribute in [`html!`](crate::html), and attached
+/// to the element with [`ObjectAttachValue`](crate::ObjectAttachValue), so
+/// it may be retrieved with `element.get_value::<NodeKey>()`.
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct NodeKey(pub String);
+
+impl NodeKey {
+	/// Creates a new key
+	pub fn new(key: impl Into<String>) -> Self {
+		Self(key.into())
+	}
+}
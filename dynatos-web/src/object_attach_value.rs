@@ -3,72 +3,55 @@
 // Imports
 use {
 	crate::{ObjectGet, ObjectSetProp},
+	core::any::{Any, TypeId},
 	dynatos_sync_types::SyncBounds,
 	dynatos_util::web::cfg_ssr_expr,
 	js_sys::Object,
+	std::collections::HashMap,
 };
 
+/// Property name for the attached values
+// TODO: Use an static anonymous symbol?
+const PROP_NAME: &str = "__dynatos_values";
+
+/// Attached values, by type.
+///
+/// Values of the same type are stored in the order they were attached.
+type Values = HashMap<TypeId, Vec<Box<dyn Any + SyncBounds>>>;
+
 /// Extension trait to add a value to an object
 #[extend::ext(name = ObjectAttachValue)]
 pub impl Object {
-	/// Attaches a value to this object
+	/// Attaches a value to this object.
+	///
+	/// Values are stored by their type, so values of different types don't
+	/// replace each other. Attaching several values of the same type keeps all
+	/// of them, with the latest one being returned by [`ObjectGetValue::get_value`].
 	fn attach_value<T>(&self, value: T)
 	where
 		T: SyncBounds + 'static,
 	{
-		// TODO: Use an static anonymous symbol?
-		let prop_name = "__dynatos_values";
-
-		cfg_ssr_expr!(
-			ssr = {
-				use {
-					dynatos_web_ssr::{ObjectAttachValueValues, ObjectAttachValueValuesFields},
-					std::sync::nonpoison::Mutex,
-					zutil_inheritance::{FromFields, Value},
-				};
-
-				let ctx_handles = match self.get::<ObjectAttachValueValues>(prop_name) {
-					Ok(effects) => effects,
-					Err(crate::GetError::WrongType(err)) => panic!("Effects map was the wrong type: {err:?}"),
-					Err(crate::GetError::Missing) => {
-						let handles = ObjectAttachValueValues::from_fields((
-							ObjectAttachValueValuesFields {
-								values: Mutex::new(vec![]),
-							},
-							<Object as Value>::Fields::default(),
-						));
-						self.set_prop(prop_name, handles.clone());
-
-						handles
-					},
-				};
-
-				ctx_handles.fields().values.lock().push(Box::new(value));
-			},
-			csr = {
-				use core::any::Any;
-
-				/// A wasm value
-				#[wasm_bindgen::prelude::wasm_bindgen]
-				#[expect(dead_code, reason = "We just want to keep the field alive, not use it")]
-				struct WasmValue(Box<dyn Any>);
-
-				// Get the values array, or create it, if it doesn't exist
-				let values = match self.get::<js_sys::Array>(prop_name) {
-					Ok(values) => values,
-					Err(crate::GetError::WrongType(err)) => panic!("Values aray was the wrong type: {err:?}"),
-					Err(crate::GetError::Missing) => {
-						let values = js_sys::Array::new();
-						self.set_prop(prop_name, &values);
-						values
-					},
-				};
-
-				// Then push the values
-				let value = WasmValue(Box::new(value));
-				values.push(&value.into());
-			}
-		)
+		self::with_values(self, |values| {
+			values.entry(TypeId::of::<T>()).or_default().push(Box::new(value));
+		});
+	}
+}
+
+/// Extension trait to get a value attached to an object
+#[extend::ext(name = ObjectGetValue)]
+pub impl Object {
+	/// Gets the latest value of type `T` attached to this object.
+	///
+	/// See [`ObjectAttachValue::attach_value`] for details.
+	fn get_value<T>(&self) -> Option<T>
+	where
+		T: Clone + 'static,
+	{
+		self::with_values(self, |values| {
+			let value = values.get(&TypeId::of::<T>())?.last()?;
+			let value = (**value).downcast_ref::<T>().expect("Value was the wrong type");
+			Some(value.clone())
+		})
 	}
 }
 
@@ -89,3 +72,88 @@ where
 		self
 	}
 }
+
+/// Uses the values attached to `object`, creating them if they don't exist
+fn with_values<F, O>(object: &Object, f: F) -> O
+where
+	F: FnOnce(&mut Values) -> O,
+{
+	cfg_ssr_expr!(
+		ssr = {
+			use {
+				dynatos_web_ssr::{ObjectAttachValueValues, ObjectAttachValueValuesFields},
+				std::sync::nonpoison::Mutex,
+				zutil_inheritance::{FromFields, Value},
+			};
+
+			let values = match object.get::<ObjectAttachValueValues>(PROP_NAME) {
+				Ok(values) => values,
+				Err(crate::GetError::WrongType(err)) => panic!("Values map was the wrong type: {err:?}"),
+				Err(crate::GetError::Missing | crate::GetError::Null) => {
+					let values = ObjectAttachValueValues::from_fields((
+						ObjectAttachValueValuesFields {
+							values: Mutex::new(HashMap::new()),
+						},
+						<Object as Value>::Fields::default(),
+					));
+					object.set_prop(PROP_NAME, values.clone());
+
+					values
+				},
+			};
+
+			f(&mut values.fields().values.lock())
+		},
+		csr = {
+			use {
+				core::cell::RefCell,
+				std::rc::Rc,
+				wasm_bindgen::{JsValue, convert::TryFromJsValue},
+			};
+
+			/// A wasm value
+			#[wasm_bindgen::prelude::wasm_bindgen]
+			struct WasmValues(Rc<RefCell<Values>>);
+
+			// Get the values map, or create it, if it doesn't exist
+			// Note: We can't borrow the values from javascript, so instead we take
+			//       them and immediately set back a clone, before using them.
+			//       This ensures that any nested calls will see the same values.
+			let values = match object.get::<JsValue>(PROP_NAME) {
+				Ok(values) => {
+					let values = WasmValues::try_from_js_value(values)
+						.unwrap_or_else(|values| panic!("Values map was the wrong type: {values:?}"));
+					Rc::clone(&values.0)
+				},
+				Err(crate::GetError::WrongType(err)) => panic!("Values map was the wrong type: {err:?}"),
+				Err(crate::GetError::Missing | crate::GetError::Null) => Rc::new(RefCell::new(HashMap::new())),
+			};
+			object.set_prop(PROP_NAME, JsValue::from(WasmValues(Rc::clone(&values))));
+
+			f(&mut values.borrow_mut())
+		}
+	)
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {
+		super::*,
+		crate::{html, test_util},
+	};
+
+	#[test]
+	fn attach_multiple_types() {
+		let ctx = test_util::ctx();
+		let div = html::div(&ctx);
+
+		div.attach_value(5_u32);
+		div.attach_value("a".to_owned());
+		div.attach_value(6_u32);
+
+		assert_eq!(div.get_value::<u32>(), Some(6));
+		assert_eq!(div.get_value::<String>(), Some("a".to_owned()));
+		assert_eq!(div.get_value::<i32>(), None);
+	}
+}
@@ -6,7 +6,7 @@ use {crate::EventTargetAddListener, core::marker::PhantomData};
 use {
 	crate::{DynatosWebCtx, ElementAddAttr, NodeAddChildren, WebError},
 	dynatos_util::web::cfg_ssr_expr,
-	dynatos_web_parser::{XHtml, XHtmlElement, XHtmlNode},
+	dynatos_web_parser::{XHtml, XHtmlAttr, XHtmlElement, XHtmlNode},
 	web_sys::{Element, HtmlElement, Node},
 };
 
@@ -21,7 +21,7 @@ pub fn parse_html_element(
 	input: &str,
 	mut environment: impl Environment,
 ) -> Result<HtmlElement, Error> {
-	let html = XHtml::parse(input).map_err(Error::parse)?;
+	let html = XHtml::parse(input).map_err(Error::Parse)?;
 
 	let is_whitespace_only = |s: &str| s.chars().all(char::is_whitespace);
 	let element = match *html.children.as_slice() {
@@ -54,7 +54,7 @@ pub fn parse_html_element(
 
 /// Parses html at runtime, emitting it as an [`HtmlElement`](HtmlElement) list
 pub fn parse(ctx: &DynatosWebCtx, input: &str, mut environment: impl Environment) -> Result<Vec<Node>, Error> {
-	let html = XHtml::parse(input).map_err(Error::parse)?;
+	let html = XHtml::parse(input).map_err(Error::Parse)?;
 	let children = html
 		.children
 		.iter()
@@ -76,12 +76,18 @@ fn parse_xhtml_node(
 	}
 }
 
-fn parse_xhtml_text(ctx: &DynatosWebCtx, mut text: &str, environment: &mut impl Environment) -> Result<Node, Error> {
+fn parse_xhtml_text(ctx: &DynatosWebCtx, text: &str, environment: &mut impl Environment) -> Result<Node, Error> {
+	let text = self::interpolate_text(ctx, text, environment)?;
+	Ok(crate::text(ctx, &text).into())
+}
+
+/// Interpolates all `%{expr}%` arguments in `text` and decodes any entities
+fn interpolate_text(ctx: &DynatosWebCtx, mut text: &str, environment: &mut impl Environment) -> Result<String, Error> {
 	let mut output = String::new();
 	while !text.is_empty() {
 		// Find the first escape
 		let Some(start) = text.find("%{") else {
-			output.push_str(text);
+			output.push_str(&dynatos_web_parser::decode_entities(text));
 			break;
 		};
 
@@ -89,7 +95,7 @@ fn parse_xhtml_text(ctx: &DynatosWebCtx, mut text: &str, environment: &mut impl
 			return Err(Error::TextEscapeEnd);
 		};
 
-		output.push_str(&text[..start]);
+		output.push_str(&dynatos_web_parser::decode_entities(&text[..start]));
 		text = &text[start..];
 
 		let expr = &text[2..end];
@@ -99,7 +105,7 @@ fn parse_xhtml_text(ctx: &DynatosWebCtx, mut text: &str, environment: &mut impl
 		output.push_str(&expr);
 	}
 
-	Ok(crate::text(ctx, &output).into())
+	Ok(output)
 }
 
 fn parse_xhtml_element(
@@ -120,8 +126,14 @@ fn parse_xhtml_element(
 			.map_err(Error::CreateElement)?,
 	};
 
-	#[expect(clippy::iter_over_hash_type, reason = "Attributes are unordered")]
-	for (&key, &value) in &xhtml_element.attrs {
+	for attr in &xhtml_element.attrs {
+		let (key, value) = match *attr {
+			XHtmlAttr::Static { name, value } => (name, value),
+
+			// Note: Dynamic attributes need to be compiled, so we can't support them here.
+			XHtmlAttr::Dyn { name, .. } => return Err(Error::DynAttr { attr: name.to_owned() }),
+		};
+
 		match key {
 			key if let Some(key) = key.strip_prefix(':') => {
 				let value = environment.eval_attr(ctx, key, value)?;
@@ -133,7 +145,10 @@ fn parse_xhtml_element(
 				environment.eval_ev(ctx, &element, event_type, value)?;
 			},
 
-			key => element.add_attr(key, value.unwrap_or("")),
+			key => {
+				let value = self::interpolate_text(ctx, value.unwrap_or(""), environment)?;
+				element.add_attr(key, value);
+			},
 		}
 	}
 
@@ -147,12 +162,8 @@ fn parse_xhtml_element(
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
-	#[error("Unable to parse html at {input:?}")]
-	Parse {
-		input: String,
-		#[source]
-		err:   dynatos_web_parser::Error,
-	},
+	#[error("Unable to parse html")]
+	Parse(#[source] dynatos_web_parser::ParseError),
 
 	#[error("Expected a single element")]
 	SingleElement,
@@ -169,6 +180,9 @@ pub enum Error {
 	#[error("Found an event listener attribute without a value")]
 	EventListenerValue,
 
+	#[error("Dynamic attribute {attr:?} is only supported by the `html!` macro")]
+	DynAttr { attr: String },
+
 	#[error("Missing element name {element_name:?} in environment")]
 	EnvironmentMissingElement { element_name: String },
 
@@ -186,14 +200,6 @@ pub enum Error {
 }
 
 impl Error {
-	#[must_use]
-	pub fn parse((input, err): (&str, dynatos_web_parser::Error)) -> Self {
-		Self::Parse {
-			input: input.to_owned(),
-			err,
-		}
-	}
-
 	#[must_use]
 	pub fn eval_element(element_name: &str) -> Self {
 		Self::EnvironmentMissingElement {
@@ -0,0 +1,93 @@
+//! SVG elements
+
+// Imports
+use {crate::DynatosWebCtx, web_sys::WebError};
+
+/// Expands to `$name` if `$name` exists, else expands to `$default`
+macro or_default_name {
+	($default:ident, $name:literal $(,)?) => {
+		$name
+	},
+	($default:ident $(,)?) => {
+		stringify!($default)
+	},
+}
+
+/// Declares all elements
+macro decl_elements(
+	$( $fn_name:ident $( = $el_name:literal )? ),* $(,)?
+) {
+	$(
+		#[must_use]
+		pub fn $fn_name(ctx: &DynatosWebCtx) -> web_sys::Element {
+			self::element(ctx, or_default_name!($fn_name, $( $el_name )?))
+		}
+	)*
+}
+
+/// Creates the svg element `el_name`.
+///
+/// Useful for elements that don't have their own function.
+#[must_use]
+pub fn element(ctx: &DynatosWebCtx, el_name: &str) -> web_sys::Element {
+	ctx.document()
+		.create_element_ns(Some(crate::SVG_NAMESPACE), el_name)
+		.unwrap_or_else(|err| self::on_create_fail(&err, el_name))
+}
+
+/// Function called when creating an element fails
+#[cold]
+fn on_create_fail(err: &WebError, el_name: &str) -> ! {
+	panic!(
+		"Unable to create element {el_name:?} on namespace {:?}: {err:?}",
+		crate::SVG_NAMESPACE
+	);
+}
+
+decl_elements! {
+	a,
+	circle,
+	clip_path = "clipPath",
+	defs,
+	desc,
+	ellipse,
+	foreign_object = "foreignObject",
+	g,
+	image,
+	line,
+	linear_gradient = "linearGradient",
+	marker,
+	mask,
+	path,
+	pattern,
+	polygon,
+	polyline,
+	radial_gradient = "radialGradient",
+	rect,
+	stop,
+	svg,
+	symbol,
+	text,
+	text_path = "textPath",
+	title,
+	tspan,
+	r#use = "use",
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+	// Imports
+	use {super::*, crate::test_util};
+
+	#[test]
+	fn namespace() {
+		let ctx = test_util::ctx();
+		for el in [
+			self::circle(&ctx),
+			self::linear_gradient(&ctx),
+			self::element(&ctx, "feGaussianBlur"),
+		] {
+			assert_eq!(el.namespace_uri().as_deref(), Some(crate::SVG_NAMESPACE));
+		}
+	}
+}
@@ -0,0 +1,28 @@
+//! Test utilities
+
+// Imports
+use {
+	crate::DynatosWebCtx,
+	web_sys::{Element, EventTarget, PointerEvent},
+};
+
+/// Creates a new server-side rendering context
+pub fn ctx() -> DynatosWebCtx {
+	let state = dynatos_web_ssr::State::new("http://localhost/".to_owned());
+	DynatosWebCtx::new(state).expect("Unable to create web context")
+}
+
+/// Dispatches a click event to `el`.
+///
+/// In ssr, click listeners are dispatched through a form, so we find the
+/// handler through the form it's associated with.
+pub fn click(ctx: &DynatosWebCtx, el: &Element) {
+	let form_id = el.get_attribute("form").expect("Element has no click listener");
+	let handler_idx = form_id
+		.strip_prefix("dynatos-form-")
+		.and_then(|idx| idx.parse().ok())
+		.unwrap_or_else(|| panic!("Invalid form id: {form_id:?}"));
+
+	EventTarget::handle(ctx.ssr_state(), handler_idx, PointerEvent::default().into())
+		.expect("Unable to handle click event");
+}
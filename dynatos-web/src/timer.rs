@@ -0,0 +1,36 @@
+//! Timers
+
+// Imports
+use {
+	crate::DynatosWebCtx,
+	core::{future::Future, time::Duration},
+};
+
+/// Returns a future that resolves after `duration`.
+///
+/// The timeout is scheduled immediately, and not when the future is polled.
+pub fn sleep(ctx: &DynatosWebCtx, duration: Duration) -> impl Future<Output = ()> + use<> {
+	crate::cfg_ssr_expr!(
+		ssr = {
+			use core::future;
+
+			// Note: There are no timers during server-side rendering, so
+			//       we never resolve.
+			_ = (ctx, duration);
+			future::pending()
+		},
+		csr = {
+			let timeout = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+			let window = ctx.window();
+			let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+				window
+					.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, timeout)
+					.expect("Unable to set timeout");
+			});
+			async move {
+				// Note: The promise is never rejected
+				_ = wasm_bindgen_futures::JsFuture::from(promise).await;
+			}
+		},
+	)
+}
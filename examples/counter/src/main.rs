@@ -5,9 +5,9 @@
 
 // Imports
 use {
-	app_error::AppError,
-	dynatos_reactive::{Signal, SignalBorrowMut, SignalGet, SignalSet},
-	dynatos_web::{DynatosWebCtx, JsResultContext, html},
+	app_error::{AppError, Context},
+	dynatos_reactive::{Signal, SignalBorrowMut, SignalSet},
+	dynatos_web::{DynatosWebCtx, html},
 	tracing_subscriber::prelude::*,
 	zutil_cloned::cloned,
 };
@@ -33,8 +33,9 @@ fn main() {
 fn run() -> Result<(), AppError> {
 	let ctx = DynatosWebCtx::new().expect("Unable to create dynatos web context");
 
-	let counter = self::counter(&ctx);
-	ctx.body().append_child(&counter).context("Unable to append counter")?;
+	dynatos_web::mount(&ctx, "body", || self::counter(&ctx))
+		.context("Unable to mount counter")?
+		.forget();
 
 	Ok(())
 }
@@ -42,6 +43,18 @@ fn run() -> Result<(), AppError> {
 fn counter(ctx: &DynatosWebCtx) -> web_sys::HtmlElement {
 	let value = Signal::new(0);
 
+	html!(
+		r#"<div>
+			<>static self::buttons(ctx, &value)</>
+			<span>Value: %{~value}%.</span>
+		</div>"#
+	)
+}
+
+/// Buttons to modify the counter.
+///
+/// Returns multiple root nodes, which are added to the counter as a fragment.
+fn buttons(ctx: &DynatosWebCtx, value: &Signal<i32>) -> [web_sys::HtmlElement; 3] {
 	#[cloned(value)]
 	let clear = move |_ev| value.set(0);
 	#[cloned(value)]
@@ -50,11 +63,8 @@ fn counter(ctx: &DynatosWebCtx) -> web_sys::HtmlElement {
 	let sub = move |_ev| *value.borrow_mut() -= 1;
 
 	html!(
-		r#"<div>
-			<button @click="clear">Clear</button>
-			<button @click="add">+</button>
-			<button @click="sub">-</button>
-			<span>Value: %{value.get()}%.</span>
-		</div>"#
+		r#"<button @click="clear">Clear</button>
+		<button @click="add">+</button>
+		<button @click="sub">-</button>"#
 	)
 }
@@ -10,7 +10,7 @@ use {
 	dynatos_reactive::{SignalBorrowMut, SignalGetCloned, SignalSet},
 	dynatos_web::{DynatosWebCtx, EventTargetWithListener, NodeWithChildren, NodeWithText, ev, html},
 	dynatos_web_reactive::NodeWithDynText,
-	dynatos_web_router::{LocationSignal, QuerySignal, SingleQuery},
+	dynatos_web_router::LocationSignal,
 	tracing_subscriber::prelude::*,
 	zutil_cloned::cloned,
 };
@@ -45,8 +45,7 @@ fn run() -> Result<(), AppError> {
 
 fn page(ctx: &DynatosWebCtx) -> web_sys::HtmlElement {
 	// TODO: If we add `.with_loadable_default()`, use it again in this example.
-	let query = SingleQuery::<i32>::new(ctx, "a");
-	let query = QuerySignal::new(ctx, query);
+	let query = ctx.store().get::<LocationSignal>().query::<i32>("a");
 
 	html::div(ctx).with_children([
 		#[cloned(query)]
@@ -0,0 +1,51 @@
+//! Prop tests
+
+// Features
+#![feature(type_changing_struct_update)]
+
+// Imports
+use dynatos_builder::builder;
+
+/// Parses a number, clamping it to `0..=10`
+fn parse_clamped(value: &str) -> u32 {
+	value.parse::<u32>().expect("Invalid number").min(10)
+}
+
+#[builder]
+fn Greeting(#[prop(with = str::to_owned as &str)] name: String, #[prop(default = 1 as u32)] times: u32) -> String {
+	name.repeat(times as usize)
+}
+
+#[builder]
+fn Clamped(#[prop(from, with = parse_clamped as &str)] value: u32) -> u32 {
+	value
+}
+
+#[test]
+fn with_transform() {
+	assert_eq!(Greeting::builder().name("hi").build(), "hi");
+	assert_eq!(Greeting::builder().times(2).name("hi").build(), "hihi");
+}
+
+#[test]
+fn with_validate() {
+	assert_eq!(Clamped::builder().value("5").build(), 5);
+	assert_eq!(Clamped::builder().value("50").build(), 10);
+	assert_eq!(Clamped::from_value("7"), 7);
+}
+
+#[builder]
+fn Required(#[prop(required)] value: u32, #[prop(default = 1 as u32)] scale: u32) -> u32 {
+	value * scale
+}
+
+#[test]
+fn required() {
+	let builder = Required::builder();
+	assert!(
+		core::any::type_name_of_val(&builder).contains("Missing_value"),
+		"Missing prop type didn't mention the prop"
+	);
+
+	assert_eq!(builder.scale(2).value(5).build(), 10);
+}
@@ -0,0 +1,117 @@
+//! `AsyncSignal` loadable tests
+
+// Imports
+use {
+	core::{
+		future,
+		sync::atomic::{self, AtomicUsize},
+	},
+	dynatos_loadable::{AsyncSignalBorrowLoadable, Loadable, LoadableSignal},
+	dynatos_reactive::{AsyncSignal, Effect, SignalGet, async_signal},
+};
+
+/// Runs `f` within a single-threaded tokio runtime
+fn block_on<F: Future>(f: F) -> F::Output {
+	tokio::runtime::Builder::new_current_thread()
+		.build()
+		.expect("Unable to create runtime")
+		.block_on(f)
+}
+
+#[test]
+fn borrow_loadable() {
+	block_on(async {
+		let signal = AsyncSignal::new(|| future::ready(Ok::<i32, ()>(5)));
+
+		let value = async_signal::with_unloaded(|| signal.borrow_loadable().map(|value| *value));
+		assert_eq!(value, Loadable::Empty);
+
+		let value = signal.borrow_loadable().map(|value| *value);
+		assert_eq!(value, Loadable::Loading);
+
+		// Let the loader run
+		tokio::task::yield_now().await;
+
+		let value = signal.borrow_loadable().map(|value| *value);
+		assert_eq!(value, Loadable::Loaded(5));
+	});
+}
+
+#[test]
+fn borrow_loadable_err() {
+	block_on(async {
+		let signal = AsyncSignal::new(|| future::ready(Err::<i32, _>("err")));
+
+		_ = signal.borrow_loadable();
+		tokio::task::yield_now().await;
+
+		let value = signal.borrow_loadable().map(|value| *value);
+		assert_eq!(value, Loadable::Err("err"));
+	});
+}
+
+#[test]
+fn state_signals() {
+	block_on(async {
+		/// Number of times the loader was called
+		static LOADS: AtomicUsize = AtomicUsize::new(0);
+
+		// Note: The first load succeeds, while any reloads fail
+		let signal = LoadableSignal::new(|| {
+			let res = match LOADS.fetch_add(1, atomic::Ordering::Relaxed) {
+				0 => Ok(5),
+				_ => Err("err"),
+			};
+			future::ready(res)
+		});
+
+		let is_empty = signal.is_empty_signal();
+		let is_loading = signal.is_loading_signal();
+		let is_loaded = signal.is_loaded_signal();
+		let is_error = signal.is_error_signal();
+		let states = || (is_empty.get(), is_loading.get(), is_loaded.get(), is_error.get());
+
+		// Accessing the state signals doesn't start loading
+		assert_eq!(states(), (true, false, false, false));
+
+		_ = signal.start_loading();
+		assert_eq!(states(), (false, true, false, false));
+
+		tokio::task::yield_now().await;
+		assert_eq!(states(), (false, false, true, false));
+
+		// Note: While reloading, the previous value is kept
+		_ = signal.restart_loading();
+		assert_eq!(states(), (false, false, true, false));
+
+		tokio::task::yield_now().await;
+		assert_eq!(states(), (false, false, true, true));
+	});
+}
+
+#[test]
+fn borrow_unloaded_no_dep() {
+	block_on(async {
+		/// Number of times the effect was run
+		static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+		let signal = LoadableSignal::new(|| future::ready(Ok::<i32, ()>(5)));
+		let _effect = Effect::new({
+			let signal = signal.clone();
+			move || {
+				_ = signal.borrow_unloaded_no_dep();
+				RUNS.fetch_add(1, atomic::Ordering::Relaxed);
+			}
+		});
+		assert_eq!(RUNS.load(atomic::Ordering::Relaxed), 1);
+
+		// Note: Neither starting nor finishing loading should re-run the effect
+		_ = signal.start_loading();
+		assert_eq!(signal.borrow_unloaded_no_dep().map(|value| *value), Loadable::Loading);
+		assert_eq!(RUNS.load(atomic::Ordering::Relaxed), 1);
+
+		tokio::task::yield_now().await;
+		assert_eq!(signal.borrow_unloaded_no_dep().map(|value| *value), Loadable::Loaded(5));
+		assert_eq!(RUNS.load(atomic::Ordering::Relaxed), 1);
+	});
+}
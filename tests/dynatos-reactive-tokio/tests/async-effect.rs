@@ -0,0 +1,119 @@
+//! Async effect tests
+
+// Features
+#![feature(proc_macro_hygiene)]
+
+// Imports
+use {
+	dynatos_reactive::{Effect, Signal, SignalGet, SignalSet},
+	dynatos_util::Counter,
+	zutil_cloned::cloned,
+};
+
+/// Runs `f` within a single-threaded tokio runtime
+fn block_on<F: Future>(f: F) -> F::Output {
+	tokio::runtime::Builder::new_current_thread()
+		.build()
+		.expect("Unable to create runtime")
+		.block_on(f)
+}
+
+/// Ensures that re-running an async effect aborts the in-flight future
+#[test]
+fn abort_in_flight() {
+	/// Counts the number of times a future started
+	static STARTED: Counter = Counter::new();
+
+	/// Counts the number of times a future finished
+	static FINISHED: Counter = Counter::new();
+
+	block_on(async {
+		let a = Signal::new(0_usize);
+
+		#[cloned(a)]
+		let _effect = Effect::new_async(move || {
+			let value = a.get();
+			async move {
+				STARTED.bump();
+				tokio::task::yield_now().await;
+				assert_eq!(value, 2, "Only the last future should finish");
+				FINISHED.bump();
+			}
+		});
+
+		// Let the first future start, then rapidly change the signal
+		tokio::task::yield_now().await;
+		assert_eq!(STARTED.get(), 1);
+		a.set(1);
+		a.set(2);
+
+		// Then let everything finish
+		for _ in 0..4 {
+			tokio::task::yield_now().await;
+		}
+		assert_eq!(STARTED.get(), 2, "Aborted future should never start");
+		assert_eq!(FINISHED.get(), 1);
+	});
+}
+
+/// Ensures that dropping an async effect aborts the in-flight future
+#[test]
+fn abort_on_drop() {
+	/// Counts the number of times a future finished
+	static FINISHED: Counter = Counter::new();
+
+	block_on(async {
+		let effect = Effect::new_async(|| async {
+			tokio::task::yield_now().await;
+			FINISHED.bump();
+		});
+
+		tokio::task::yield_now().await;
+		drop(effect);
+
+		for _ in 0..4 {
+			tokio::task::yield_now().await;
+		}
+		assert_eq!(FINISHED.get(), 0);
+	});
+}
+
+/// Ensures that a deferred effect only runs after the current call stack unwinds
+#[test]
+fn deferred() {
+	/// Counts the number of times the effect started
+	static STARTED: Counter = Counter::new();
+
+	/// Counts the number of times the effect finished
+	static FINISHED: Counter = Counter::new();
+
+	block_on(async {
+		let a = Signal::new(0_usize);
+
+		#[cloned(a)]
+		let _effect = Effect::new_deferred(move || {
+			STARTED.bump();
+			assert_eq!(STARTED.get(), FINISHED.get() + 1, "Effect shouldn't be re-entered");
+
+			// Note: Writing to our own dependency should schedule another run
+			let value = a.get();
+			if value < 2 {
+				a.set(value + 1);
+			}
+
+			FINISHED.bump();
+		});
+		assert_eq!(STARTED.get(), 0, "Effect shouldn't run while creating it");
+
+		tokio::task::yield_now().await;
+		assert_eq!(FINISHED.get(), 3);
+		assert_eq!(a.get(), 2);
+
+		// Then ensure multiple writes only result in a single run
+		a.set(5);
+		a.set(6);
+		assert_eq!(STARTED.get(), 3, "Effect shouldn't run while writing");
+		tokio::task::yield_now().await;
+		assert_eq!(FINISHED.get(), 4);
+	});
+}
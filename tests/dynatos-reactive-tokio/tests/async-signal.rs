@@ -0,0 +1,120 @@
+//! Async signal tests
+
+// Imports
+use {
+	core::{
+		future,
+		sync::atomic::{self, AtomicBool},
+	},
+	dynatos_reactive::{AsyncSignal, SignalBorrow, SignalSet},
+	futures::channel::oneshot,
+	std::sync::Arc,
+};
+
+/// Runs `f` within a single-threaded tokio runtime
+fn block_on<F: Future>(f: F) -> F::Output {
+	tokio::runtime::Builder::new_current_thread()
+		.build()
+		.expect("Unable to create runtime")
+		.block_on(f)
+}
+
+/// Ensures that mapped signals load after the original
+#[test]
+fn map() {
+	/// Mapped value
+	#[derive(PartialEq, Debug)]
+	struct Value(i32);
+
+	block_on(async {
+		let (tx, rx) = oneshot::channel::<i32>();
+		let mut rx = Some(rx);
+		let signal = AsyncSignal::new(move || rx.take().expect("Loaded twice"));
+		let mapped = signal
+			.clone()
+			.map(|value| Value(*value.as_ref().expect("Sender was dropped")));
+
+		assert!(!signal.is_loading());
+		assert!(!mapped.is_loading());
+
+		// Loading the mapped signal should load the original
+		assert!(mapped.borrow().is_none());
+		assert!(signal.is_loading());
+		assert!(mapped.is_loading());
+
+		// Then once the original loads, so should the mapped one
+		tx.send(5).expect("Unable to send value");
+		tokio::task::yield_now().await;
+		assert!(!signal.is_loading());
+		tokio::task::yield_now().await;
+		assert!(!mapped.is_loading());
+		assert_eq!(mapped.borrow().as_deref(), Some(&Value(5)));
+	});
+}
+
+/// Ensures that errors returned by the loader are observable
+#[test]
+fn load_error() {
+	block_on(async {
+		let signal = AsyncSignal::new(|| future::ready(Err::<i32, _>("err")));
+
+		assert!(signal.borrow().is_none());
+		tokio::task::yield_now().await;
+		assert_eq!(signal.borrow().as_deref(), Some(&Err("err")));
+		assert!(signal.load_panic().is_none());
+	});
+}
+
+/// Ensures that panics in the loader are caught and observable
+#[test]
+fn load_panic() {
+	block_on(async {
+		let signal = AsyncSignal::new(|| async { panic!("Loader panicked") });
+
+		assert!(signal.borrow().is_none());
+		assert!(signal.is_loading());
+		tokio::task::yield_now().await;
+
+		assert!(!signal.is_loading(), "Signal was still loading after panicking");
+		assert!(signal.borrow_unloaded().is_none());
+		let panic = signal.load_panic().expect("Panic wasn't stored");
+		assert_eq!(panic.message(), Some("Loader panicked"));
+	});
+}
+
+/// Ensures that setting the value stops loading
+#[test]
+fn set_while_loading() {
+	/// Sets a flag when dropped
+	struct DropFlag(Arc<AtomicBool>);
+
+	impl Drop for DropFlag {
+		fn drop(&mut self) {
+			self.0.store(true, atomic::Ordering::Relaxed);
+		}
+	}
+
+	block_on(async {
+		let dropped = Arc::new(AtomicBool::new(false));
+		let loader_dropped = Arc::clone(&dropped);
+		let signal = AsyncSignal::new(move || {
+			let flag = DropFlag(Arc::clone(&loader_dropped));
+			async move {
+				let _flag = flag;
+				future::pending::<i32>().await
+			}
+		});
+
+		assert!(signal.borrow().is_none());
+		assert!(signal.is_loading());
+		tokio::task::yield_now().await;
+
+		signal.set(5);
+		assert!(!signal.is_loading(), "Signal was still loading after setting it");
+		assert_eq!(signal.borrow().as_deref(), Some(&5));
+
+		tokio::task::yield_now().await;
+		assert!(dropped.load(atomic::Ordering::Relaxed), "Loading future wasn't dropped");
+		assert_eq!(signal.borrow().as_deref(), Some(&5));
+	});
+}
@@ -0,0 +1,90 @@
+//! Suspense tests
+
+// Imports
+use {
+	core::future,
+	dynatos_reactive::{AsyncSignal, Effect, SignalBorrow, SuspenseContext},
+	dynatos_util::Counter,
+	futures::channel::oneshot,
+	std::panic::{self, AssertUnwindSafe},
+};
+
+/// Runs `f` within a single-threaded tokio runtime
+fn block_on<F: Future>(f: F) -> F::Output {
+	tokio::runtime::Builder::new_current_thread()
+		.build()
+		.expect("Unable to create runtime")
+		.block_on(f)
+}
+
+/// Ensures that the context is pending until all of its signals load
+#[test]
+fn pending_two_signals() {
+	/// Counts the number of times `is_pending` changed
+	static CHANGES: Counter = Counter::new();
+
+	block_on(async {
+		let suspense = SuspenseContext::new();
+
+		let (tx1, rx1) = oneshot::channel::<i32>();
+		let (tx2, rx2) = oneshot::channel::<i32>();
+		let mut rx1 = Some(rx1);
+		let mut rx2 = Some(rx2);
+		let (signal1, signal2) = suspense.provide(|| {
+			(
+				AsyncSignal::new(move || rx1.take().expect("Loaded twice")),
+				AsyncSignal::new(move || rx2.take().expect("Loaded twice")),
+			)
+		});
+
+		let effect_suspense = suspense.clone();
+		let _effect = Effect::new(move || {
+			_ = effect_suspense.is_pending();
+			CHANGES.bump();
+		});
+		assert!(!suspense.is_pending(), "Signals shouldn't be pending before loading");
+
+		// Start loading both signals
+		_ = signal1.borrow();
+		_ = signal2.borrow();
+		assert_eq!(suspense.pending(), 2);
+
+		// Then load them one at a time
+		tx2.send(2).expect("Unable to send value");
+		tokio::task::yield_now().await;
+		assert_eq!(suspense.pending(), 1);
+		assert!(suspense.is_pending());
+
+		tx1.send(1).expect("Unable to send value");
+		tokio::task::yield_now().await;
+		assert_eq!(suspense.pending(), 0);
+		assert!(!suspense.is_pending());
+		assert_eq!(CHANGES.get(), 5, "Effect should run initially and on each change");
+	});
+}
+
+/// Ensures that signals outside of a context aren't registered
+#[test]
+fn outside_context() {
+	block_on(async {
+		let suspense = SuspenseContext::new();
+		let signal = AsyncSignal::new(|| future::ready(5_i32));
+
+		_ = signal.borrow();
+		assert!(!suspense.is_pending());
+	});
+}
+
+/// Ensures that the context is removed even if providing it panics
+#[test]
+fn provide_panic() {
+	let suspense = SuspenseContext::new();
+	let res = panic::catch_unwind(AssertUnwindSafe(|| {
+		suspense.provide(|| panic!("Panicked within context"))
+	}));
+	assert!(res.is_err(), "Expected a panic");
+	assert!(
+		SuspenseContext::current().is_none(),
+		"Context was still current after panicking"
+	);
+}
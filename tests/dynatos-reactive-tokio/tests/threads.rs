@@ -0,0 +1,57 @@
+//! Multi-threaded reactivity tests
+
+// Features
+#![feature(proc_macro_hygiene, nonpoison_mutex, sync_nonpoison)]
+
+// Imports
+use {
+	dynatos_reactive::{Derived, Effect, Signal, SignalGet, SignalSet},
+	std::{
+		sync::{Arc, nonpoison::Mutex},
+		thread,
+	},
+	zutil_cloned::cloned,
+};
+
+/// Ensures that setting a signal from another thread runs effects that depend on it
+#[test]
+fn set_from_other_thread() {
+	let a = Signal::new(0_i32);
+	let values = Arc::new(Mutex::new(vec![]));
+
+	#[cloned(a, values)]
+	let _effect = Effect::new(move || values.lock().push(a.get()));
+
+	#[cloned(a)]
+	thread::spawn(move || a.set(1)).join().expect("Thread panicked");
+	a.set(2);
+
+	assert_eq!(*values.lock(), [0, 1, 2]);
+}
+
+/// Ensures that an effect created on another thread tracks a signal from this thread
+#[test]
+fn effect_on_other_thread() {
+	let a = Signal::new(0_i32);
+	#[cloned(a)]
+	let b = Derived::new(move || a.get() * 2);
+
+	#[cloned(b)]
+	let (effect, values) = thread::spawn(move || {
+		let values = Arc::new(Mutex::new(vec![]));
+
+		#[cloned(values)]
+		let effect = Effect::new(move || values.lock().push(b.get()));
+
+		(effect, values)
+	})
+	.join()
+	.expect("Thread panicked");
+
+	a.set(1);
+	assert_eq!(*values.lock(), [0, 2]);
+
+	drop(effect);
+	a.set(2);
+	assert_eq!(*values.lock(), [0, 2]);
+}
@@ -6,9 +6,12 @@
 
 // Imports
 use {
-	dynatos_reactive::{Effect, Trigger, WeakEffect, WeakTrigger, effect},
+	core::sync::atomic::{self, AtomicUsize},
+	dynatos_reactive::{Effect, GLOBAL_WORLD, Trigger, WeakEffect, WeakTrigger, effect, without_subscriber_warning},
 	dynatos_sync_types::{OnceCell, thread_local_or_global},
 	dynatos_util::Counter,
+	std::sync::Arc,
+	tracing::{Event, Level, Metadata, Subscriber, span},
 	zutil_cloned::cloned,
 };
 
@@ -36,6 +39,30 @@ fn basic() {
 	assert_eq!(TRIGGERS.get(), 2, "Trigger was triggered after effect was dropped");
 }
 
+#[test]
+fn subscribe_once() {
+	/// Counts the number of times the effect was run
+	static TRIGGERS: Counter = Counter::new();
+
+	let trigger = Trigger::new();
+	let effect = Effect::new_raw(move || TRIGGERS.bump());
+	trigger.subscribe_once(&effect);
+	assert_eq!(TRIGGERS.get(), 0, "Effect was run early");
+
+	// The first execution runs the effect
+	drop(trigger.exec());
+	assert_eq!(TRIGGERS.get(), 1, "Effect was not run");
+
+	// But further executions don't
+	drop(trigger.exec());
+	assert_eq!(TRIGGERS.get(), 1, "Effect was run after its subscription was removed");
+
+	// Unless we subscribe again
+	trigger.subscribe_once(&effect);
+	drop(trigger.exec());
+	assert_eq!(TRIGGERS.get(), 2, "Effect was not run after re-subscribing");
+}
+
 #[test]
 fn trigger_exec_multiple() {
 	/// Counts the number of times the effect was run
@@ -167,6 +194,39 @@ fn running_stacked() {
 	assert!(running_bottom.is_inert());
 }
 
+/// Ensures that dependencies gathered with `Effect::gather_deps` are added to the existing ones
+#[test]
+fn gather_deps() {
+	/// Counts the number of times the effect was run
+	static RUNS: Counter = Counter::new();
+
+	let trigger1 = Trigger::new();
+	let trigger2 = Trigger::new();
+	#[cloned(trigger1)]
+	let effect = Effect::new(move || {
+		trigger1.gather_subs();
+		RUNS.bump();
+	});
+	assert_eq!(RUNS.get(), 1);
+
+	effect.gather_deps(|| {
+		assert_eq!(effect::running().as_ref(), Some(&effect.clone().unsize()));
+		trigger2.gather_subs();
+	});
+	assert_eq!(RUNS.get(), 1, "Gathering dependencies ran the effect");
+
+	drop(trigger2.exec());
+	assert_eq!(
+		RUNS.get(),
+		2,
+		"Effect wasn't subscribed to trigger gathered outside of it"
+	);
+
+	effect.gather_deps(|| trigger2.gather_subs());
+	drop(trigger1.exec());
+	assert_eq!(RUNS.get(), 3, "Gathering dependencies removed the existing ones");
+}
+
 #[test]
 fn weak_effect_empty() {
 	let effect = WeakEffect::<fn()>::new();
@@ -223,3 +283,90 @@ fn trigger_upgrade() {
 
 	assert_eq!(Some(trigger), weak.upgrade());
 }
+
+/// Ensures dropped effects are removed from the dependency graph when pruning
+#[test]
+fn prune_dep_graph() {
+	let trigger = Trigger::new();
+	let effects = (0..100)
+		.map(|_| {
+			#[cloned(trigger)]
+			Effect::new(move || trigger.gather_subs())
+		})
+		.collect::<Vec<_>>();
+
+	let dep_graph = GLOBAL_WORLD.dep_graph();
+	let subs_len = || {
+		let mut len = 0;
+		dep_graph.with_trigger_subs(trigger.downgrade(), |_, _| len += 1);
+		len
+	};
+	assert_eq!(subs_len(), 100);
+
+	let weak_effects = effects.iter().map(Effect::downgrade).collect::<Vec<_>>();
+	drop(effects);
+
+	// Note: Other tests share the dependency graph and may prune it concurrently,
+	//       so we can't rely on the number of pruned nodes here.
+	dep_graph.prune();
+	assert!(
+		weak_effects.iter().all(|effect| effect.upgrade().is_none()),
+		"Dropped effects were still alive"
+	);
+	assert_eq!(subs_len(), 0, "Dropped effects weren't pruned");
+
+	// Note: The trigger is still alive, so it must still work.
+	drop(trigger.exec());
+}
+
+#[test]
+fn subscriber_warning() {
+	/// Counts the number of times the effect was run
+	static TRIGGERS: Counter = Counter::new();
+
+	let warnings = Arc::new(AtomicUsize::new(0));
+	let trigger = Trigger::new();
+	tracing::subscriber::with_default(WarnCounter(Arc::clone(&warnings)), || {
+		trigger.gather_subs();
+		assert_eq!(warnings.load(atomic::Ordering::Relaxed), 1, "Expected a warning");
+
+		without_subscriber_warning(|| trigger.gather_subs());
+		assert_eq!(warnings.load(atomic::Ordering::Relaxed), 1, "Warning wasn't suppressed");
+
+		#[cloned(trigger)]
+		let _effect = Effect::new(move || {
+			without_subscriber_warning(|| trigger.gather_subs());
+			TRIGGERS.bump();
+		});
+		assert_eq!(TRIGGERS.get(), 1);
+
+		drop(trigger.exec());
+		assert_eq!(TRIGGERS.get(), 2, "Effect didn't subscribe to the trigger");
+		assert_eq!(warnings.load(atomic::Ordering::Relaxed), 1);
+	});
+}
+
+/// Subscriber that counts all warnings
+struct WarnCounter(Arc<AtomicUsize>);
+
+impl Subscriber for WarnCounter {
+	fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+		*metadata.level() == Level::WARN
+	}
+
+	fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+		span::Id::from_u64(1)
+	}
+
+	fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+	fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+	fn event(&self, _event: &Event<'_>) {
+		self.0.fetch_add(1, atomic::Ordering::Relaxed);
+	}
+
+	fn enter(&self, _span: &span::Id) {}
+
+	fn exit(&self, _span: &span::Id) {}
+}
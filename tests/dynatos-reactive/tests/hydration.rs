@@ -0,0 +1,100 @@
+//! Hydration tests
+
+// Features
+#![feature(proc_macro_hygiene, stmt_expr_attributes)]
+
+// Imports
+use {
+	dynatos_reactive::{Effect, HydrationError, HydrationRegistry, Signal, SignalGet, SignalGetCloned, SignalSet},
+	dynatos_sync_types::{IMut, RcPtr},
+	dynatos_util::Counter,
+	zutil_cloned::cloned,
+};
+
+/// Ensures values survive a serialize/hydrate round-trip
+#[test]
+fn round_trip() {
+	let server = HydrationRegistry::new();
+	server.register("bool", Signal::new(true));
+	server.register("int", Signal::new(-5_i32));
+	server.register("float", Signal::new(1.5_f64));
+	server.register("char", Signal::new('a'));
+	server.register("string", Signal::new("Hello".to_owned()));
+	let json = server.serialize().expect("Unable to serialize");
+
+	let client = HydrationRegistry::new();
+	let bool_sig = Signal::new(false);
+	let int_sig = Signal::new(0_i32);
+	let float_sig = Signal::new(0.0_f64);
+	let char_sig = Signal::new(' ');
+	let string_sig = Signal::new(String::new());
+	client.register("bool", bool_sig.clone());
+	client.register("int", int_sig.clone());
+	client.register("float", float_sig.clone());
+	client.register("char", char_sig.clone());
+	client.register("string", string_sig.clone());
+	client.hydrate(&json).expect("Unable to hydrate");
+
+	assert!(bool_sig.get());
+	assert_eq!(int_sig.get(), -5);
+	assert_eq!(float_sig.get(), 1.5);
+	assert_eq!(char_sig.get(), 'a');
+	assert_eq!(string_sig.get_cloned(), "Hello");
+	assert_eq!(client.serialize().expect("Unable to serialize"), json);
+}
+
+/// Ensures serializing doesn't gather dependencies
+#[test]
+fn serialize_no_dep() {
+	let registry = HydrationRegistry::new();
+	let sig = Signal::new(5_i32);
+	registry.register("sig", sig.clone());
+
+	/// Counts the number of times the effect was run
+	static RUNS: Counter = Counter::new();
+
+	let _effect = Effect::new(move || {
+		RUNS.bump();
+		registry.serialize().expect("Unable to serialize");
+	});
+	assert_eq!(RUNS.get(), 1);
+
+	sig.set(6);
+	assert_eq!(RUNS.get(), 1, "Serializing gathered a dependency");
+}
+
+/// Ensures effects run once after all signals are hydrated
+#[test]
+fn hydrate_deferred() {
+	let registry = HydrationRegistry::new();
+	let lhs = Signal::new(0_i32);
+	let rhs = Signal::new(0_i32);
+	registry.register("lhs", lhs.clone());
+	registry.register("rhs", rhs.clone());
+
+	let sums = RcPtr::new(IMut::new(vec![]));
+	#[cloned(sums)]
+	let _effect = Effect::new(move || {
+		sums.lock().push(lhs.get() + rhs.get());
+	});
+
+	registry
+		.hydrate(r#"{"lhs": 1, "rhs": 2, "unknown": "ignored"}"#)
+		.expect("Unable to hydrate");
+	assert_eq!(*sums.lock(), [0, 3]);
+}
+
+/// Ensures invalid values are reported
+#[test]
+fn hydrate_error() {
+	let registry = HydrationRegistry::new();
+	let sig = Signal::new(0_i32);
+	registry.register("sig", sig.clone());
+
+	assert!(matches!(registry.hydrate("["), Err(HydrationError::Parse(_))));
+	assert!(matches!(
+		registry.hydrate(r#"{"sig": "five"}"#),
+		Err(HydrationError::Deserialize { key, .. }) if key == "sig"
+	));
+	assert_eq!(sig.get(), 0);
+}
@@ -6,9 +6,9 @@
 // Imports
 use {
 	core::iter,
-	dynatos_reactive::{Derived, Effect, Signal, SignalBorrowMut, SignalGet, Trigger},
+	dynatos_reactive::{Derived, Effect, Signal, SignalBorrowMut, SignalGet, SignalSet, Trigger},
 	dynatos_util::Counter,
-	std::sync::nonpoison::Mutex,
+	std::sync::{Arc, nonpoison::Mutex},
 	zutil_cloned::cloned,
 };
 
@@ -98,3 +98,190 @@ fn order() {
 	drop((b.exec(), a_first.exec()));
 	assert_eq!(COUNT.get(), 3);
 }
+
+#[test]
+fn on_flush_complete() {
+	static ORDER: Mutex<Vec<&'static str>> = Mutex::new(vec![]);
+
+	let a = Signal::new(5_usize);
+
+	#[cloned(a)]
+	let b = Derived::new(move || {
+		ORDER.lock().push("b");
+		a.get() + 1
+	});
+
+	let _c = Effect::new(move || {
+		ORDER.lock().push("c");
+		b.get();
+	});
+
+	ORDER.lock().clear();
+
+	dynatos_reactive::run_queue::on_flush_complete(|| ORDER.lock().push("flush"));
+	assert_eq!(*ORDER.lock(), [] as [&str; 0], "Callback was run before flushing");
+
+	a.set(6);
+	assert_eq!(
+		*ORDER.lock(),
+		["b", "c", "flush"],
+		"Callback wasn't run once after flushing"
+	);
+
+	a.set(7);
+	assert_eq!(
+		*ORDER.lock(),
+		["b", "c", "flush", "b", "c"],
+		"Callback was run more than once"
+	);
+}
+
+/// Runs effects `a` and `b`, both depending on the same trigger, with
+/// `order` applied to them, and returns the order they were run in.
+fn run_after_order(order: impl FnOnce(&Effect, &Effect)) -> Vec<&'static str> {
+	// Note: We can't use a `static` here, since multiple tests use this function concurrently.
+	let order_run = Arc::new(Mutex::new(vec![]));
+
+	let trigger = Trigger::new();
+
+	#[cloned(trigger, order_run)]
+	let a = Effect::new(move || {
+		order_run.lock().push("a");
+		trigger.gather_subs();
+	});
+	#[cloned(trigger, order_run)]
+	let b = Effect::new(move || {
+		order_run.lock().push("b");
+		trigger.gather_subs();
+	});
+	order(&a.clone().unsize(), &b.clone().unsize());
+
+	order_run.lock().clear();
+	trigger.exec();
+
+	let order = order_run.lock().clone();
+	drop((a, b));
+	order
+}
+
+#[test]
+fn run_after() {
+	assert_eq!(
+		self::run_after_order(|a, b| a.run_after(b)),
+		["b", "a"],
+		"Effect was run before the effect it runs after"
+	);
+	assert_eq!(
+		self::run_after_order(|a, b| b.run_after(a)),
+		["a", "b"],
+		"Effect was run before the effect it runs after"
+	);
+}
+
+#[test]
+fn priority() {
+	assert_eq!(
+		self::run_after_order(|a, _| a.set_priority(1)),
+		["a", "b"],
+		"Effect was run before a higher priority effect"
+	);
+	assert_eq!(
+		self::run_after_order(|_, b| b.set_priority(1)),
+		["b", "a"],
+		"Effect was run before a higher priority effect"
+	);
+	assert_eq!(
+		self::run_after_order(|a, b| {
+			a.set_priority(2);
+			b.set_priority(1);
+		}),
+		["a", "b"],
+		"Effect was run before a higher priority effect"
+	);
+	assert_eq!(
+		self::run_after_order(|a, b| {
+			a.set_priority(1);
+			a.run_after(b);
+		}),
+		["b", "a"],
+		"Priority took precedence over running after an effect"
+	);
+}
+
+#[test]
+fn run_after_cycle() {
+	assert_eq!(
+		self::run_after_order(|a, b| {
+			a.run_after(b);
+			b.run_after(a);
+		}),
+		["b", "a"],
+		"Cyclic ordering wasn't ignored"
+	);
+}
+
+#[test]
+fn run_after_transitive() {
+	static ORDER: Mutex<Vec<&'static str>> = Mutex::new(vec![]);
+
+	let trigger = Trigger::new();
+
+	#[cloned(trigger)]
+	let a = Effect::new(move || {
+		ORDER.lock().push("a");
+		trigger.gather_subs();
+	});
+	let b = Effect::new(move || ORDER.lock().push("b"));
+	#[cloned(trigger)]
+	let c = Effect::new(move || {
+		ORDER.lock().push("c");
+		trigger.gather_subs();
+	});
+
+	// Note: `b` is never in the run queue, but `a` should
+	//       still run after `c`, through it.
+	a.run_after(&b);
+	b.run_after(&c);
+
+	ORDER.lock().clear();
+	trigger.exec();
+	assert_eq!(
+		*ORDER.lock(),
+		["c", "a"],
+		"Effect was run before the effect it runs after"
+	);
+}
+
+#[test]
+fn defer_effects() {
+	let a = Signal::new(5_usize);
+
+	static COUNT: Counter = Counter::new();
+	#[cloned(a)]
+	let _effect = Effect::new(move || {
+		a.get();
+		COUNT.bump();
+	});
+	assert_eq!(COUNT.get(), 1);
+
+	let defer = dynatos_reactive::defer_effects();
+	a.set(6);
+	a.set(7);
+	assert_eq!(COUNT.get(), 1, "Effect was run while deferred");
+
+	dynatos_reactive::flush();
+	assert_eq!(COUNT.get(), 2, "Effect wasn't run once after flushing");
+
+	// Flushing an empty queue doesn't run anything
+	dynatos_reactive::flush();
+	assert_eq!(COUNT.get(), 2, "Effect was run after flushing an empty queue");
+
+	a.set(8);
+	assert_eq!(COUNT.get(), 2, "Effect was run while deferred after flushing");
+
+	drop(defer);
+	assert_eq!(COUNT.get(), 3, "Effect wasn't run after dropping the guard");
+
+	a.set(9);
+	assert_eq!(COUNT.get(), 4, "Effect wasn't run after the guard was dropped");
+}
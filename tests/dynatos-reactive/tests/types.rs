@@ -43,5 +43,6 @@ are_send_sync! {
 	Trigger,
 	WeakTrigger,
 	WithDefault<Signal<i32>, i32>,
+	WithDefault<Signal<Option<String>>, str>,
 	GlobalWorld,
 }